@@ -2,6 +2,8 @@ use std::io::Write;
 
 use anchor_lang::__private::CLOSED_ACCOUNT_DISCRIMINATOR;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
 
 use crate::{errors::ErrorCode, try_math::*};
 
@@ -22,3 +24,79 @@ pub fn close_account(pda_to_close: &mut AccountInfo, sol_destination_account: &m
         .map_err(|_| error!(ErrorCode::AnchorSerializationIssue))?;
     Ok(())
 }
+
+pub fn try_deserialize_with_padding<T: AccountDeserialize>(account_info: &AccountInfo, padding: usize) -> Result<T> {
+
+    // Older account layouts are a prefix of the current layout, so any trailing fields missing from the account are read as zeroes
+    try_deserialize_prefix(account_info, account_info.data_len(), padding)
+}
+
+pub fn try_deserialize_prefix<T: AccountDeserialize>(account_info: &AccountInfo, prefix_length: usize, padding: usize) -> Result<T> {
+
+    // Only the first prefix_length bytes hold the account's own layout, as earlier reallocations may have left stale bytes after it,
+    // so everything past the prefix is read as zeroes
+    let mut data: Vec<u8> = account_info.try_borrow_data()?.to_vec();
+    data.truncate(prefix_length);
+    data.resize(data.len() + padding, 0);
+
+    T::try_deserialize(&mut data.as_slice())
+}
+
+pub fn legacy_layout_length<T: AnchorDeserialize>(account_info: &AccountInfo) -> Result<usize> {
+
+    // Decode the fields of the account's original layout following its discriminator to find where they end
+    let data = account_info.try_borrow_data()?;
+    let mut fields: &[u8] = data.get(8..).ok_or_else(|| error!(ErrorCode::AnchorSerializationIssue))?;
+    T::deserialize(&mut fields).map_err(|_| error!(ErrorCode::AnchorSerializationIssue))?;
+
+    Ok(data.len() - fields.len())
+}
+
+fn realloc_with_rent<'info>(
+    account_info: &AccountInfo<'info>,
+    new_data_bytes_amount: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+
+    let minimum_balance_for_rent_exemption: u64 = Rent::get()?.minimum_balance(new_data_bytes_amount);
+    let lamports_difference: u64 = minimum_balance_for_rent_exemption.saturating_sub(account_info.lamports());
+
+    // Transfer the required difference in Lamports to accommodate any increase in space
+    if lamports_difference > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account_info.key, lamports_difference),
+            &[payer.clone(), account_info.clone(), system_program.clone()],
+        )?;
+    }
+
+    // Reallocate the account to exactly the required size, shrinking it for shorter data and zeroing any added bytes
+    if new_data_bytes_amount != account_info.data_len() {
+        account_info.realloc(new_data_bytes_amount, true)?;
+    }
+
+    Ok(())
+}
+
+pub fn serialize_with_realloc<'info, T: AccountSerialize>(
+    account: &T,
+    account_info: &AccountInfo<'info>,
+    min_space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+
+    let mut buffer: Vec<u8> = Vec::new();
+    account.try_serialize(&mut buffer)?;
+
+    let buffer_length: usize = buffer.len();
+    let new_data_bytes_amount: usize = buffer_length.max(min_space);
+
+    realloc_with_rent(account_info, new_data_bytes_amount, payer, system_program)?;
+
+    // Zero everything after the serialized account, where longer data previously written to the account may have been left behind
+    let mut data = account_info.try_borrow_mut_data()?;
+    data[..buffer_length].clone_from_slice(&buffer);
+    data[buffer_length..].fill(0);
+    Ok(())
+}
@@ -60,60 +60,153 @@ pub enum ErrorCode {
     #[msg("accepted submissions can only be edited by moderators once their bounty/reputation is awarded")]
     AccountCannotBeEdited, //0x1782
 
-    Reserved19, //0x1783
-    Reserved20, //0x1784
-
-    Reserved21, //0x1785
-    Reserved22, //0x1786
-    Reserved23, //0x1787
-    Reserved24, //0x1788
-    Reserved25, //0x1789
-    Reserved26, //0x178A
-    Reserved27, //0x178B
-    Reserved28, //0x178C
-    Reserved29, //0x178D
-
-    Reserved30, //0x178E
-    Reserved31, //0x178F
-    Reserved32, //0x1790
-    Reserved33, //0x1791
-    Reserved34, //0x1792
-    Reserved35, //0x1793
-    Reserved36, //0x1794
-    Reserved37, //0x1795
-    Reserved38, //0x1796
-    Reserved39, //0x1797
-
-    Reserved40, //0x1798
-    Reserved41, //0x1799
-    Reserved42, //0x179A
-    Reserved43, //0x179B
-    Reserved44, //0x179C
-    Reserved45, //0x179D
-    Reserved46, //0x179E
-    Reserved47, //0x179F
-    Reserved48, //0x17A0
-    Reserved49, //0x17A1
-
-    Reserved50, //0x17A2
-    Reserved51, //0x17A3
-    Reserved52, //0x17A4
-    Reserved53, //0x17A5
-    Reserved54, //0x17A6
-    Reserved55, //0x17A7
-    Reserved56, //0x17A8
-    Reserved57, //0x17A9
-    Reserved58, //0x17AA
-    Reserved59, //0x17AB
-
-    Reserved60, //0x17AC
-    Reserved61, //0x17AD
-    Reserved62, //0x17AE
-    Reserved63, //0x17AF
-    Reserved64, //0x17B0
-    Reserved65, //0x17B1
-    Reserved66, //0x17B2
-    Reserved67, //0x17B3
+    #[msg("bounty per completion must be non-zero and not greater than the total bounty, or both must be zero")]
+    InvalidBountyParams, //0x1783
+
+    #[msg("token accounts and the token program must be provided when fees or bounties are denominated in an SPL token")]
+    TokenAccountsRequired, //0x1784
+
+    #[msg("challenge token bounty has already been funded")]
+    TokenBountyAlreadyFunded, //0x1785
+
+    #[msg("treasury balance is insufficient to cover the requested payouts")]
+    InsufficientTreasuryBalance, //0x1786
+
+    #[msg("every payout receiver must be passed in as a writable remaining account")]
+    PayoutReceiverNotProvided, //0x1787
+
+    #[msg("signer is not the pending crux manager")]
+    NotPendingCruxManager, //0x1788
+
+    #[msg("signer is not the crux manager")]
+    NotCruxManager, //0x1789
+
+    #[msg("profile creation is paused for this crux")]
+    ProfileCreationPaused, //0x178A
+
+    #[msg("challenge creation is paused for this crux")]
+    ChallengeCreationPaused, //0x178B
+
+    #[msg("submissions are paused for this crux")]
+    SubmissionsPaused, //0x178C
+
+    #[msg("evaluation is paused for this crux")]
+    EvaluationPaused, //0x178D
+
+    #[msg("submission cannot be moved from its current state to the requested state")]
+    InvalidSubmissionStateTransition, //0x178E
+
+    #[msg("score must not exceed the maximum score and is only given to completed submissions")]
+    InvalidScore, //0x178F
+
+    #[msg("challenge must require at least one moderator approval")]
+    InvalidRequiredApprovals, //0x1790
+
+    #[msg("moderators cannot evaluate or delete their own submissions")]
+    SelfEvaluationForbidden, //0x1791
+
+    #[msg("crux forbids moderators from evaluating submissions to challenges they created")]
+    AuthorEvaluationForbidden, //0x1792
+
+    #[msg("only rejected submissions can be appealed, and only appealed submissions can be resolved")]
+    SubmissionNotAppealable, //0x1793
+
+    #[msg("appeals must be resolved by the crux manager or a moderator who did not take part in the rejection")]
+    InvalidAppealResolver, //0x1794
+
+    #[msg("feedback_content_data_url must not be longer than the maximum character length specified in crux constants")]
+    InvalidFeedbackUrlStringInput, //0x1795
+
+    #[msg("submission has reached the challenge's maximum number of revisions")]
+    RevisionLimitReached, //0x1796
+
+    #[msg("user has reached the challenge's maximum number of submissions")]
+    SubmissionLimitReached, //0x1797
+
+    #[msg("submission index must be the next index recorded in the user's submission entries, which are required for challenges accepting multiple submissions")]
+    InvalidSubmissionIndex, //0x1798
+
+    #[msg("co-authors must be distinct profiles other than the submitter, with either no weights or a positive weight for the submitter and each co-author")]
+    InvalidCoAuthors, //0x1799
+
+    #[msg("every co-author must accept the submission before it can be evaluated")]
+    CoAuthorsPending, //0x179A
+
+    #[msg("co-author profiles must be passed as writable remaining accounts in the order they are named on the submission")]
+    CoAuthorProfilesRequired, //0x179B
+
+    #[msg("committed submissions can only be revealed after the challenge expires and before its reveal period ends")]
+    RevealNotOpen, //0x179C
+
+    #[msg("revealed content does not match the submission's commitment")]
+    InvalidReveal, //0x179D
+
+    #[msg("committed submissions must be revealed before they can be evaluated")]
+    SubmissionNotRevealed, //0x179E
+
+    #[msg("only unrevealed submissions can be closed, once the challenge's reveal period has ended")]
+    RevealPeriodNotOver, //0x179F
+
+    #[msg("challenge does not accept auto-graded answers")]
+    ChallengeNotAutoGraded, //0x17A0
+
+    #[msg("submission has used all of the challenge's answer attempts")]
+    AnswerAttemptsExhausted, //0x17A1
+
+    #[msg("submission must wait for the challenge's answer cooldown before attempting another answer")]
+    AnswerCooldownActive, //0x17A2
+
+    #[msg("challenge does not name a verifier program")]
+    ChallengeNotVerifiable, //0x17A3
+
+    #[msg("verifier program did not confirm the submission")]
+    VerificationFailed, //0x17A4
+
+    #[msg("challenge is not open for submissions")]
+    ChallengeNotOpen, //0x17A5
+
+    #[msg("challenge must open before it expires, and its evaluation deadline must not be before it expires and its submissions can be revealed")]
+    InvalidChallengeSchedule, //0x17A6
+
+    #[msg("pending submissions can't be evaluated after the challenge's evaluation deadline")]
+    EvaluationDeadlinePassed, //0x17A7
+
+    #[msg("only submissions still pending after the challenge's evaluation deadline can be resolved by default")]
+    EvaluationDeadlineNotReached, //0x17A8
+
+    #[msg("challenge has reached its maximum number of completions")]
+    ChallengeCompletionsFilled, //0x17A9
+
+    #[msg("reputation schedule must descend from the first rank and not fall below the challenge's reputation")]
+    InvalidReputationSchedule, //0x17AA
+
+    #[msg("prerequisites must be distinct existing challenges of the same crux, passed in order as remaining accounts")]
+    InvalidPrerequisites, //0x17AB
+
+    #[msg("a completed submission is required for each of the challenge's prerequisites, passed in order as remaining accounts")]
+    PrerequisiteNotCompleted, //0x17AC
+
+    #[msg("user profile's reputation score is below the challenge's minimum")]
+    InsufficientReputation, //0x17AD
+
+    #[msg("recurring challenges can't accept multiple submissions per user in a period")]
+    InvalidRecurrence, //0x17AE
+
+    #[msg("invalid challenge status transition")]
+    InvalidChallengeStatusTransition, //0x17AF
+
+    #[msg("archived challenges are kept for history and can't be edited, deleted or have their submissions decided")]
+    ChallengeArchived, //0x17B0
+
+    #[msg("reviews can only be closed once their submission has been closed")]
+    SubmissionNotClosed, //0x17B1
+
+    #[msg("completions moved down the reputation schedule must be passed after the co-author profiles with their user and co-author profiles")]
+    RankedCompletionsRequired, //0x17B2
+
+    #[msg("commit-reveal challenges can't be auto-graded or verified, as their answers are only revealed after expiry")]
+    CommitRevealNotSupported, //0x17B3
+
     Reserved68, //0x17B4
     Reserved69, //0x17B5
 
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile, MAX_SCORE_BPS};
+use prog_common::{TryAdd, TryDiv, TryMul, TrySub, errors::ErrorCode};
+
+// Token accounts through which a challenge's token bounty is paid out or refunded, only required when the challenge holds a token bounty
+pub struct BountyTokenAccounts<'a, 'info> {
+    pub crux_authority: &'a Option<AccountInfo<'info>>,
+    pub bounty_token_vault: &'a Option<Box<Account<'info, TokenAccount>>>,
+    pub receiver_token_account: &'a Option<Box<Account<'info, TokenAccount>>>,
+    pub token_program: &'a Option<Program<'info, Token>>,
+}
+
+// Pays out the challenge's lamport and token bounty shares to the submitter the first time the submission is completed
+pub fn pay_out_bounty<'info>(
+    crux: &Crux,
+    challenge: &mut Challenge,
+    submission: &mut Submission,
+    bounty_vault: AccountInfo<'info>,
+    profile_owner: AccountInfo<'info>,
+    bounty_token_accounts: BountyTokenAccounts<'_, 'info>,
+) -> Result<()> {
+
+    if submission.bounty_paid {
+        return Ok(());
+    }
+
+    let bounty_payout = challenge.bounty_per_completion.min(challenge.bounty_lamports);
+    let bounty_token_payout = challenge.bounty_token_per_completion.min(challenge.bounty_token_amount);
+
+    if bounty_payout > 0 {
+        transfer_bounty(&bounty_vault, &profile_owner, bounty_payout)?;
+        challenge.bounty_lamports.try_sub_assign(bounty_payout)?;
+
+        msg!("{} lamports of bounty paid out to {}", bounty_payout, profile_owner.key());
+    }
+
+    if bounty_token_payout > 0 {
+        transfer_token_bounty(crux, &bounty_token_accounts, bounty_token_payout)?;
+        challenge.bounty_token_amount.try_sub_assign(bounty_token_payout)?;
+
+        msg!("{} tokens of mint {} paid out to {}", bounty_token_payout, challenge.bounty_token_mint, profile_owner.key());
+    }
+
+    submission.bounty_paid = true;
+    Ok(())
+}
+
+// Accounts updated when a submission is completed, whether by moderators, by default, on appeal, by a correct answer or by a verifier
+pub struct CompletionAccounts<'a, 'info> {
+    pub crux: &'a Account<'info, Crux>,
+    pub challenge: &'a mut Account<'info, Challenge>,
+    pub submission: &'a mut Account<'info, Submission>,
+    pub user_profile: &'a mut Account<'info, UserProfile>,
+    pub bounty_vault: AccountInfo<'info>,
+    pub profile_owner: AccountInfo<'info>,
+    pub bounty_token_accounts: BountyTokenAccounts<'a, 'info>,
+}
+
+// Completes a submission with the given score, paying out the challenge's bounty shares and crediting the scored share of the
+// challenge's reputation at the submission's completion rank, split with any co-authors whose profiles are passed in order.
+// The co-author profiles are followed by the accounts of any later-posted completions the submission moves down the reputation schedule
+pub fn complete_submission<'info>(
+    completion_accounts: CompletionAccounts<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    score_bps: u64,
+    now_ts: u64,
+) -> Result<()> {
+
+    let CompletionAccounts {
+        crux, challenge, submission, user_profile, bounty_vault, profile_owner, bounty_token_accounts,
+    } = completion_accounts;
+
+    pay_out_bounty(crux, challenge, submission, bounty_vault, profile_owner, bounty_token_accounts)?;
+
+    let co_author_count = (submission.co_author_count as usize).min(remaining_accounts.len());
+    let (co_author_profiles, mut moved_completion_accounts) = remaining_accounts.split_at(co_author_count);
+
+    let (reputation, moved_completions) = challenge.record_completion(submission.key(), submission.submission_posted_ts)?;
+    let awarded_reputation = reputation.try_mul(score_bps)?.try_div(MAX_SCORE_BPS)?;
+    let submitter_reputation = submission.credit_co_authors(crux.key(), co_author_profiles, awarded_reputation)?;
+
+    submission.awarded_reputation = submitter_reputation;
+    submission.submission_state = SubmissionState::Completed;
+    submission.score_bps = score_bps;
+
+    user_profile.challenges_completed.try_add_assign(1)?;
+    user_profile.reputation_score.try_add_assign(submitter_reputation)?;
+
+    // Completing a period of a recurring challenge counts towards the submitter's streak
+    if challenge.is_recurring() {
+        user_profile.record_period_completion(challenge, submission.submission_index, now_ts)?;
+    }
+
+    // The moved completions may share profiles with the submitter, so the profile is written out before they are re-credited and read back after
+    if !moved_completions.is_empty() {
+        user_profile.exit(&crate::ID)?;
+
+        for (moved_submission, moved_reputation) in moved_completions {
+            let account_count = rerank_completion(crux.key(), moved_completion_accounts, moved_submission, moved_reputation)?;
+            moved_completion_accounts = &moved_completion_accounts[account_count..];
+        }
+
+        user_profile.reload()?;
+    }
+
+    Ok(())
+}
+
+// Counts the accounts passed for the later-posted completions a submission posted at the given timestamp moves down the reputation schedule,
+// each as the completed submission followed by its user profile and co-author profiles
+pub fn moved_completion_account_count(challenge: &Challenge, submission_posted_ts: u64, accounts: &[AccountInfo]) -> Result<usize> {
+    let (rank, free_rank) = match challenge.completion_rank(submission_posted_ts) {
+        Some(completion_rank) => completion_rank,
+        None => return Ok(0),
+    };
+
+    let mut account_count = 0;
+    for ranked in &challenge.ranked_completions[rank..free_rank] {
+        let (_, moved_account_count) = load_moved_completion(accounts.get(account_count..).unwrap_or_default(), ranked.submission)?;
+        account_count += moved_account_count;
+    }
+
+    Ok(account_count)
+}
+
+// Loads a completed submission moved to another rank from the front of the given accounts, returning it with the number of accounts it is passed with
+fn load_moved_completion<'info>(accounts: &[AccountInfo<'info>], moved_submission: Pubkey) -> Result<(Account<'info, Submission>, usize)> {
+    let submission: Account<Submission> = match accounts.first() {
+        Some(submission_info) if submission_info.key() == moved_submission => Account::try_from(submission_info)?,
+        _ => return Err(error!(ErrorCode::RankedCompletionsRequired)),
+    };
+
+    let account_count = 2 + submission.co_author_count as usize;
+    if (accounts.len() < account_count) || (accounts[1].key() != submission.user_profile) {
+        return Err(error!(ErrorCode::RankedCompletionsRequired));
+    }
+
+    Ok((submission, account_count))
+}
+
+// Re-credits a completed submission moved to another rank with the scored share of that rank's reputation, split with its co-authors as before
+fn rerank_completion<'info>(crux: Pubkey, accounts: &[AccountInfo<'info>], moved_submission: Pubkey, reputation: u64) -> Result<usize> {
+    let (mut submission, account_count) = load_moved_completion(accounts, moved_submission)?;
+    let co_author_profiles = &accounts[2..account_count];
+
+    submission.reverse_co_authors(crux, co_author_profiles)?;
+    let awarded_reputation = reputation.try_mul(submission.score_bps)?.try_div(MAX_SCORE_BPS)?;
+    let submitter_reputation = submission.credit_co_authors(crux, co_author_profiles, awarded_reputation)?;
+
+    let mut user_profile: Account<UserProfile> = Account::try_from(&accounts[1])?;
+    user_profile.reputation_score = user_profile.reputation_score.try_sub(submission.awarded_reputation)?.try_add(submitter_reputation)?;
+    user_profile.exit(&crate::ID)?;
+
+    msg!("Completed submission {} moved to reputation {} with {} awarded to the submitter", moved_submission, reputation, submitter_reputation);
+
+    submission.awarded_reputation = submitter_reputation;
+    submission.exit(&crate::ID)?;
+    Ok(account_count)
+}
+
+fn transfer_bounty(bounty_vault: &AccountInfo, profile_owner: &AccountInfo, lamports: u64) -> Result<()> {
+    let bounty_vault_lamports_initial = bounty_vault.lamports();
+    let profile_owner_lamports_initial = profile_owner.lamports();
+
+    **bounty_vault.lamports.borrow_mut() = bounty_vault_lamports_initial.try_sub(lamports)?;
+    **profile_owner.lamports.borrow_mut() = profile_owner_lamports_initial.try_add(lamports)?;
+    Ok(())
+}
+
+fn transfer_token_bounty(crux: &Crux, bounty_token_accounts: &BountyTokenAccounts, amount: u64) -> Result<()> {
+    match (bounty_token_accounts.crux_authority, bounty_token_accounts.bounty_token_vault,
+           bounty_token_accounts.receiver_token_account, bounty_token_accounts.token_program) {
+        (Some(crux_authority), Some(bounty_token_vault), Some(receiver_token_account), Some(token_program)) => {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: bounty_token_vault.to_account_info(),
+                        to: receiver_token_account.to_account_info(),
+                        authority: crux_authority.to_account_info(),
+                    },
+                    &[&crux.crux_seeds()],
+                ),
+                amount,
+            )
+        }
+        _ => Err(error!(ErrorCode::TokenAccountsRequired)),
+    }
+}
+
+// Returns a challenge's unclaimed token bounty to the funder's token account and closes the bounty token vault, reclaiming its rent
+pub fn refund_token_bounty_and_close_vault<'info>(crux: &Crux, bounty_token_accounts: BountyTokenAccounts<'_, 'info>, rent_receiver: AccountInfo<'info>) -> Result<()> {
+    match (bounty_token_accounts.crux_authority, bounty_token_accounts.bounty_token_vault,
+           bounty_token_accounts.receiver_token_account, bounty_token_accounts.token_program) {
+        (Some(crux_authority), Some(bounty_token_vault), Some(receiver_token_account), Some(token_program)) => {
+            let crux_seeds = crux.crux_seeds();
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: bounty_token_vault.to_account_info(),
+                        to: receiver_token_account.to_account_info(),
+                        authority: crux_authority.to_account_info(),
+                    },
+                    &[&crux_seeds],
+                ),
+                bounty_token_vault.amount,
+            )?;
+
+            token::close_account(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    CloseAccount {
+                        account: bounty_token_vault.to_account_info(),
+                        destination: rent_receiver,
+                        authority: crux_authority.to_account_info(),
+                    },
+                    &[&crux_seeds],
+                ),
+            )
+        }
+        _ => Err(error!(ErrorCode::TokenAccountsRequired)),
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, Crux, Submission, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_co_author_profile: u8)]
+pub struct AcceptCoAuthorship<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    pub co_author: Signer<'info>,
+
+    // The co-author's profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), co_author.key().as_ref()],
+              bump = bump_co_author_profile, has_one = crux, constraint = co_author_profile.profile_owner == co_author.key())]
+    pub co_author_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge of the team submission, which must belong to the co-author's crux
+    #[account(has_one = crux)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Team submission naming the co-author's profile
+    #[account(mut, has_one = challenge)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptCoAuthorship>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Ensure the submission names the co-author's profile and mark it as accepted
+    let co_author_profile_key = ctx.accounts.co_author_profile.key();
+    let co_author_count = ctx.accounts.submission.co_author_count as usize;
+
+    let submission = &mut ctx.accounts.submission;
+    let co_author = match submission.co_authors[..co_author_count].iter_mut().find(|co_author| co_author.user_profile == co_author_profile_key) {
+        Some(co_author) => co_author,
+        None => return Err(error!(ErrorCode::InvalidCoAuthors)),
+    };
+    co_author.accepted = true;
+
+    // Update the co-author profile's most recent engagement ts
+    let co_author_profile = &mut ctx.accounts.co_author_profile;
+    co_author_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("User profile with pubkey {} accepted co-authorship of submission account with address {}",
+         ctx.accounts.co_author_profile.key(), ctx.accounts.submission.key());
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Crux};
+use prog_common::{errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct AcceptCruxManager<'info> {
+
+    // Crux and the pending Crux Manager
+    #[account(mut)]
+    pub crux: Box<Account<'info, Crux>>,
+    pub new_crux_manager: Signer<'info>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptCruxManager>) -> Result<()> {
+
+    // Ensure the signer is the proposed crux manager
+    if ctx.accounts.crux.pending_crux_manager != ctx.accounts.new_crux_manager.key() {
+        return Err(error!(ErrorCode::NotPendingCruxManager));
+    }
+
+    let crux = &mut ctx.accounts.crux;
+    crux.crux_manager = ctx.accounts.new_crux_manager.key();
+    crux.pending_crux_manager = Pubkey::default();
+
+    msg!("Crux manager of crux {} is now {}", ctx.accounts.crux.key(), ctx.accounts.new_crux_manager.key());
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::{Challenge, Crux, Submission, SubmissionState, TokenLedger, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+pub struct AppealSubmission<'info> {
+
+    // Crux
+    #[account(mut, has_one = crux_treasury)]
+    pub crux: Box<Account<'info, Crux>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), crux.key().as_ref()], bump = bump_treasury)]
+    pub crux_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    /// CHECK:
+    // The content data hash of the appeal
+    pub appeal_content_data_hash: AccountInfo<'info>,
+
+    // Token accounts, only required when the crux charges fees in an SPL token
+    #[account(mut, token::mint = crux.fee_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, address = crux.crux_treasury_token_account)]
+    pub crux_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [b"token_ledger".as_ref(), crux.key().as_ref(), crux.fee_mint.as_ref()], bump)]
+    pub crux_token_ledger: Option<Box<Account<'info, TokenLedger>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AppealSubmission<'info> {
+
+    fn transfer_payment_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, self.crux_treasury.key, lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.crux_treasury.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+
+    fn transfer_token_payment_ctx(&self, amount: u64) -> Result<()> {
+        match (&self.profile_owner_token_account, &self.crux_treasury_token_account, &self.token_program) {
+            (Some(profile_owner_token_account), Some(crux_treasury_token_account), Some(token_program)) => {
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: profile_owner_token_account.to_account_info(),
+                            to: crux_treasury_token_account.to_account_info(),
+                            authority: self.profile_owner.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )
+            }
+            _ => Err(error!(ErrorCode::TokenAccountsRequired)),
+        }
+    }
+}
+
+pub fn handler(ctx: Context<AppealSubmission>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Submissions to an archived challenge are kept as they were when it was archived
+    if ctx.accounts.challenge.is_archived() {
+        return Err(error!(ErrorCode::ChallengeArchived));
+    }
+
+    // Ensure only rejected submissions are appealed
+    if ctx.accounts.submission.submission_state != SubmissionState::Rejected {
+        return Err(error!(ErrorCode::SubmissionNotAppealable));
+    }
+
+    // Transfer fee for appealing, held by the treasury until the appeal is resolved
+    let appeal_fee = ctx.accounts.crux.appeal_fee;
+    let appeal_fee_mint = ctx.accounts.crux.fee_mint;
+
+    if appeal_fee > 0 {
+        if ctx.accounts.crux.has_fee_mint() {
+            ctx.accounts.transfer_token_payment_ctx(appeal_fee)?;
+
+            let crux_token_ledger = ctx.accounts.crux_token_ledger.as_mut().ok_or(error!(ErrorCode::TokenAccountsRequired))?;
+            crux_token_ledger.total_token_fees_collected.try_add_assign(appeal_fee)?;
+        } else {
+            ctx.accounts.transfer_payment_ctx(appeal_fee)?;
+
+            let crux = &mut ctx.accounts.crux;
+            crux.crux_ledger.total_fees_collected.try_add_assign(appeal_fee)?;
+        }
+    }
+
+    // Update the submission's state account
+    let submission = &mut ctx.accounts.submission;
+    submission.most_recent_engagement_ts = now_ts;
+    submission.submission_state = SubmissionState::Appealed;
+    submission.appeal_content_data_hash = ctx.accounts.appeal_content_data_hash.key();
+    submission.appeal_fee_paid = appeal_fee;
+    submission.appeal_fee_mint = appeal_fee_mint;
+
+    // Update user profile's most recent engagement ts
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Submission PDA account with address {} has been appealed", ctx.accounts.submission.key());
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Crux, Review, Submission, UserProfile};
+use prog_common::{close_account, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8)]
+pub struct CloseReview<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    // Moderator who cast the review, receiving its rent
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(seeds = [b"user_profile".as_ref(), crux.key().as_ref(), moderator.key().as_ref()],
+              bump = bump_moderator_profile, has_one = crux, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The submission which the review belongs to, only read to ensure it has been closed or created again since the review
+    pub submission: AccountInfo<'info>,
+
+    // Review PDA account
+    #[account(mut, seeds = [b"review".as_ref(), submission.key().as_ref(), moderator_profile.key().as_ref(),
+                            &review.submission_posted_ts.to_le_bytes(), &review.review_round.to_le_bytes()],
+              bump, has_one = submission, has_one = moderator_profile)]
+    pub review: Box<Account<'info, Review>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseReview>) -> Result<()> {
+
+    // Ensure the submission has been closed, as appeals and later review rounds rely on the votes cast before,
+    // unless the submission was deleted and created again, leaving the review with no submission to refer to
+    if ctx.accounts.submission.lamports() > 0 {
+        let submission: Account<Submission> = Account::try_from(&ctx.accounts.submission)?;
+
+        if submission.submission_posted_ts == ctx.accounts.review.submission_posted_ts {
+            return Err(error!(ErrorCode::SubmissionNotClosed));
+        }
+    }
+
+    // Return the review's rent to the moderator
+    let moderator = &mut ctx.accounts.moderator.to_account_info();
+
+    // Close the review state account
+    let review_account_info = &mut (*ctx.accounts.review).to_account_info();
+    close_account(review_account_info, moderator)?;
+
+    msg!("Review PDA account with address {} now closed", ctx.accounts.review.key());
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, Crux, Submission, SubmissionEntries, UserProfile};
+use prog_common::{now_ts, close_account, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+pub struct CloseUnrevealedSubmission<'info> {
+
+    // Crux
+    #[account(mut)]
+    pub crux: Box<Account<'info, Crux>>,
+
+    /// CHECK: Used for seed verification of user profile pda account, and receives the submission's rent
+    #[account(mut)]
+    pub profile_owner: AccountInfo<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Entry counters, only required when the challenge accepts multiple submissions per user
+    #[account(mut, seeds = [b"submission_entries".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump, has_one = challenge, has_one = user_profile)]
+    pub submission_entries: Option<Box<Account<'info, SubmissionEntries>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseUnrevealedSubmission>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the submission was never revealed and the challenge's reveal period is over
+    let reveal_ends_ts = ctx.accounts.challenge.challenge_expires_ts.try_add(ctx.accounts.challenge.reveal_duration)?;
+    if !ctx.accounts.submission.awaiting_reveal || (now_ts <= reveal_ends_ts) {
+        return Err(error!(ErrorCode::RevealPeriodNotOver));
+    }
+
+    // Return the submission's rent to the submitter
+    let profile_owner = &mut ctx.accounts.profile_owner;
+
+    // Close the submission state account
+    let submission_account_info = &mut (*ctx.accounts.submission).to_account_info();
+    close_account(submission_account_info, profile_owner)?;
+
+    // Decrement submission count in crux's state
+    let crux = &mut ctx.accounts.crux;
+    crux.crux_counts.submission_count.try_sub_assign(1)?;
+
+    // Decrement submission count in user profile's state once the user holds no entries for the challenge
+    let is_last_entry: bool = if ctx.accounts.challenge.max_submissions_per_user > 0 {
+        let submission_entries = match &mut ctx.accounts.submission_entries {
+            Some(submission_entries) => submission_entries,
+            None => return Err(error!(ErrorCode::InvalidSubmissionIndex)),
+        };
+
+        submission_entries.entry_count.try_sub_assign(1)?;
+        submission_entries.entry_count == 0
+    } else {
+        true
+    };
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    if is_last_entry {
+        user_profile.challenges_submitted.try_sub_assign(1)?;
+    }
+
+    msg!("Unrevealed submission PDA account with address {} now closed", ctx.accounts.submission.key());
+    Ok(())
+}
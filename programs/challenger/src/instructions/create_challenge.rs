@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 
-use anchor_lang::solana_program::hash::hash;
-use anchor_lang::solana_program::program::{invoke_signed};
-use anchor_lang::solana_program::system_instruction::{create_account};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction::{self, create_account};
 
-use crate::state::{Crux, Tags, UserProfile};
+use crate::state::{Challenge, ChallengeSettings, ChallengeStatus, Crux, RankedCompletion, UserProfile, LATEST_CHALLENGE_VERSION, MAX_PREREQUISITES, REPUTATION_SCHEDULE_LENGTH};
 use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -30,6 +29,11 @@ pub struct CreateChallenge<'info> {
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
+    /// CHECK:
+    // Escrow PDA holding the challenge's lamport bounty
+    #[account(init, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump, payer = moderator, space = 8)]
+    pub bounty_vault: AccountInfo<'info>,
+
     /// CHECK:
     // The content data hash of the challenge struct
     pub content_data_hash: AccountInfo<'info>,
@@ -37,22 +41,48 @@ pub struct CreateChallenge<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: String, content_data_url: String, challenge_expires_ts: u64, reputation: u64) -> Result<()> {
+impl<'info> CreateChallenge<'info> {
+
+    fn transfer_bounty_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.moderator.key, self.bounty_vault.key, lamports),
+            &[
+                self.moderator.to_account_info(),
+                self.bounty_vault.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn handler(ctx: Context<CreateChallenge>, challenge_settings: ChallengeSettings) -> Result<()> {
+
+    let ChallengeSettings {
+        tags, title, content_data_url, challenge_expires_ts, reputation, bounty_lamports, bounty_per_completion, required_approvals,
+        max_revisions, max_submissions_per_user, reveal_duration, answer_hash, max_answer_attempts, answer_cooldown, verifier_program,
+        verifier_params, challenge_opens_ts, evaluation_deadline_ts, max_completions, reputation_schedule, prerequisites,
+        min_reputation_score, period_length, is_draft,
+    } = challenge_settings;
 
     let now_ts: u64 = now_ts()?;
 
+    if ctx.accounts.crux.crux_pause_flags.challenge_creation_paused {
+        return Err(error!(ErrorCode::ChallengeCreationPaused));
+    }
+
     if !ctx.accounts.moderator_profile.is_moderator {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
     // Record vector length of tags and character lengths of title and content_data_url to be added
-    let tags_length = tags.len();
-    let title_length = title.len();
-    let url_length = content_data_url.len();
+    let tags_length: u64 = tags.len() as u64;
+    let title_length: u64 = title.len() as u64;
+    let url_length: u64 = content_data_url.len() as u64;
 
-    let max_tags_length = 3; // ctx.accounts.crux.crux_constants.max_tags_length;
-    let max_title_length = 256; // ctx.accounts.crux.crux_constants.max_title_length;
-    let max_url_length = 256; // ctx.accounts.crux.crux_constants.max_url_length;
+    let max_tags_length = ctx.accounts.crux.crux_constants.max_tags_length;
+    let max_title_length = ctx.accounts.crux.crux_constants.max_title_length;
+    let max_url_length = ctx.accounts.crux.crux_constants.max_url_length;
 
     // Ensure that the length of tags vector is non-zero and not greater than max_tags_length
     if (tags_length == 0) || (tags_length > max_tags_length){
@@ -74,6 +104,56 @@ pub fn handler(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: String, co
         return Err(error!(ErrorCode::InvalidExpiryTs));
     }
 
+    // Ensure the challenge's schedule and scheduled reputation are consistent
+    Challenge::validate_schedule(challenge_opens_ts, challenge_expires_ts, evaluation_deadline_ts, reveal_duration, reputation, &reputation_schedule)?;
+
+    // Ensure the bounty paid per completion is non-zero and fits within the total bounty, or that there is no bounty at all
+    let is_valid_bounty = if bounty_lamports == 0 {
+        bounty_per_completion == 0
+    } else {
+        (bounty_per_completion > 0) && (bounty_per_completion <= bounty_lamports)
+    };
+
+    if !is_valid_bounty {
+        return Err(error!(ErrorCode::InvalidBountyParams));
+    }
+
+    // Ensure commit-reveal challenges are evaluated by moderators, as answers and verifications are only accepted before expiry
+    // while committed submissions are only revealed after it
+    if (reveal_duration > 0) && ((answer_hash != Pubkey::default()) || (verifier_program != Pubkey::default())) {
+        return Err(error!(ErrorCode::CommitRevealNotSupported));
+    }
+
+    // Ensure recurring challenges take a single submission per user in each period
+    if (period_length > 0) && (max_submissions_per_user > 0) {
+        return Err(error!(ErrorCode::InvalidRecurrence));
+    }
+
+    // Ensure each prerequisite is a distinct challenge which already exists in the same crux
+    // As prerequisites can only name existing challenges and are fixed on creation, the prerequisite graph can't contain a cycle
+    if (prerequisites.len() > MAX_PREREQUISITES) || (ctx.remaining_accounts.len() < prerequisites.len()) {
+        return Err(error!(ErrorCode::InvalidPrerequisites));
+    }
+
+    for (index, prerequisite) in prerequisites.iter().enumerate() {
+        let prerequisite_info = &ctx.remaining_accounts[index];
+
+        if (*prerequisite == Pubkey::default()) || (*prerequisite == ctx.accounts.challenge.key())
+            || prerequisites[..index].contains(prerequisite) || (prerequisite_info.key() != *prerequisite) {
+            return Err(error!(ErrorCode::InvalidPrerequisites));
+        }
+
+        let prerequisite_challenge: Account<Challenge> = Account::try_from(prerequisite_info)?;
+        if prerequisite_challenge.crux != ctx.accounts.crux.key() {
+            return Err(error!(ErrorCode::InvalidPrerequisites));
+        }
+    }
+
+    // Ensure at least one moderator vote is required to decide a submission
+    if required_approvals == 0 {
+        return Err(error!(ErrorCode::InvalidRequiredApprovals));
+    }
+
     // find bump - doing this program-side to reduce amount of info to be passed in (tx size)
     let (_pk, bump) = Pubkey::find_program_address(
         &[
@@ -87,27 +167,55 @@ pub fn handler(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: String, co
     // Create the challenge account PDA if it doesn't exist
     if ctx.accounts.challenge.data_is_empty() {
 
-        // Calculate data sizes and convert data to slice arrays
-        let mut tag_buffer: Vec<u8> = Vec::new();
-        tags.serialize(&mut tag_buffer).unwrap();
-
-        let tag_buffer_as_slice: &[u8] = tag_buffer.as_slice();
-        let tag_buffer_slice_length: usize = tag_buffer_as_slice.len();
-        let tag_slice_end_byte = 88 + tag_buffer_slice_length;
-
-        let mut title_buffer: Vec<u8> = Vec::new();
-        title.serialize(&mut title_buffer).unwrap();
-
-        let title_buffer_as_slice: &[u8] = title_buffer.as_slice();
-        let title_buffer_slice_length: usize = title_buffer_as_slice.len();
-        let title_slice_end_byte = tag_slice_end_byte + title_buffer_slice_length;
-
-        let mut content_data_url_buffer: Vec<u8> = Vec::new();
-        content_data_url.serialize(&mut content_data_url_buffer).unwrap();
-
-        let content_data_url_buffer_as_slice: &[u8] = content_data_url_buffer.as_slice();
-        let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
-        let content_data_url_slice_end_byte = title_slice_end_byte + content_data_url_buffer_slice_length;
+        let mut challenge_prerequisites = [Pubkey::default(); MAX_PREREQUISITES];
+        challenge_prerequisites[..prerequisites.len()].copy_from_slice(&prerequisites);
+
+        let challenge = Challenge {
+            crux: ctx.accounts.crux.key(),
+            challenge_seed: ctx.accounts.challenge_seed.key(),
+            challenge_posted_ts: now_ts,
+            challenge_expires_ts,
+            tags,
+            title,
+            content_data_url,
+            content_data_hash: ctx.accounts.content_data_hash.key(),
+            reputation,
+            version: LATEST_CHALLENGE_VERSION,
+            bounty_vault: ctx.accounts.bounty_vault.key(),
+            bounty_funder: ctx.accounts.moderator.key(),
+            bounty_lamports,
+            bounty_per_completion,
+            // Token bounty fields are left unset until funded
+            bounty_token_mint: Pubkey::default(),
+            bounty_token_vault: Pubkey::default(),
+            bounty_token_funder: Pubkey::default(),
+            bounty_token_amount: 0,
+            bounty_token_per_completion: 0,
+            required_approvals,
+            challenge_author: ctx.accounts.moderator_profile.key(),
+            max_revisions,
+            max_submissions_per_user,
+            reveal_duration,
+            answer_hash,
+            max_answer_attempts,
+            answer_cooldown,
+            verifier_program,
+            verifier_params,
+            challenge_opens_ts,
+            evaluation_deadline_ts,
+            max_completions,
+            completion_count: 0,
+            reputation_schedule,
+            ranked_completions: [RankedCompletion::default(); REPUTATION_SCHEDULE_LENGTH],
+            prerequisites: challenge_prerequisites,
+            min_reputation_score,
+            period_length,
+            challenge_status: if is_draft { ChallengeStatus::Draft } else { ChallengeStatus::Open },
+        };
+
+        // Serialize the challenge with its discriminator, sizing the account to fit its tags, title and content_data_url
+        let mut challenge_data: Vec<u8> = Vec::new();
+        challenge.try_serialize(&mut challenge_data)?;
 
         create_pda_with_space(
             &[
@@ -117,27 +225,18 @@ pub fn handler(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: String, co
                 &[bump],
             ],
             &ctx.accounts.challenge,
-            8 + 80 + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 40,
+            challenge_data.len(),
             ctx.program_id,
             &ctx.accounts.moderator.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
         )?;
 
-        // Perform all necessary conversions to bytes
-        let disc = hash("account:Challenge".as_bytes());
-
-        // Pack byte data into Challenge account
-        let mut challenge_account_raw = ctx.accounts.challenge.data.borrow_mut();
-        challenge_account_raw[..8].clone_from_slice(&disc.to_bytes()[..8]);
-        challenge_account_raw[8..40].clone_from_slice(&ctx.accounts.crux.key().to_bytes());
-        challenge_account_raw[40..72].clone_from_slice(&ctx.accounts.challenge_seed.key().to_bytes());
-        challenge_account_raw[72..80].clone_from_slice(&now_ts.to_le_bytes());
-        challenge_account_raw[80..88].clone_from_slice(&challenge_expires_ts.to_le_bytes());
-        challenge_account_raw[88..tag_slice_end_byte].clone_from_slice(tag_buffer_as_slice);
-        challenge_account_raw[tag_slice_end_byte..title_slice_end_byte].clone_from_slice(title_buffer_as_slice);
-        challenge_account_raw[title_slice_end_byte..content_data_url_slice_end_byte].clone_from_slice(content_data_url_buffer_as_slice);
-        challenge_account_raw[content_data_url_slice_end_byte..content_data_url_slice_end_byte+32].clone_from_slice(&ctx.accounts.content_data_hash.key().to_bytes());
-        challenge_account_raw[content_data_url_slice_end_byte+32..content_data_url_slice_end_byte+40].clone_from_slice(&reputation.to_le_bytes());
+        ctx.accounts.challenge.data.borrow_mut().copy_from_slice(&challenge_data);
+
+        // Fund the challenge's bounty vault
+        if bounty_lamports > 0 {
+            ctx.accounts.transfer_bounty_ctx(bounty_lamports)?;
+        }
 
         // Increment challenge count in crux's state account
         let crux = &mut ctx.accounts.crux;
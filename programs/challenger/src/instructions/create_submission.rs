@@ -2,12 +2,13 @@ use anchor_lang::prelude::*;
 
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile};
+use crate::state::{load_co_author_profile, Challenge, CoAuthor, Crux, Submission, SubmissionEntries, SubmissionState, SubmissionTeam, TokenLedger, UserProfile, LATEST_SUBMISSION_VERSION, MAX_CO_AUTHORS, REVISION_HISTORY_LENGTH};
 use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_treasury: u8, bump_user_profile: u8, bump_challenge: u8)]
+#[instruction(bump_treasury: u8, bump_user_profile: u8, bump_challenge: u8, content_data_url: String, submission_index: u64)]
 pub struct CreateSubmission<'info> {
 
     // Crux
@@ -35,14 +36,32 @@ pub struct CreateSubmission<'info> {
     pub challenge_seed: AccountInfo<'info>,
 
     // Submission PDA account
-    #[account(init, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
-              bump, payer = profile_owner, space = 8 + std::mem::size_of::<Submission>())]
+    #[account(init, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission_index)],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<Submission>() + content_data_url.len())]
     pub submission: Box<Account<'info, Submission>>,
 
+    // Entry counters, only required when the challenge accepts multiple submissions per user
+    #[account(init_if_needed, seeds = [b"submission_entries".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<SubmissionEntries>())]
+    pub submission_entries: Option<Box<Account<'info, SubmissionEntries>>>,
+
     /// CHECK:
     // The content data hash of the submission struct
     pub content_data_hash: AccountInfo<'info>,
 
+    // Token accounts, only required when the crux charges fees in an SPL token
+    #[account(mut, token::mint = crux.fee_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, address = crux.crux_treasury_token_account)]
+    pub crux_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [b"token_ledger".as_ref(), crux.key().as_ref(), crux.fee_mint.as_ref()], bump)]
+    pub crux_token_ledger: Option<Box<Account<'info, TokenLedger>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -59,18 +78,153 @@ impl<'info> CreateSubmission<'info> {
         )
             .map_err(Into::into)
     }
+
+    fn transfer_token_payment_ctx(&self, amount: u64) -> Result<()> {
+        match (&self.profile_owner_token_account, &self.crux_treasury_token_account, &self.token_program) {
+            (Some(profile_owner_token_account), Some(crux_treasury_token_account), Some(token_program)) => {
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: profile_owner_token_account.to_account_info(),
+                            to: crux_treasury_token_account.to_account_info(),
+                            authority: self.profile_owner.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )
+            }
+            _ => Err(error!(ErrorCode::TokenAccountsRequired)),
+        }
+    }
 }
 
-pub fn handler(ctx: Context<CreateSubmission>) -> Result<()> {
+pub fn handler(ctx: Context<CreateSubmission>, content_data_url: String, submission_index: u64, submission_team: SubmissionTeam) -> Result<()> {
+
+    let SubmissionTeam { co_author_profiles, weights } = submission_team;
 
     let now_ts: u64 = now_ts()?;
 
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Ensure the challenge has been published, has not been closed, and has opened
+    if !ctx.accounts.challenge.is_open() || (now_ts < ctx.accounts.challenge.challenge_opens_ts) {
+        return Err(error!(ErrorCode::ChallengeNotOpen));
+    }
+
+    // Ensure the challenge has not closed by reaching its maximum number of completions
+    if ctx.accounts.challenge.is_completions_filled() {
+        return Err(error!(ErrorCode::ChallengeCompletionsFilled));
+    }
+
     // Ensure challenge expires timestamp has not yet passed
     let challenge_expires_ts = ctx.accounts.challenge.challenge_expires_ts;
     if now_ts > challenge_expires_ts {
         return Err(error!(ErrorCode::ChallengeExpired));
     }
 
+    // Ensure the user holds the challenge's minimum reputation score
+    if ctx.accounts.user_profile.reputation_score < ctx.accounts.challenge.min_reputation_score {
+        return Err(error!(ErrorCode::InsufficientReputation));
+    }
+
+    // Ensure the user completed each prerequisite challenge, as the submitter or an accepted co-author
+    let user_profile_key = ctx.accounts.user_profile.key();
+    let prerequisite_count = ctx.accounts.challenge.prerequisites().len();
+    for (index, prerequisite) in ctx.accounts.challenge.prerequisites().iter().enumerate() {
+        let prerequisite_submission: Account<Submission> = match ctx.remaining_accounts.get(index) {
+            Some(prerequisite_submission_info) => Account::try_from(prerequisite_submission_info)?,
+            None => return Err(error!(ErrorCode::PrerequisiteNotCompleted)),
+        };
+
+        let is_completed_by_user = (prerequisite_submission.challenge == *prerequisite)
+            && (prerequisite_submission.submission_state == SubmissionState::Completed)
+            && ((prerequisite_submission.user_profile == user_profile_key)
+                || prerequisite_submission.co_authors().iter().any(|co_author| co_author.accepted && (co_author.user_profile == user_profile_key)));
+
+        if !is_completed_by_user {
+            return Err(error!(ErrorCode::PrerequisiteNotCompleted));
+        }
+    }
+
+    // Ensure that the length of the content_data_url string is non-zero and not more than max_url_length characters long,
+    // or that it is withheld until the reveal when the challenge uses commit-reveal
+    let is_commit_reveal = ctx.accounts.challenge.is_commit_reveal();
+    let url_length: u64 = content_data_url.len() as u64;
+    if is_commit_reveal {
+        if url_length > 0 {
+            return Err(error!(ErrorCode::InvalidUrlStringInput));
+        }
+    } else if (url_length == 0) || (url_length > ctx.accounts.crux.crux_constants.max_url_length) {
+        return Err(error!(ErrorCode::InvalidUrlStringInput));
+    }
+
+    // Ensure co-authors are distinct from each other and the submitter, and that weights are either omitted for an equal split or given for every member
+    let co_author_count: usize = co_author_profiles.len();
+
+    if co_author_count > MAX_CO_AUTHORS {
+        return Err(error!(ErrorCode::InvalidCoAuthors));
+    }
+
+    for (index, co_author_profile) in co_author_profiles.iter().enumerate() {
+        if (*co_author_profile == user_profile_key) || co_author_profiles[..index].contains(co_author_profile) {
+            return Err(error!(ErrorCode::InvalidCoAuthors));
+        }
+    }
+
+    // Ensure each co-author profile, passed in order as remaining accounts after the prerequisite submissions, belongs to the challenge's crux
+    let co_author_profile_infos = ctx.remaining_accounts.get(prerequisite_count..).unwrap_or_default();
+    for (index, co_author_profile) in co_author_profiles.iter().enumerate() {
+        load_co_author_profile(co_author_profile_infos, index, *co_author_profile, ctx.accounts.challenge.crux)?;
+    }
+
+    let weights: Vec<u64> = if weights.is_empty() { vec![1; co_author_count + 1] } else { weights };
+    if (weights.len() != co_author_count + 1) || weights.contains(&0) {
+        return Err(error!(ErrorCode::InvalidCoAuthors));
+    }
+
+    // Challenges accepting multiple submissions track the user's entries, and the user's first entry counts as submitting to the challenge
+    let max_submissions_per_user = ctx.accounts.challenge.max_submissions_per_user;
+    let is_first_entry: bool = if max_submissions_per_user > 0 {
+        let challenge_key = ctx.accounts.challenge.key();
+
+        let submission_entries = match &mut ctx.accounts.submission_entries {
+            Some(submission_entries) => submission_entries,
+            None => return Err(error!(ErrorCode::InvalidSubmissionIndex)),
+        };
+
+        if submission_index != submission_entries.next_submission_index {
+            return Err(error!(ErrorCode::InvalidSubmissionIndex));
+        }
+
+        if submission_entries.entry_count >= max_submissions_per_user {
+            return Err(error!(ErrorCode::SubmissionLimitReached));
+        }
+
+        submission_entries.challenge = challenge_key;
+        submission_entries.user_profile = user_profile_key;
+        submission_entries.entry_count.try_add_assign(1)?;
+        submission_entries.next_submission_index.try_add_assign(1)?;
+
+        submission_entries.entry_count == 1
+    } else if ctx.accounts.challenge.is_recurring() {
+
+        // Each period of a recurring challenge takes its own submission, indexed by the current period
+        if submission_index != ctx.accounts.challenge.period_index(now_ts)? {
+            return Err(error!(ErrorCode::InvalidSubmissionIndex));
+        }
+
+        true
+    } else {
+        if submission_index != 0 {
+            return Err(error!(ErrorCode::InvalidSubmissionIndex));
+        }
+
+        true
+    };
+
     // Record Submission's State
     let submission = &mut ctx.accounts.submission;
     submission.challenge = ctx.accounts.challenge.key();
@@ -79,14 +233,68 @@ pub fn handler(ctx: Context<CreateSubmission>) -> Result<()> {
     submission.submission_posted_ts = now_ts;
     submission.most_recent_engagement_ts = now_ts;
 
-    submission.content_data_hash = ctx.accounts.content_data_hash.key();
+    // Commit-reveal challenges receive a commitment in place of the content data hash
+    if is_commit_reveal {
+        submission.content_data_hash = Pubkey::default();
+        submission.content_commitment = ctx.accounts.content_data_hash.key();
+    } else {
+        submission.content_data_hash = ctx.accounts.content_data_hash.key();
+        submission.content_commitment = Pubkey::default();
+    }
+    submission.awaiting_reveal = is_commit_reveal;
+    submission.content_data_url = content_data_url;
+
     submission.submission_state = SubmissionState::Pending;
+    submission.version = LATEST_SUBMISSION_VERSION;
+    submission.awarded_reputation = 0;
+    submission.bounty_paid = false;
+    submission.score_bps = 0;
+
+    submission.review_round = 0;
+    submission.approval_count = 0;
+    submission.rejection_count = 0;
+    submission.revision_request_count = 0;
+    submission.approval_score_bps_total = 0;
+
+    submission.appeal_content_data_hash = Pubkey::default();
+    submission.appeal_fee_paid = 0;
+    submission.appeal_fee_mint = Pubkey::default();
+
+    submission.revision_count = 0;
+    submission.previous_content_data_hashes = [Pubkey::default(); REVISION_HISTORY_LENGTH];
+
+    submission.submission_index = submission_index;
+
+    submission.submitter_weight = weights[0];
+    submission.co_author_count = co_author_count as u8;
+    submission.co_authors = [CoAuthor::default(); MAX_CO_AUTHORS];
+    for (index, co_author_profile) in co_author_profiles.iter().enumerate() {
+        submission.co_authors[index] = CoAuthor {
+            user_profile: *co_author_profile,
+            weight: weights[index + 1],
+            awarded_reputation: 0,
+            accepted: false,
+        };
+    }
+
+    submission.answer_attempts = 0;
+    submission.last_answer_attempt_ts = 0;
 
     // Transfer fee for making submission
     let submission_fee = ctx.accounts.crux.crux_fees.submission_fee;
 
     if submission_fee > 0 {
-        ctx.accounts.transfer_payment_ctx(submission_fee)?;
+        if ctx.accounts.crux.has_fee_mint() {
+            ctx.accounts.transfer_token_payment_ctx(submission_fee)?;
+
+            let crux_token_ledger = ctx.accounts.crux_token_ledger.as_mut().ok_or(error!(ErrorCode::TokenAccountsRequired))?;
+            crux_token_ledger.total_token_fees_collected.try_add_assign(submission_fee)?;
+        } else {
+            ctx.accounts.transfer_payment_ctx(submission_fee)?;
+
+            let crux = &mut ctx.accounts.crux;
+            crux.crux_ledger.total_fees_collected.try_add_assign(submission_fee)?;
+        }
     }
 
     // Increment submission count in crux's state account
@@ -95,7 +303,9 @@ pub fn handler(ctx: Context<CreateSubmission>) -> Result<()> {
 
     // Increment submission count in user profile's state account
     let user_profile = &mut ctx.accounts.user_profile;
-    user_profile.challenges_submitted.try_add_assign(1)?;
+    if is_first_entry {
+        user_profile.challenges_submitted.try_add_assign(1)?;
+    }
 
     // Update user profile's most recent engagement ts
     user_profile.most_recent_engagement_ts = now_ts;
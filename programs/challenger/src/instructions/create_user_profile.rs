@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{Crux, UserProfile};
-use prog_common::{now_ts, TryAdd};
+use crate::state::{Crux, TokenLedger, UserProfile, LATEST_USER_PROFILE_VERSION};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_treasury: u8)]
@@ -24,6 +25,18 @@ pub struct CreateUserProfile<'info> {
               bump, payer = profile_owner, space = 8 + std::mem::size_of::<UserProfile>())]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    // Token accounts, only required when the crux charges fees in an SPL token
+    #[account(mut, token::mint = crux.fee_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, address = crux.crux_treasury_token_account)]
+    pub crux_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [b"token_ledger".as_ref(), crux.key().as_ref(), crux.fee_mint.as_ref()], bump)]
+    pub crux_token_ledger: Option<Box<Account<'info, TokenLedger>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,15 +53,49 @@ impl<'info> CreateUserProfile<'info> {
         )
             .map_err(Into::into)
     }
+
+    fn transfer_token_payment_ctx(&self, amount: u64) -> Result<()> {
+        match (&self.profile_owner_token_account, &self.crux_treasury_token_account, &self.token_program) {
+            (Some(profile_owner_token_account), Some(crux_treasury_token_account), Some(token_program)) => {
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: profile_owner_token_account.to_account_info(),
+                            to: crux_treasury_token_account.to_account_info(),
+                            authority: self.profile_owner.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )
+            }
+            _ => Err(error!(ErrorCode::TokenAccountsRequired)),
+        }
+    }
 }
 
 pub fn handler(ctx: Context<CreateUserProfile>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.profile_creation_paused {
+        return Err(error!(ErrorCode::ProfileCreationPaused));
+    }
+
     let crux_profile_fee = ctx.accounts.crux.crux_fees.profile_fee;
 
     if crux_profile_fee > 0 {
-        ctx.accounts.transfer_payment_ctx(crux_profile_fee)?;
+        if ctx.accounts.crux.has_fee_mint() {
+            ctx.accounts.transfer_token_payment_ctx(crux_profile_fee)?;
+
+            let crux_token_ledger = ctx.accounts.crux_token_ledger.as_mut().ok_or(error!(ErrorCode::TokenAccountsRequired))?;
+            crux_token_ledger.total_token_fees_collected.try_add_assign(crux_profile_fee)?;
+        } else {
+            ctx.accounts.transfer_payment_ctx(crux_profile_fee)?;
+
+            let crux = &mut ctx.accounts.crux;
+            crux.crux_ledger.total_fees_collected.try_add_assign(crux_profile_fee)?;
+        }
     }
 
     // Record User Profile's State
@@ -64,6 +111,7 @@ pub fn handler(ctx: Context<CreateUserProfile>) -> Result<()> {
 
     // user_profile.nft_pfp_token_mint = ;
     user_profile.is_moderator = false;
+    user_profile.version = LATEST_USER_PROFILE_VERSION;
 
     // Increment user profile count in crux state's account
     let crux = &mut ctx.accounts.crux;
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
+use crate::bounty::{refund_token_bounty_and_close_vault, BountyTokenAccounts};
 use crate::state::{Challenge, Crux, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_moderator_profile: u8, bump_challenge: u8)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8, bump_bounty_vault: u8)]
 pub struct DeleteChallenge<'info> {
 
     // Crux
@@ -20,16 +22,37 @@ pub struct DeleteChallenge<'info> {
 
     // Challenge PDA account and seed
     #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
-              bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault, has_one = bounty_funder)]
     pub challenge: Box<Account<'info, Challenge>>,
 
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
+    /// CHECK:
+    #[account(mut, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump = bump_bounty_vault)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    /// CHECK: Receives any unclaimed bounty along with the bounty vault's rent
+    #[account(mut)]
+    pub bounty_funder: AccountInfo<'info>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
 
+    // Token accounts, only required when the challenge holds a token bounty
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = challenge.bounty_token_funder)]
+    pub bounty_token_funder_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -41,6 +64,27 @@ pub fn handler(ctx: Context<DeleteChallenge>) -> Result<()> {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
+    if ctx.accounts.challenge.is_archived() {
+        return Err(error!(ErrorCode::ChallengeArchived));
+    }
+
+    // Return any unclaimed token bounty to the funder and close the bounty token vault
+    if ctx.accounts.challenge.has_token_bounty() {
+        refund_token_bounty_and_close_vault(
+            &ctx.accounts.crux,
+            BountyTokenAccounts {
+                crux_authority: &ctx.accounts.crux_authority,
+                bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                receiver_token_account: &ctx.accounts.bounty_token_funder_token_account,
+                token_program: &ctx.accounts.token_program,
+            },
+            ctx.accounts.receiver.to_account_info(),
+        )?;
+
+        msg!("{} tokens of unclaimed bounty returned to {}",
+             ctx.accounts.challenge.bounty_token_amount, ctx.accounts.challenge.bounty_token_funder);
+    }
+
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
     let receiver = &mut ctx.accounts.receiver;
 
@@ -48,6 +92,14 @@ pub fn handler(ctx: Context<DeleteChallenge>) -> Result<()> {
     let challenge_account_info = &mut (*ctx.accounts.challenge).to_account_info();
     close_account(challenge_account_info, receiver)?;
 
+    // Close the bounty vault, returning any unclaimed bounty to the funder
+    let unclaimed_bounty_lamports = ctx.accounts.challenge.bounty_lamports;
+    let bounty_funder = &mut ctx.accounts.bounty_funder;
+    let bounty_vault_account_info = &mut ctx.accounts.bounty_vault.to_account_info();
+    close_account(bounty_vault_account_info, bounty_funder)?;
+
+    msg!("{} lamports of unclaimed bounty returned to {}", unclaimed_bounty_lamports, ctx.accounts.bounty_funder.key());
+
     // Decrement challenge count in crux's state
     let crux = &mut ctx.accounts.crux;
     crux.crux_counts.challenge_count.try_sub_assign(1)?;
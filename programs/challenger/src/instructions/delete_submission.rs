@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile};
+use crate::state::{Challenge, Crux, Submission, SubmissionEntries, SubmissionState, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -26,10 +26,16 @@ pub struct DeleteSubmission<'info> {
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
-    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
               bump = bump_submission, has_one = challenge, has_one = user_profile)]
     pub submission: Box<Account<'info, Submission>>,
 
+    // Entry counters, only required when the challenge accepts multiple submissions per user
+    #[account(mut, seeds = [b"submission_entries".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump, has_one = challenge, has_one = user_profile)]
+    pub submission_entries: Option<Box<Account<'info, SubmissionEntries>>>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -59,9 +65,23 @@ pub fn handler(ctx: Context<DeleteSubmission>) -> Result<()> {
     let crux = &mut ctx.accounts.crux;
     crux.crux_counts.submission_count.try_sub_assign(1)?;
 
-    // Decrement submission count in user profile's state
+    // Decrement submission count in user profile's state once the user holds no entries for the challenge
+    let is_last_entry: bool = if ctx.accounts.challenge.max_submissions_per_user > 0 {
+        let submission_entries = match &mut ctx.accounts.submission_entries {
+            Some(submission_entries) => submission_entries,
+            None => return Err(error!(ErrorCode::InvalidSubmissionIndex)),
+        };
+
+        submission_entries.entry_count.try_sub_assign(1)?;
+        submission_entries.entry_count == 0
+    } else {
+        true
+    };
+
     let user_profile = &mut ctx.accounts.user_profile;
-    user_profile.challenges_submitted.try_sub_assign(1)?;
+    if is_last_entry {
+        user_profile.challenges_submitted.try_sub_assign(1)?;
+    }
 
     // Update user profile's most recent engagement ts
     user_profile.most_recent_engagement_ts = now_ts;
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Crux, Submission, UserProfile};
+use crate::state::{Challenge, Crux, Submission, SubmissionEntries, SubmissionState, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -27,17 +27,23 @@ pub struct DeleteSubmissionModerator<'info> {
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Challenge PDA account and seed
-    #[account(seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
               bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
     pub challenge: Box<Account<'info, Challenge>>,
 
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
-    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
               bump = bump_submission, has_one = challenge, has_one = user_profile)]
     pub submission: Box<Account<'info, Submission>>,
 
+    // Entry counters, only required when the challenge accepts multiple submissions per user
+    #[account(mut, seeds = [b"submission_entries".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump, has_one = challenge, has_one = user_profile)]
+    pub submission_entries: Option<Box<Account<'info, SubmissionEntries>>>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -53,6 +59,34 @@ pub fn handler(ctx: Context<DeleteSubmissionModerator>) -> Result<()> {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
+    // Ensure the moderator is not deleting their own or their team's submission
+    let moderator_profile_key = ctx.accounts.moderator_profile.key();
+    if (moderator_profile_key == ctx.accounts.user_profile.key())
+        || ctx.accounts.submission.co_authors().iter().any(|co_author| co_author.user_profile == moderator_profile_key) {
+        return Err(error!(ErrorCode::SelfEvaluationForbidden));
+    }
+
+    // Reverse the rewards credited to the user profile and any co-authors if a completed submission is removed
+    if ctx.accounts.submission.submission_state == SubmissionState::Completed {
+        let awarded_reputation = ctx.accounts.submission.awarded_reputation;
+
+        let submission_key = ctx.accounts.submission.key();
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.revoke_completion(submission_key)?;
+
+        let submission = &mut ctx.accounts.submission;
+        submission.reverse_co_authors(ctx.accounts.crux.key(), ctx.remaining_accounts)?;
+
+        let user_profile = &mut ctx.accounts.user_profile;
+        user_profile.challenges_completed.try_sub_assign(1)?;
+        user_profile.reputation_score.try_sub_assign(awarded_reputation)?;
+
+        // A deleted completion no longer fills its period in the submitter's streak
+        if ctx.accounts.challenge.is_recurring() {
+            user_profile.revoke_period_completion(ctx.accounts.challenge.key(), ctx.accounts.submission.submission_index);
+        }
+    }
+
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
     let receiver = &mut ctx.accounts.receiver;
 
@@ -64,9 +98,23 @@ pub fn handler(ctx: Context<DeleteSubmissionModerator>) -> Result<()> {
     let crux = &mut ctx.accounts.crux;
     crux.crux_counts.submission_count.try_sub_assign(1)?;
 
-    // Decrement submission count in user profile's state
+    // Decrement submission count in user profile's state once the user holds no entries for the challenge
+    let is_last_entry: bool = if ctx.accounts.challenge.max_submissions_per_user > 0 {
+        let submission_entries = match &mut ctx.accounts.submission_entries {
+            Some(submission_entries) => submission_entries,
+            None => return Err(error!(ErrorCode::InvalidSubmissionIndex)),
+        };
+
+        submission_entries.entry_count.try_sub_assign(1)?;
+        submission_entries.entry_count == 0
+    } else {
+        true
+    };
+
     let user_profile = &mut ctx.accounts.user_profile;
-    user_profile.challenges_submitted.try_sub_assign(1)?;
+    if is_last_entry {
+        user_profile.challenges_submitted.try_sub_assign(1)?;
+    }
 
     // Update moderator profile's most recent engagement ts
     let moderator_profile = &mut ctx.accounts.moderator_profile;
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile};
-use prog_common::{now_ts, errors::ErrorCode};
+use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile, REVISION_HISTORY_LENGTH};
+use prog_common::{now_ts, serialize_with_realloc, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
@@ -10,6 +10,7 @@ pub struct EditSubmission<'info> {
     // Crux
     pub crux: Box<Account<'info, Crux>>,
 
+    #[account(mut)]
     pub profile_owner: Signer<'info>,
 
     // The user profile
@@ -26,7 +27,8 @@ pub struct EditSubmission<'info> {
     pub challenge_seed: AccountInfo<'info>,
 
     // Submission PDA account
-    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
               bump = bump_submission, has_one = challenge, has_one = user_profile)]
     pub submission: Box<Account<'info, Submission>>,
 
@@ -37,28 +39,80 @@ pub struct EditSubmission<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<EditSubmission>) -> Result<()> {
+pub fn handler(ctx: Context<EditSubmission>, new_content_data_url: String) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
-    // Ensure submission is not completed
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Ensure submission is not completed or under appeal
     let submission_state: SubmissionState = ctx.accounts.submission.submission_state;
-    if submission_state == SubmissionState::Completed {
+    if (submission_state == SubmissionState::Completed) || (submission_state == SubmissionState::Appealed) {
+        return Err(error!(ErrorCode::AccountCannotBeEdited));
+    }
+
+    // Ensure committed content is not replaced before it has been revealed
+    if ctx.accounts.submission.awaiting_reveal {
         return Err(error!(ErrorCode::AccountCannotBeEdited));
     }
 
+    // Ensure the challenge has not been closed
+    if !ctx.accounts.challenge.is_open() {
+        return Err(error!(ErrorCode::ChallengeNotOpen));
+    }
+
     // Ensure challenge has not expired
     let challenge_expires_ts = ctx.accounts.challenge.challenge_expires_ts;
     if now_ts > challenge_expires_ts {
         return Err(error!(ErrorCode::ChallengeExpired));
     }
 
-    // Update submission account's most recent engagement timestamp and overwrite with the new content data hash
+    // Ensure that the length of the new content_data_url string is non-zero and not more than max_url_length characters long
+    let new_url_length: u64 = new_content_data_url.len() as u64;
+    if (new_url_length == 0) || (new_url_length > ctx.accounts.crux.crux_constants.max_url_length) {
+        return Err(error!(ErrorCode::InvalidUrlStringInput));
+    }
+
+    // Ensure the submission has not used up the challenge's revisions
+    let max_revisions = ctx.accounts.challenge.max_revisions;
+    if (max_revisions > 0) && (ctx.accounts.submission.revision_count >= max_revisions) {
+        return Err(error!(ErrorCode::RevisionLimitReached));
+    }
+
+    // Record the replaced content data hash in the submission's revision history
     let submission = &mut ctx.accounts.submission;
+    let history_index = (submission.revision_count % REVISION_HISTORY_LENGTH as u64) as usize;
+    submission.previous_content_data_hashes[history_index] = submission.content_data_hash;
+    submission.revision_count.try_add_assign(1)?;
+
+    // Votes cast on the previous content no longer apply, so the revision is reviewed in a new round
+    if (submission.approval_count > 0) || (submission.rejection_count > 0) || (submission.revision_request_count > 0) {
+        submission.review_round.try_add_assign(1)?;
+        submission.approval_count = 0;
+        submission.rejection_count = 0;
+        submission.revision_request_count = 0;
+        submission.approval_score_bps_total = 0;
+    }
+
+    // Update submission account's most recent engagement timestamp and overwrite with the new content data hash
     submission.most_recent_engagement_ts = now_ts;
+    submission.content_data_url = new_content_data_url;
     submission.content_data_hash = ctx.accounts.new_content_data_hash.key();
     submission.submission_state = SubmissionState::Pending;
 
+    // Resize the submission to exactly fit the new content_data_url, so that a shorter url leaves no stale bytes behind
+    let space: usize = 8 + std::mem::size_of::<Submission>() + submission.content_data_url.len();
+
+    serialize_with_realloc(
+        &***submission,
+        &submission.to_account_info(),
+        space,
+        &ctx.accounts.profile_owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     // Update user profile's most recent engagement ts
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.most_recent_engagement_ts = now_ts;
@@ -1,15 +1,19 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
-use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile};
-use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+use crate::bounty::{complete_submission, BountyTokenAccounts, CompletionAccounts};
+use crate::state::{Challenge, Crux, Evaluation, Review, Submission, SubmissionDecision, SubmissionState, UserProfile};
+use prog_common::{now_ts, TryAdd, TryDiv, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+#[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_bounty_vault: u8,
+              evaluation: Evaluation)]
 pub struct EvaluateSubmission<'info> {
 
     // Crux
     pub crux: Box<Account<'info, Crux>>,
 
+    #[account(mut)]
     pub moderator: Signer<'info>,
 
     // The moderator profile
@@ -17,7 +21,8 @@ pub struct EvaluateSubmission<'info> {
               bump = bump_moderator_profile, has_one = crux, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
-    /// CHECK:
+    /// CHECK: Used for seed verification of user profile pda account and receives any bounty payout
+    #[account(mut)]
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
@@ -26,48 +31,225 @@ pub struct EvaluateSubmission<'info> {
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Challenge PDA account and seed
-    #[account(seeds = [b"challenge".as_ref(), crux.key().as_ref(),challenge_seed.key().as_ref()],
-              bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(),challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault)]
     pub challenge: Box<Account<'info, Challenge>>,
 
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
+    /// CHECK:
+    #[account(mut, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump = bump_bounty_vault)]
+    pub bounty_vault: AccountInfo<'info>,
+
     // Submission PDA account
-    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
               bump = bump_submission, has_one = challenge, has_one = user_profile)]
     pub submission: Box<Account<'info, Submission>>,
 
+    // Review PDA recording the moderator's vote in the submission's current review round, which can only be created once
+    #[account(init, seeds = [b"review".as_ref(), submission.key().as_ref(), moderator_profile.key().as_ref(),
+                             &submission.submission_posted_ts.to_le_bytes(), &submission.review_round.to_le_bytes()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<Review>() + evaluation.feedback_content_data_url.len())]
+    pub review: Box<Account<'info, Review>>,
+
+    /// CHECK:
+    // The content data hash of the moderator's feedback
+    pub feedback_content_data_hash: AccountInfo<'info>,
+
+    // Token accounts, only required when the challenge holds a token bounty
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<EvaluateSubmission>, submission_state: SubmissionState) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, EvaluateSubmission<'info>>, evaluation: Evaluation) -> Result<()> {
+
+    let Evaluation { decision: SubmissionDecision { submission_state, score_bps }, feedback_content_data_url } = evaluation;
 
     let now_ts = now_ts()?;
-    let reputation = ctx.accounts.challenge.reputation;
+
+    if ctx.accounts.crux.crux_pause_flags.evaluation_paused {
+        return Err(error!(ErrorCode::EvaluationPaused));
+    }
+
+    // Submissions to an archived challenge are kept as they were when it was archived
+    if ctx.accounts.challenge.is_archived() {
+        return Err(error!(ErrorCode::ChallengeArchived));
+    }
 
     if !ctx.accounts.moderator_profile.is_moderator {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
-    // Update the submission's state account
+    // Ensure the moderator is not evaluating their own or their team's submission
+    let moderator_profile_key = ctx.accounts.moderator_profile.key();
+    if (moderator_profile_key == ctx.accounts.user_profile.key())
+        || ctx.accounts.submission.co_authors().iter().any(|co_author| co_author.user_profile == moderator_profile_key) {
+        return Err(error!(ErrorCode::SelfEvaluationForbidden));
+    }
+
+    // Ensure committed content has been revealed
+    if ctx.accounts.submission.awaiting_reveal {
+        return Err(error!(ErrorCode::SubmissionNotRevealed));
+    }
+
+    // Ensure every co-author has accepted a team submission
+    if !ctx.accounts.submission.co_authors_accepted() {
+        return Err(error!(ErrorCode::CoAuthorsPending));
+    }
+
+    // Ensure the challenge's author is not evaluating it if the crux forbids it
+    if ctx.accounts.crux.crux_policies.forbid_author_evaluation && (ctx.accounts.challenge.challenge_author == ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::AuthorEvaluationForbidden));
+    }
+
+    let previous_submission_state = ctx.accounts.submission.submission_state;
+
+    // Pending submissions are left to the crux's default resolution once the evaluation deadline passes
+    if (previous_submission_state == SubmissionState::Pending) && ctx.accounts.challenge.is_evaluation_deadline_passed(now_ts) {
+        return Err(error!(ErrorCode::EvaluationDeadlinePassed));
+    }
+
+    if !previous_submission_state.can_transition_to(submission_state) {
+        return Err(error!(ErrorCode::InvalidSubmissionStateTransition));
+    }
+
+    // Ensure no more submissions are completed once the challenge has reached its maximum number of completions
+    if (submission_state == SubmissionState::Completed) && ctx.accounts.challenge.is_completions_filled() {
+        return Err(error!(ErrorCode::ChallengeCompletionsFilled));
+    }
+
+    // Ensure completed submissions are given a non-zero score within bounds, and only completed submissions are scored
+    if !submission_state.is_valid_score(score_bps) {
+        return Err(error!(ErrorCode::InvalidScore));
+    }
+
+    // Ensure that the feedback_content_data_url string is not more than max_url_length characters long
+    if (feedback_content_data_url.len() as u64) > ctx.accounts.crux.crux_constants.max_url_length {
+        return Err(error!(ErrorCode::InvalidFeedbackUrlStringInput));
+    }
+
+    // Record the moderator's vote
+    let review = &mut ctx.accounts.review;
+    review.submission = ctx.accounts.submission.key();
+    review.submission_posted_ts = ctx.accounts.submission.submission_posted_ts;
+    review.moderator_profile = ctx.accounts.moderator_profile.key();
+    review.review_round = ctx.accounts.submission.review_round;
+    review.review_ts = now_ts;
+    review.submission_state = submission_state;
+    review.score_bps = score_bps;
+    review.feedback_content_data_hash = ctx.accounts.feedback_content_data_hash.key();
+    review.feedback_content_data_url = feedback_content_data_url;
+
     let submission = &mut ctx.accounts.submission;
     submission.most_recent_engagement_ts = now_ts;
-    submission.submission_state = submission_state;
 
-    // If challenge completed, update the user profile's state account
+    let vote_count = match submission_state {
+        SubmissionState::Completed => {
+            submission.approval_count.try_add_assign(1)?;
+            submission.approval_score_bps_total.try_add_assign(score_bps)?;
+            submission.approval_count
+        }
+        SubmissionState::NeedsRevision => {
+            submission.revision_request_count.try_add_assign(1)?;
+            submission.revision_request_count
+        }
+        _ => {
+            submission.rejection_count.try_add_assign(1)?;
+            submission.rejection_count
+        }
+    };
+
+    // The submission is only decided once the challenge's quorum of matching votes is reached
+    let required_approvals = ctx.accounts.challenge.required_approvals;
+
+    if vote_count < required_approvals {
+        let moderator_profile = &mut ctx.accounts.moderator_profile;
+        moderator_profile.most_recent_engagement_ts = now_ts;
+
+        msg!("Moderator profile with pubkey {} voted {:?} on submission account with address {} ({} of {} required votes)",
+             ctx.accounts.moderator_profile.key(), submission_state, ctx.accounts.submission.key(), vote_count, required_approvals);
+        return Ok(());
+    }
+
+    // Completed submissions are scored with the average of the approving votes
+    let score_bps = if submission_state == SubmissionState::Completed {
+        submission.approval_score_bps_total.try_div(submission.approval_count)?
+    } else {
+        0
+    };
+
+    // Start a new review round so that the decision can later be revisited
+    submission.review_round.try_add_assign(1)?;
+    submission.approval_count = 0;
+    submission.rejection_count = 0;
+    submission.revision_request_count = 0;
+    submission.approval_score_bps_total = 0;
+
+    // Complete the submission with the scored share of the challenge's reputation, or reverse the amount recorded on the submission
+    // when a completion is revoked
+    // Team submissions split the reputation between the submitter and co-authors
     if submission_state == SubmissionState::Completed {
+        complete_submission(
+            CompletionAccounts {
+                crux: &ctx.accounts.crux,
+                challenge: &mut ctx.accounts.challenge,
+                submission: &mut ctx.accounts.submission,
+                user_profile: &mut ctx.accounts.user_profile,
+                bounty_vault: ctx.accounts.bounty_vault.to_account_info(),
+                profile_owner: ctx.accounts.profile_owner.to_account_info(),
+                bounty_token_accounts: BountyTokenAccounts {
+                    crux_authority: &ctx.accounts.crux_authority,
+                    bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                    receiver_token_account: &ctx.accounts.profile_owner_token_account,
+                    token_program: &ctx.accounts.token_program,
+                },
+            },
+            ctx.remaining_accounts,
+            score_bps,
+            now_ts,
+        )?;
+    } else if previous_submission_state == SubmissionState::Completed {
+        let submission_key = ctx.accounts.submission.key();
         let user_profile = &mut ctx.accounts.user_profile;
-        user_profile.challenges_completed.try_add_assign(1)?;
-        user_profile.reputation_score.try_add_assign(reputation)?;
+        let submission = &mut ctx.accounts.submission;
+
+        ctx.accounts.challenge.revoke_completion(submission_key)?;
+        submission.reverse_co_authors(ctx.accounts.crux.key(), ctx.remaining_accounts)?;
+
+        user_profile.challenges_completed.try_sub_assign(1)?;
+        user_profile.reputation_score.try_sub_assign(submission.awarded_reputation)?;
+        submission.awarded_reputation = 0;
+
+        // A revoked period of a recurring challenge no longer counts towards the submitter's streak
+        if ctx.accounts.challenge.is_recurring() {
+            user_profile.revoke_period_completion(ctx.accounts.challenge.key(), submission.submission_index);
+        }
     }
 
+    // Update the submission's state account
+    let submission = &mut ctx.accounts.submission;
+    submission.submission_state = submission_state;
+    submission.score_bps = score_bps;
+
     // Update the moderator profile's state account
     let moderator_profile = &mut ctx.accounts.moderator_profile;
     moderator_profile.most_recent_engagement_ts = now_ts;
 
-    msg!("Submission account with address {} evaluated with submission state {:?}",
-         ctx.accounts.submission.key(), submission_state);
+    msg!("Submission account with address {} evaluated with submission state {:?} and score {} bps",
+         ctx.accounts.submission.key(), submission_state, score_bps);
 
     Ok(())
 }
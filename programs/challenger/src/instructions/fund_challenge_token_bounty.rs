@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::{Challenge, Crux, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_crux_auth: u8, bump_moderator_profile: u8, bump_challenge: u8)]
+pub struct FundChallengeTokenBounty<'info> {
+
+    // Crux
+    #[account(has_one = crux_authority)]
+    pub crux: Box<Account<'info, Crux>>,
+
+    /// CHECK:
+    #[account(seeds = [crux.key().as_ref()], bump = bump_crux_auth)]
+    pub crux_authority: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), moderator.key().as_ref()],
+              bump = bump_moderator_profile, has_one = crux, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // SPL token mint in which the bounty is paid
+    pub bounty_token_mint: Box<Account<'info, Mint>>,
+
+    // Escrow token account holding the challenge's token bounty, owned by the crux authority PDA
+    #[account(init, seeds = [b"bounty_token_vault".as_ref(), challenge.key().as_ref(), bounty_token_mint.key().as_ref()], bump,
+              token::mint = bounty_token_mint, token::authority = crux_authority, payer = moderator)]
+    pub bounty_token_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = bounty_token_mint, token::authority = moderator)]
+    pub moderator_token_account: Box<Account<'info, TokenAccount>>,
+
+    // misc
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundChallengeTokenBounty<'info> {
+
+    fn transfer_bounty_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.moderator_token_account.to_account_info(),
+                to: self.bounty_token_vault.to_account_info(),
+                authority: self.moderator.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<FundChallengeTokenBounty>, bounty_token_amount: u64, bounty_token_per_completion: u64) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure the challenge does not already hold a token bounty
+    if ctx.accounts.challenge.has_token_bounty() {
+        return Err(error!(ErrorCode::TokenBountyAlreadyFunded));
+    }
+
+    // Ensure the bounty paid per completion is non-zero and fits within the total bounty
+    if (bounty_token_per_completion == 0) || (bounty_token_per_completion > bounty_token_amount) {
+        return Err(error!(ErrorCode::InvalidBountyParams));
+    }
+
+    // Fund the challenge's bounty token vault
+    token::transfer(ctx.accounts.transfer_bounty_ctx(), bounty_token_amount)?;
+
+    // Update challenge account's state
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.bounty_token_mint = ctx.accounts.bounty_token_mint.key();
+    challenge.bounty_token_vault = ctx.accounts.bounty_token_vault.key();
+    challenge.bounty_token_funder = ctx.accounts.moderator.key();
+    challenge.bounty_token_amount = bounty_token_amount;
+    challenge.bounty_token_per_completion = bounty_token_per_completion;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge PDA account with address {} funded with a bounty of {} tokens of mint {}",
+         ctx.accounts.challenge.key(), bounty_token_amount, ctx.accounts.bounty_token_mint.key());
+    Ok(())
+}
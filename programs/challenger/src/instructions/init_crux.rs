@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Crux, CruxFees, LATEST_CRUX_VERSION};
+use crate::state::{Crux, CruxConstants, CruxFees, CruxPauseFlags, CruxPolicies, LATEST_CRUX_VERSION};
+use prog_common::errors::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(bump_crux_auth: u8)]
@@ -26,7 +27,13 @@ pub struct InitCrux<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitCrux>, crux_fees: CruxFees) -> Result<()> {
+pub fn handler(ctx: Context<InitCrux>, crux_fees: CruxFees, crux_constants: CruxConstants, crux_policies: CruxPolicies, appeal_fee: u64,
+               auto_accept_after_evaluation_deadline: bool) -> Result<()> {
+
+    // Ensure the crux constants leave room for challenge and submission content
+    if !crux_constants.is_valid() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
 
     let crux = &mut ctx.accounts.crux;
 
@@ -43,6 +50,7 @@ pub fn handler(ctx: Context<InitCrux>, crux_fees: CruxFees) -> Result<()> {
     // Record Crux's State
     crux.version = LATEST_CRUX_VERSION;
     crux.crux_manager = ctx.accounts.crux_manager.key();
+    crux.pending_crux_manager = Pubkey::default();
 
     crux.crux_authority = ctx.accounts.crux_authority.key();
     crux.crux_authority_seed = crux.key();
@@ -55,6 +63,17 @@ pub fn handler(ctx: Context<InitCrux>, crux_fees: CruxFees) -> Result<()> {
     crux.crux_counts.challenge_count = 0;
     crux.crux_counts.submission_count = 0;
 
+    crux.crux_constants = crux_constants;
+
+    crux.crux_ledger.total_fees_collected = 0;
+    crux.crux_ledger.total_paid_out = 0;
+
+    crux.crux_pause_flags = CruxPauseFlags::default();
+
+    crux.crux_policies = crux_policies;
+    crux.appeal_fee = appeal_fee;
+    crux.auto_accept_after_evaluation_deadline = auto_accept_after_evaluation_deadline;
+
     msg!("New crux account with pubkey {} initialized", ctx.accounts.crux.key());
     Ok(())
 }
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, Crux, LegacyChallenge, CHALLENGE_LAYOUT_TAIL_LENGTHS, LATEST_CHALLENGE_VERSION};
+use prog_common::{legacy_layout_length, serialize_with_realloc, try_deserialize_prefix, errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct MigrateChallenge<'info> {
+
+    // Crux which the challenge belongs to, migrated beforehand
+    #[account(has_one = crux_manager)]
+    pub crux: Box<Account<'info, Crux>>,
+
+    /// CHECK: Older challenge layouts can't be loaded as Account<Challenge>, so the account is deserialized in the handler
+    #[account(mut, owner = crate::ID)]
+    pub challenge: AccountInfo<'info>,
+
+    /// CHECK:
+    // Escrow PDA holding the challenge's lamport bounty, created for challenges which predate bounties
+    #[account(init_if_needed, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump, payer = crux_manager, space = 8)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    // Crux manager migrates the challenge, paying for any additional rent required by the current layout
+    // and recorded as the funder of bounty vaults created for challenges which predate bounties
+    #[account(mut)]
+    pub crux_manager: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateChallenge>) -> Result<()> {
+
+    let challenge_info = ctx.accounts.challenge.to_account_info();
+
+    // Edits only ever grew older challenges, so stale bytes may follow the end of a challenge's own layout.
+    // The original fields are decoded first to find where the version and later fixed size fields begin
+    let legacy_length: usize = legacy_layout_length::<LegacyChallenge>(&challenge_info)?;
+
+    let versioned: Challenge = try_deserialize_prefix(&challenge_info, legacy_length + CHALLENGE_LAYOUT_TAIL_LENGTHS[1], std::mem::size_of::<Challenge>())?;
+
+    // Challenges which predate versioning may hold stale bytes where the version would be, so it is only trusted
+    // alongside the challenge's own bounty vault, which every versioned challenge records
+    let old_version: u16 = if (versioned.version <= LATEST_CHALLENGE_VERSION) && (versioned.bounty_vault == ctx.accounts.bounty_vault.key()) {
+        versioned.version
+    }
+    else {
+        0
+    };
+
+    // Decode the challenge's own layout, reading the fields added by later versions as zeroes
+    let layout_length: usize = legacy_length + CHALLENGE_LAYOUT_TAIL_LENGTHS[old_version as usize];
+    let mut challenge: Challenge = try_deserialize_prefix(&challenge_info, layout_length, std::mem::size_of::<Challenge>())?;
+
+    if challenge.crux != ctx.accounts.crux.key() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    if (old_version >= LATEST_CHALLENGE_VERSION) && (challenge_info.data_len() == layout_length) {
+        msg!("Challenge account with address {} is already at version {}", challenge_info.key(), old_version);
+        return Ok(());
+    }
+
+    // Version 1 introduced bounty escrow, so older challenges get an empty bounty vault whose rent is refunded to the crux manager
+    if old_version < 1 {
+        challenge.bounty_vault = ctx.accounts.bounty_vault.key();
+        challenge.bounty_funder = ctx.accounts.crux_manager.key();
+    }
+
+    // Version 2 introduced review quorums, before which a single moderator decided each submission
+    if old_version < 2 {
+        challenge.required_approvals = 1;
+    }
+
+    // Versions 3 to 13 only appended fields whose zeroed defaults keep older challenges behaving as before:
+    // version 3 introduced the challenge author, version 4 the revision limit, version 5 submissions per user,
+    // version 6 commit-reveal, version 7 auto-grading, version 8 verifier programs, version 9 the challenge schedule,
+    // version 10 capped and ranked completions, version 11 prerequisites, version 12 recurrence and version 13 the
+    // challenge status, whose first variant keeps older challenges open
+
+    challenge.version = LATEST_CHALLENGE_VERSION;
+
+    serialize_with_realloc(
+        &challenge,
+        &challenge_info,
+        0,
+        &ctx.accounts.crux_manager.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("Challenge account with address {} migrated from version {} to version {}", challenge_info.key(), old_version, challenge.version);
+    Ok(())
+}
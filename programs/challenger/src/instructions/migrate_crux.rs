@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Crux, CruxConstants, CruxPauseFlags, CruxPolicies, LATEST_CRUX_VERSION};
+use prog_common::{serialize_with_realloc, try_deserialize_with_padding, errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct MigrateCrux<'info> {
+
+    /// CHECK: Older crux layouts can't be loaded as Account<Crux>, so the account is deserialized in the handler
+    #[account(mut, owner = crate::ID)]
+    pub crux: AccountInfo<'info>,
+
+    // Crux manager pays for any additional rent required by the current layout
+    #[account(mut)]
+    pub crux_manager: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateCrux>) -> Result<()> {
+
+    let crux_info = ctx.accounts.crux.to_account_info();
+    let space: usize = 8 + std::mem::size_of::<Crux>();
+
+    let mut crux: Crux = try_deserialize_with_padding(&crux_info, std::mem::size_of::<Crux>())?;
+
+    if crux.crux_manager != ctx.accounts.crux_manager.key() {
+        return Err(error!(ErrorCode::NotCruxManager));
+    }
+
+    let old_version = crux.version;
+
+    if (old_version >= LATEST_CRUX_VERSION) && (crux_info.data_len() >= space) {
+        msg!("Crux account with address {} is already at version {}", crux_info.key(), old_version);
+        return Ok(());
+    }
+
+    // Version 1 introduced configurable crux constants, which default to the previously hardcoded limits
+    if old_version < 1 {
+        crux.crux_constants = CruxConstants::default();
+    }
+
+    // Version 2 introduced pause flags, which leave older cruxes unpaused
+    if old_version < 2 {
+        crux.crux_pause_flags = CruxPauseFlags::default();
+    }
+
+    // Version 3 introduced crux policies, which leave challenge authors free to evaluate as before
+    if old_version < 3 {
+        crux.crux_policies = CruxPolicies::default();
+    }
+
+    // Version 4 introduced appeals, which older cruxes accept without a fee
+    if old_version < 4 {
+        crux.appeal_fee = 0;
+    }
+
+    // Version 5 introduced evaluation deadlines, whose expired submissions older cruxes reject by default
+    if old_version < 5 {
+        crux.auto_accept_after_evaluation_deadline = false;
+    }
+
+    crux.version = LATEST_CRUX_VERSION;
+
+    serialize_with_realloc(
+        &crux,
+        &crux_info,
+        space,
+        &ctx.accounts.crux_manager.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("Crux account with address {} migrated from version {} to version {}", crux_info.key(), old_version, crux.version);
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, Submission, SubmissionState, LATEST_SUBMISSION_VERSION, MAX_SCORE_BPS};
+use prog_common::{serialize_with_realloc, try_deserialize_prefix, errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct MigrateSubmission<'info> {
+
+    /// CHECK: Older submission layouts can't be loaded as Account<Submission>, so the account is deserialized in the handler
+    #[account(mut, owner = crate::ID)]
+    pub submission: AccountInfo<'info>,
+
+    // Challenge which the submission belongs to, used to backfill the reputation awarded to completed submissions
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Anyone may migrate a submission by paying for any additional rent required by the current layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateSubmission>) -> Result<()> {
+
+    let submission_info = ctx.accounts.submission.to_account_info();
+
+    // Edits only ever grew older submissions, so stale bytes may follow the end of a submission's own layout.
+    // The fields up to the content data url's length are decoded first to find where the submission's layout ends
+    let versioned: Submission = try_deserialize_prefix(&submission_info, Submission::layout_length(7, 0), std::mem::size_of::<Submission>())?;
+
+    if versioned.challenge != ctx.accounts.challenge.key() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    let old_version = versioned.version;
+
+    if old_version > LATEST_SUBMISSION_VERSION {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Decode the submission's own layout, reading the fields added by later versions as zeroes
+    let layout_length: usize = Submission::layout_length(old_version, versioned.content_data_url.len());
+    let mut submission: Submission = try_deserialize_prefix(&submission_info, layout_length, std::mem::size_of::<Submission>())?;
+    let space: usize = 8 + std::mem::size_of::<Submission>() + submission.content_data_url.len();
+
+    if (old_version >= LATEST_SUBMISSION_VERSION) && (submission_info.data_len() == space) {
+        msg!("Submission account with address {} is already at version {}", submission_info.key(), old_version);
+        return Ok(());
+    }
+
+    // Version 2 started recording rewards on the submission, which completed submissions have already received
+    if (old_version < 2) && (submission.submission_state == SubmissionState::Completed) {
+        submission.awarded_reputation = ctx.accounts.challenge.reputation;
+        submission.bounty_paid = true;
+    }
+
+    // Version 3 introduced scored evaluations, before which completed submissions were awarded full reputation
+    if (old_version < 3) && (submission.submission_state == SubmissionState::Completed) {
+        submission.score_bps = MAX_SCORE_BPS;
+    }
+
+    // Versions 4 to 11 only appended fields whose zeroed defaults keep older submissions behaving as before:
+    // version 4 introduced review quorums, version 5 appeals, version 6 revisions, version 7 content urls,
+    // version 8 indexed submissions, version 9 team submissions, version 10 commit-reveal and version 11 answer attempts
+
+    submission.version = LATEST_SUBMISSION_VERSION;
+
+    serialize_with_realloc(
+        &submission,
+        &submission_info,
+        space,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("Submission account with address {} migrated from version {} to version {}", submission_info.key(), old_version, submission.version);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{UserProfile, LATEST_USER_PROFILE_VERSION};
+use prog_common::{serialize_with_realloc, try_deserialize_with_padding};
+
+#[derive(Accounts)]
+pub struct MigrateUserProfile<'info> {
+
+    /// CHECK: Older user profile layouts can't be loaded as Account<UserProfile>, so the account is deserialized in the handler
+    #[account(mut, owner = crate::ID)]
+    pub user_profile: AccountInfo<'info>,
+
+    // Anyone may migrate a user profile by paying for any additional rent required by the current layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateUserProfile>) -> Result<()> {
+
+    let user_profile_info = ctx.accounts.user_profile.to_account_info();
+    let space: usize = 8 + std::mem::size_of::<UserProfile>();
+
+    let mut user_profile: UserProfile = try_deserialize_with_padding(&user_profile_info, std::mem::size_of::<UserProfile>())?;
+
+    let old_version = user_profile.version;
+
+    if (old_version >= LATEST_USER_PROFILE_VERSION) && (user_profile_info.data_len() >= space) {
+        msg!("User profile account with address {} is already at version {}", user_profile_info.key(), old_version);
+        return Ok(());
+    }
+
+    user_profile.version = LATEST_USER_PROFILE_VERSION;
+
+    serialize_with_realloc(
+        &user_profile,
+        &user_profile_info,
+        space,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("User profile account with address {} migrated from version {} to version {}", user_profile_info.key(), old_version, user_profile.version);
+    Ok(())
+}
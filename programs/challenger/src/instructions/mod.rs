@@ -1,6 +1,11 @@
+pub mod accept_co_authorship;
+pub mod accept_crux_manager;
 pub mod add_moderator;
+pub mod appeal_submission;
 pub mod close_account;
 pub mod close_crux;
+pub mod close_review;
+pub mod close_unrevealed_submission;
 pub mod create_challenge;
 pub mod create_submission;
 pub mod create_user_profile;
@@ -12,14 +17,34 @@ pub mod edit_challenge;
 pub mod edit_submission;
 pub mod edit_user_profile;
 pub mod evaluate_submission;
+pub mod fund_challenge_token_bounty;
 pub mod init_crux;
+pub mod migrate_challenge;
+pub mod migrate_crux;
+pub mod migrate_submission;
+pub mod migrate_user_profile;
+pub mod payout_from_token_treasury;
 pub mod payout_from_treasury;
+pub mod propose_crux_manager;
 pub mod remove_moderator;
+pub mod resolve_appeal;
+pub mod resolve_expired_submission;
+pub mod reveal_submission;
+pub mod submit_answer;
+pub mod update_challenge_status;
+pub mod update_crux_fee_mint;
 pub mod update_crux_params;
+pub mod update_crux_pause_flags;
+pub mod verify_submission;
 
+pub use accept_co_authorship::*;
+pub use accept_crux_manager::*;
 pub use add_moderator::*;
+pub use appeal_submission::*;
 pub use close_account::*;
 pub use close_crux::*;
+pub use close_review::*;
+pub use close_unrevealed_submission::*;
 pub use create_challenge::*;
 pub use create_submission::*;
 pub use create_user_profile::*;
@@ -31,7 +56,22 @@ pub use edit_challenge::*;
 pub use edit_submission::*;
 pub use edit_user_profile::*;
 pub use evaluate_submission::*;
+pub use fund_challenge_token_bounty::*;
 pub use init_crux::*;
+pub use migrate_challenge::*;
+pub use migrate_crux::*;
+pub use migrate_submission::*;
+pub use migrate_user_profile::*;
+pub use payout_from_token_treasury::*;
 pub use payout_from_treasury::*;
+pub use propose_crux_manager::*;
 pub use remove_moderator::*;
+pub use resolve_appeal::*;
+pub use resolve_expired_submission::*;
+pub use reveal_submission::*;
+pub use submit_answer::*;
+pub use update_challenge_status::*;
+pub use update_crux_fee_mint::*;
 pub use update_crux_params::*;
+pub use update_crux_pause_flags::*;
+pub use verify_submission::*;
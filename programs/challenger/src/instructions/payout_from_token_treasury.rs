@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::{Crux, TokenLedger, TreasuryPayout};
+use prog_common::{TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_crux_auth: u8, bump_token_treasury: u8)]
+pub struct PayoutFromTokenTreasury<'info> {
+
+    // Crux and Crux Manager
+    #[account(has_one = crux_manager, has_one = crux_authority)]
+    pub crux: Box<Account<'info, Crux>>,
+    pub crux_manager: Signer<'info>,
+
+    /// CHECK:
+    #[account(seeds = [crux.key().as_ref()], bump = bump_crux_auth)]
+    pub crux_authority: AccountInfo<'info>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, seeds = [b"token_treasury".as_ref(), crux.key().as_ref(), mint.key().as_ref()], bump = bump_token_treasury)]
+    pub crux_treasury_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [b"token_ledger".as_ref(), crux.key().as_ref(), mint.key().as_ref()], bump)]
+    pub crux_token_ledger: Box<Account<'info, TokenLedger>>,
+
+    #[account(mut, token::mint = mint)]
+    pub receiver_token_account: Box<Account<'info, TokenAccount>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PayoutFromTokenTreasury<'info> {
+
+    fn transfer_tokens_ctx(&self, receiver_token_account: AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.crux_treasury_token_account.to_account_info(),
+                to: receiver_token_account,
+                authority: self.crux_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, PayoutFromTokenTreasury<'info>>, amount: u64, additional_payouts: Vec<TreasuryPayout>) -> Result<()> {
+
+    // Ensure the treasury token account can cover all payouts
+    let mut total_payout_amount = amount;
+    for payout in additional_payouts.iter() {
+        total_payout_amount.try_add_assign(payout.amount)?;
+    }
+
+    if total_payout_amount > ctx.accounts.crux_treasury_token_account.amount {
+        return Err(error!(ErrorCode::InsufficientTreasuryBalance));
+    }
+
+    let crux_seeds = ctx.accounts.crux.crux_seeds();
+
+    token::transfer(
+        ctx.accounts.transfer_tokens_ctx(ctx.accounts.receiver_token_account.to_account_info()).with_signer(&[&crux_seeds]),
+        amount,
+    )?;
+
+    msg!("{} tokens of mint {} transferred from treasury to {}",
+         amount, ctx.accounts.mint.key(), ctx.accounts.receiver_token_account.key());
+
+    // Pay out any additional receiver token accounts passed in as remaining accounts
+    for payout in additional_payouts.iter() {
+        let receiver_token_account_info = ctx.remaining_accounts.iter()
+            .find(|account| (account.key() == payout.receiver) && account.is_writable)
+            .ok_or(error!(ErrorCode::PayoutReceiverNotProvided))?;
+
+        token::transfer(
+            ctx.accounts.transfer_tokens_ctx(receiver_token_account_info.clone()).with_signer(&[&crux_seeds]),
+            payout.amount,
+        )?;
+
+        msg!("{} tokens of mint {} transferred from treasury to {}",
+             payout.amount, ctx.accounts.mint.key(), payout.receiver);
+    }
+
+    // Record the payouts in the mint's treasury ledger
+    let crux_token_ledger = &mut ctx.accounts.crux_token_ledger;
+    crux_token_ledger.total_token_paid_out.try_add_assign(total_payout_amount)?;
+
+    Ok(())
+}
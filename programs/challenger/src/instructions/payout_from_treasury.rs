@@ -1,14 +1,14 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Crux};
-use prog_common::{TrySub, TryAdd};
+use crate::state::{Crux, TreasuryPayout};
+use prog_common::{TrySub, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_treasury: u8)]
 pub struct PayoutFromTreasury<'info> {
 
     // Crux and Crux Manager
-    #[account(has_one = crux_manager, has_one = crux_treasury)]
+    #[account(mut, has_one = crux_manager, has_one = crux_treasury)]
     pub crux: Box<Account<'info, Crux>>,
     pub crux_manager: Signer<'info>,
 
@@ -24,22 +24,52 @@ pub struct PayoutFromTreasury<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<PayoutFromTreasury>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, PayoutFromTreasury<'info>>, amount: u64, additional_payouts: Vec<TreasuryPayout>) -> Result<()> {
 
     // Get minimum balance for rent exemption for size of 16 bytes
     let minimum_balance_for_rent_exemption: u64 = Rent::get()?.minimum_balance(16);
 
-    let treasury_account_info: &mut AccountInfo = &mut ctx.accounts.crux_treasury.to_account_info();
-    let receiver_account_info: &mut AccountInfo = &mut ctx.accounts.receiver.to_account_info();
+    let treasury_account_info: &AccountInfo = &ctx.accounts.crux_treasury.to_account_info();
 
-    let treasury_lamports_initial = treasury_account_info.lamports();
-    let receiver_lamports_initial = receiver_account_info.lamports();
+    // Ensure the treasury can cover all payouts while remaining rent exempt
+    let mut total_payout_amount = amount;
+    for payout in additional_payouts.iter() {
+        total_payout_amount.try_add_assign(payout.amount)?;
+    }
 
-    let amount = treasury_lamports_initial.try_sub(minimum_balance_for_rent_exemption)?;
-
-    **receiver_account_info.lamports.borrow_mut() = receiver_lamports_initial.try_add(amount)?;
-    **treasury_account_info.lamports.borrow_mut() = minimum_balance_for_rent_exemption;
+    let available_lamports = treasury_account_info.lamports().try_sub(minimum_balance_for_rent_exemption)?;
+    if total_payout_amount > available_lamports {
+        return Err(error!(ErrorCode::InsufficientTreasuryBalance));
+    }
 
+    transfer_from_treasury(treasury_account_info, &ctx.accounts.receiver.to_account_info(), amount)?;
     msg!("{} lamports transferred from treasury to {}", amount, ctx.accounts.receiver.key());
+
+    // Pay out any additional receivers passed in as remaining accounts
+    for payout in additional_payouts.iter() {
+        let receiver_account_info = ctx.remaining_accounts.iter()
+            .find(|account| (account.key() == payout.receiver) && account.is_writable)
+            .ok_or(error!(ErrorCode::PayoutReceiverNotProvided))?;
+
+        transfer_from_treasury(treasury_account_info, receiver_account_info, payout.amount)?;
+        msg!("{} lamports transferred from treasury to {}", payout.amount, payout.receiver);
+    }
+
+    // Record the payouts in the crux's treasury ledger
+    let crux = &mut ctx.accounts.crux;
+    crux.crux_ledger.total_paid_out.try_add_assign(total_payout_amount)?;
+
+    Ok(())
+}
+
+// Auxiliary helper functions
+
+fn transfer_from_treasury(treasury_account_info: &AccountInfo, receiver_account_info: &AccountInfo, lamports: u64) -> Result<()> {
+
+    let treasury_lamports_initial = treasury_account_info.lamports();
+    let receiver_lamports_initial = receiver_account_info.lamports();
+
+    **receiver_account_info.lamports.borrow_mut() = receiver_lamports_initial.try_add(lamports)?;
+    **treasury_account_info.lamports.borrow_mut() = treasury_lamports_initial.try_sub(lamports)?;
     Ok(())
 }
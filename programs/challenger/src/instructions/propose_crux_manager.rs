@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Crux};
+
+#[derive(Accounts)]
+pub struct ProposeCruxManager<'info> {
+
+    // Crux and Crux Manager
+    #[account(mut, has_one = crux_manager)]
+    pub crux: Box<Account<'info, Crux>>,
+    pub crux_manager: Signer<'info>,
+
+    /// CHECK: The proposed crux manager, who must sign accept_crux_manager to complete the handover
+    pub new_crux_manager: AccountInfo<'info>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeCruxManager>) -> Result<()> {
+
+    let crux = &mut ctx.accounts.crux;
+    crux.pending_crux_manager = ctx.accounts.new_crux_manager.key();
+
+    msg!("Crux manager handover to {} proposed", ctx.accounts.new_crux_manager.key());
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::bounty::{complete_submission, BountyTokenAccounts, CompletionAccounts};
+use crate::state::{Challenge, Crux, Submission, SubmissionDecision, SubmissionState, TokenLedger, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_bounty_vault: u8)]
+pub struct ResolveAppeal<'info> {
+
+    // Crux
+    #[account(mut, has_one = crux_treasury)]
+    pub crux: Box<Account<'info, Crux>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), crux.key().as_ref()], bump = bump_treasury)]
+    pub crux_treasury: AccountInfo<'info>,
+
+    // Either the crux manager or a moderator who did not take part in the rejection
+    pub resolver: Signer<'info>,
+
+    // The resolving moderator's profile, not required when the crux manager resolves the appeal
+    #[account(mut, has_one = crux, constraint = resolver_profile.profile_owner == resolver.key())]
+    pub resolver_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    /// CHECK: The resolving moderator's review PDA for the review round which rejected the submission, which must not exist
+    pub previous_review: Option<AccountInfo<'info>>,
+
+    /// CHECK: Used for seed verification of user profile pda account and receives any bounty payout and appeal fee refund
+    #[account(mut)]
+    pub profile_owner: AccountInfo<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump = bump_bounty_vault)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Token accounts, only required when the challenge holds a token bounty or the appeal fee was paid in an SPL token
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [b"token_treasury".as_ref(), crux.key().as_ref(), submission.appeal_fee_mint.as_ref()], bump)]
+    pub crux_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [b"token_ledger".as_ref(), crux.key().as_ref(), submission.appeal_fee_mint.as_ref()], bump)]
+    pub crux_token_ledger: Option<Box<Account<'info, TokenLedger>>>,
+
+    #[account(mut, token::mint = submission.appeal_fee_mint, token::authority = profile_owner)]
+    pub profile_owner_fee_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveAppeal<'info> {
+
+    fn transfer_lamports(&self, from: &AccountInfo<'info>, lamports: u64) -> Result<()> {
+        let from_account_info: &mut AccountInfo = &mut from.to_account_info();
+        let profile_owner_account_info: &mut AccountInfo = &mut self.profile_owner.to_account_info();
+
+        let from_lamports_initial = from_account_info.lamports();
+        let profile_owner_lamports_initial = profile_owner_account_info.lamports();
+
+        **from_account_info.lamports.borrow_mut() = from_lamports_initial.try_sub(lamports)?;
+        **profile_owner_account_info.lamports.borrow_mut() = profile_owner_lamports_initial.try_add(lamports)?;
+        Ok(())
+    }
+
+    fn refund_token_appeal_fee_ctx(&self, amount: u64) -> Result<()> {
+        match (&self.crux_authority, &self.crux_treasury_token_account, &self.profile_owner_fee_token_account, &self.token_program) {
+            (Some(crux_authority), Some(crux_treasury_token_account), Some(profile_owner_fee_token_account), Some(token_program)) => {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: crux_treasury_token_account.to_account_info(),
+                            to: profile_owner_fee_token_account.to_account_info(),
+                            authority: crux_authority.to_account_info(),
+                        },
+                        &[&self.crux.crux_seeds()],
+                    ),
+                    amount,
+                )
+            }
+            _ => Err(error!(ErrorCode::TokenAccountsRequired)),
+        }
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ResolveAppeal<'info>>, decision: SubmissionDecision) -> Result<()> {
+
+    let SubmissionDecision { submission_state, score_bps } = decision;
+
+    let now_ts = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.evaluation_paused {
+        return Err(error!(ErrorCode::EvaluationPaused));
+    }
+
+    // Submissions to an archived challenge are kept as they were when it was archived
+    if ctx.accounts.challenge.is_archived() {
+        return Err(error!(ErrorCode::ChallengeArchived));
+    }
+
+    // Ensure the submission is under appeal
+    if ctx.accounts.submission.submission_state != SubmissionState::Appealed {
+        return Err(error!(ErrorCode::SubmissionNotAppealable));
+    }
+
+    // Ensure the resolver, including a crux manager, is neither the submitter nor one of the submission's co-authors,
+    // whose profiles are found at the resolver's profile address whether or not the resolver passed their profile
+    let (resolver_profile_key, _bump) = Pubkey::find_program_address(
+        &[b"user_profile".as_ref(), ctx.accounts.crux.key().as_ref(), ctx.accounts.resolver.key().as_ref()],
+        ctx.program_id,
+    );
+
+    if (resolver_profile_key == ctx.accounts.user_profile.key())
+        || ctx.accounts.submission.co_authors().iter().any(|co_author| co_author.user_profile == resolver_profile_key) {
+        return Err(error!(ErrorCode::SelfEvaluationForbidden));
+    }
+
+    // Ensure the resolver is the crux manager or a moderator independent of the rejection
+    if ctx.accounts.resolver.key() != ctx.accounts.crux.crux_manager {
+        let resolver_profile = match &ctx.accounts.resolver_profile {
+            Some(resolver_profile) => resolver_profile,
+            None => return Err(error!(ErrorCode::InvalidAppealResolver)),
+        };
+
+        if !resolver_profile.is_moderator {
+            return Err(error!(ErrorCode::ProfileIsNotModerator));
+        }
+
+        if ctx.accounts.crux.crux_policies.forbid_author_evaluation && (ctx.accounts.challenge.challenge_author == resolver_profile.key()) {
+            return Err(error!(ErrorCode::AuthorEvaluationForbidden));
+        }
+
+        // The resolver must not have voted in the review round which rejected the submission
+        let review_round = ctx.accounts.submission.review_round;
+        if review_round > 0 {
+            let (previous_review_key, _bump) = Pubkey::find_program_address(
+                &[
+                    b"review".as_ref(),
+                    ctx.accounts.submission.key().as_ref(),
+                    resolver_profile.key().as_ref(),
+                    &ctx.accounts.submission.submission_posted_ts.to_le_bytes(),
+                    &review_round.try_sub(1)?.to_le_bytes(),
+                ],
+                ctx.program_id,
+            );
+
+            let has_voted = match &ctx.accounts.previous_review {
+                Some(previous_review) => (previous_review.key() != previous_review_key) || !previous_review.data_is_empty(),
+                None => true,
+            };
+
+            if has_voted {
+                return Err(error!(ErrorCode::InvalidAppealResolver));
+            }
+        }
+    }
+
+    // An appeal is either upheld, completing the submission, or dismissed, leaving it rejected
+    if (submission_state != SubmissionState::Completed) && (submission_state != SubmissionState::Rejected) {
+        return Err(error!(ErrorCode::InvalidSubmissionStateTransition));
+    }
+
+    // Ensure completed submissions are given a non-zero score within bounds, and only completed submissions are scored
+    if !submission_state.is_valid_score(score_bps) {
+        return Err(error!(ErrorCode::InvalidScore));
+    }
+
+    if submission_state == SubmissionState::Completed {
+
+        // Refund the appeal fee from the treasury
+        let appeal_fee_paid = ctx.accounts.submission.appeal_fee_paid;
+        if appeal_fee_paid > 0 {
+            let appeal_fee_mint = ctx.accounts.submission.appeal_fee_mint;
+
+            if appeal_fee_mint == Pubkey::default() {
+
+                // Ensure the treasury is either emptied or left rent exempt by the refund, as its collected fees may already have been paid out
+                let minimum_balance_for_rent_exemption: u64 = Rent::get()?.minimum_balance(16);
+                let remaining_lamports = ctx.accounts.crux_treasury.lamports().checked_sub(appeal_fee_paid)
+                    .ok_or(error!(ErrorCode::InsufficientTreasuryBalance))?;
+                if (remaining_lamports > 0) && (remaining_lamports < minimum_balance_for_rent_exemption) {
+                    return Err(error!(ErrorCode::InsufficientTreasuryBalance));
+                }
+
+                ctx.accounts.transfer_lamports(&ctx.accounts.crux_treasury, appeal_fee_paid)?;
+
+                let crux = &mut ctx.accounts.crux;
+                crux.crux_ledger.total_paid_out.try_add_assign(appeal_fee_paid)?;
+            } else {
+                ctx.accounts.refund_token_appeal_fee_ctx(appeal_fee_paid)?;
+
+                let crux_token_ledger = ctx.accounts.crux_token_ledger.as_mut().ok_or(error!(ErrorCode::TokenAccountsRequired))?;
+                crux_token_ledger.total_token_paid_out.try_add_assign(appeal_fee_paid)?;
+            }
+
+            msg!("Appeal fee of {} refunded to {}", appeal_fee_paid, ctx.accounts.profile_owner.key());
+        }
+
+        // Complete the submission with the scored share of the challenge's reputation, split with any co-authors
+        complete_submission(
+            CompletionAccounts {
+                crux: &ctx.accounts.crux,
+                challenge: &mut ctx.accounts.challenge,
+                submission: &mut ctx.accounts.submission,
+                user_profile: &mut ctx.accounts.user_profile,
+                bounty_vault: ctx.accounts.bounty_vault.to_account_info(),
+                profile_owner: ctx.accounts.profile_owner.to_account_info(),
+                bounty_token_accounts: BountyTokenAccounts {
+                    crux_authority: &ctx.accounts.crux_authority,
+                    bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                    receiver_token_account: &ctx.accounts.profile_owner_token_account,
+                    token_program: &ctx.accounts.token_program,
+                },
+            },
+            ctx.remaining_accounts,
+            score_bps,
+            now_ts,
+        )?;
+    }
+
+    // Update the submission's state account
+    let submission = &mut ctx.accounts.submission;
+    submission.most_recent_engagement_ts = now_ts;
+    submission.submission_state = submission_state;
+    submission.score_bps = score_bps;
+    submission.appeal_fee_paid = 0;
+
+    // Update the resolving moderator's state account
+    if let Some(resolver_profile) = &mut ctx.accounts.resolver_profile {
+        resolver_profile.most_recent_engagement_ts = now_ts;
+    }
+
+    msg!("Appeal of submission account with address {} resolved with submission state {:?} and score {} bps",
+         ctx.accounts.submission.key(), submission_state, score_bps);
+
+    Ok(())
+}
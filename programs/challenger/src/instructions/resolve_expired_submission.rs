@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::bounty::{complete_submission, BountyTokenAccounts, CompletionAccounts};
+use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile, MAX_SCORE_BPS};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_bounty_vault: u8)]
+pub struct ResolveExpiredSubmission<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    /// CHECK: Used for seed verification of user profile pda account and receives any bounty payout
+    #[account(mut)]
+    pub profile_owner: AccountInfo<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump = bump_bounty_vault)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Token accounts, only required when the challenge holds a token bounty
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ResolveExpiredSubmission<'info>>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.evaluation_paused {
+        return Err(error!(ErrorCode::EvaluationPaused));
+    }
+
+    // Submissions to an archived challenge are kept as they were when it was archived
+    if ctx.accounts.challenge.is_archived() {
+        return Err(error!(ErrorCode::ChallengeArchived));
+    }
+
+    // Only submissions still pending once the challenge's evaluation deadline has passed are resolved by default
+    if !ctx.accounts.challenge.is_evaluation_deadline_passed(now_ts) {
+        return Err(error!(ErrorCode::EvaluationDeadlineNotReached));
+    }
+
+    if ctx.accounts.submission.submission_state != SubmissionState::Pending {
+        return Err(error!(ErrorCode::InvalidSubmissionStateTransition));
+    }
+
+    // Content that was never revealed can't be accepted, and is cleaned up through close_unrevealed_submission instead
+    if ctx.accounts.submission.awaiting_reveal {
+        return Err(error!(ErrorCode::SubmissionNotRevealed));
+    }
+
+    // Team submissions whose co-authors never all accepted are rejected, as they could not have been evaluated,
+    // as are submissions to challenges which have already reached their maximum number of completions
+    let submission_state = if ctx.accounts.crux.auto_accept_after_evaluation_deadline
+        && ctx.accounts.submission.co_authors_accepted()
+        && !ctx.accounts.challenge.is_completions_filled() {
+        SubmissionState::Completed
+    } else {
+        SubmissionState::Rejected
+    };
+
+    // Votes cast before the deadline are discarded along with the review round
+    let submission = &mut ctx.accounts.submission;
+    submission.review_round.try_add_assign(1)?;
+    submission.approval_count = 0;
+    submission.rejection_count = 0;
+    submission.revision_request_count = 0;
+    submission.approval_score_bps_total = 0;
+    submission.most_recent_engagement_ts = now_ts;
+
+    // Complete the submission with the challenge's full reputation, split with any co-authors, or reject it
+    if submission_state == SubmissionState::Completed {
+        complete_submission(
+            CompletionAccounts {
+                crux: &ctx.accounts.crux,
+                challenge: &mut ctx.accounts.challenge,
+                submission: &mut ctx.accounts.submission,
+                user_profile: &mut ctx.accounts.user_profile,
+                bounty_vault: ctx.accounts.bounty_vault.to_account_info(),
+                profile_owner: ctx.accounts.profile_owner.to_account_info(),
+                bounty_token_accounts: BountyTokenAccounts {
+                    crux_authority: &ctx.accounts.crux_authority,
+                    bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                    receiver_token_account: &ctx.accounts.profile_owner_token_account,
+                    token_program: &ctx.accounts.token_program,
+                },
+            },
+            ctx.remaining_accounts,
+            MAX_SCORE_BPS,
+            now_ts,
+        )?;
+    } else {
+        submission.submission_state = submission_state;
+    }
+
+    msg!("Submission account with address {} resolved with submission state {:?} after the evaluation deadline",
+         ctx.accounts.submission.key(), submission_state);
+    Ok(())
+}
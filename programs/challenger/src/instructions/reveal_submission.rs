@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{Challenge, Crux, Submission, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+pub struct RevealSubmission<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    /// CHECK:
+    // The committed content data hash of the submission struct
+    pub content_data_hash: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevealSubmission<'info> {
+    fn pay_lamports_difference(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, &self.submission.key(), lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.submission.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn handler(ctx: Context<RevealSubmission>, content_data_url: String, salt: [u8; 32]) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Ensure the submission is awaiting its reveal and the challenge is within its reveal period
+    let challenge_expires_ts = ctx.accounts.challenge.challenge_expires_ts;
+    let reveal_ends_ts = challenge_expires_ts.try_add(ctx.accounts.challenge.reveal_duration)?;
+
+    if !ctx.accounts.submission.awaiting_reveal || (now_ts <= challenge_expires_ts) || (now_ts > reveal_ends_ts) {
+        return Err(error!(ErrorCode::RevealNotOpen));
+    }
+
+    // Ensure that the length of the content_data_url string is non-zero and not more than max_url_length characters long
+    let url_length: u64 = content_data_url.len() as u64;
+    if (url_length == 0) || (url_length > ctx.accounts.crux.crux_constants.max_url_length) {
+        return Err(error!(ErrorCode::InvalidUrlStringInput));
+    }
+
+    // Ensure the revealed content matches the commitment, which is bound to the user profile so that it can't be copied by others
+    let content_data_hash = ctx.accounts.content_data_hash.key();
+    let commitment = hashv(&[
+        ctx.accounts.user_profile.key().as_ref(),
+        content_data_hash.as_ref(),
+        content_data_url.as_bytes(),
+        &salt,
+    ]);
+
+    if commitment.to_bytes() != ctx.accounts.submission.content_commitment.to_bytes() {
+        return Err(error!(ErrorCode::InvalidReveal));
+    }
+
+    // Calculate total space required for the revealed content_data_url
+    let new_data_bytes_amount: usize = 8 + std::mem::size_of::<Submission>() + content_data_url.len();
+    let old_data_bytes_amount: usize = ctx.accounts.submission.to_account_info().data_len();
+
+    if new_data_bytes_amount > old_data_bytes_amount {
+
+        let minimum_balance_for_rent_exemption: u64 = Rent::get()?.minimum_balance(new_data_bytes_amount);
+        let lamports_difference: u64 = minimum_balance_for_rent_exemption.try_sub(ctx.accounts.submission.to_account_info().lamports())?;
+
+        // Transfer the required difference in Lamports to accommodate this increase in space
+        ctx.accounts.pay_lamports_difference(lamports_difference)?;
+
+        // Reallocate the submission pda account with the proper byte data size
+        ctx.accounts.submission.to_account_info().realloc(new_data_bytes_amount, false)?;
+    }
+
+    // Record the revealed content on the submission account
+    let submission = &mut ctx.accounts.submission;
+    submission.most_recent_engagement_ts = now_ts;
+    submission.content_data_hash = content_data_hash;
+    submission.content_data_url = content_data_url;
+    submission.awaiting_reveal = false;
+
+    // Update user profile's most recent engagement ts
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Submission PDA account with address {} has been revealed", ctx.accounts.submission.key());
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::bounty::{complete_submission, BountyTokenAccounts, CompletionAccounts};
+use crate::state::{AnswerAttempts, Challenge, Crux, Submission, SubmissionState, UserProfile, MAX_SCORE_BPS};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_bounty_vault: u8)]
+pub struct SubmitAnswer<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump = bump_bounty_vault)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // The user's answer attempts on the challenge, kept apart from their submissions so that deleting or adding entries doesn't reset them
+    #[account(init_if_needed, seeds = [b"answer_attempts".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<AnswerAttempts>())]
+    pub answer_attempts: Box<Account<'info, AnswerAttempts>>,
+
+    // Token accounts, only required when the challenge holds a token bounty
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SubmitAnswer<'info>>, answer: String) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Completing a submission counts as an evaluation, so it is paused along with moderator evaluation
+    if ctx.accounts.crux.crux_pause_flags.evaluation_paused {
+        return Err(error!(ErrorCode::EvaluationPaused));
+    }
+
+    if !ctx.accounts.challenge.is_auto_graded() {
+        return Err(error!(ErrorCode::ChallengeNotAutoGraded));
+    }
+
+    // Ensure the challenge has not been closed or expired
+    if !ctx.accounts.challenge.is_open() {
+        return Err(error!(ErrorCode::ChallengeNotOpen));
+    }
+
+    if now_ts > ctx.accounts.challenge.challenge_expires_ts {
+        return Err(error!(ErrorCode::ChallengeExpired));
+    }
+
+    // Only pending submissions are graded, once any committed content has been revealed and every co-author has accepted
+    if ctx.accounts.submission.submission_state != SubmissionState::Pending {
+        return Err(error!(ErrorCode::InvalidSubmissionStateTransition));
+    }
+
+    if ctx.accounts.submission.awaiting_reveal {
+        return Err(error!(ErrorCode::SubmissionNotRevealed));
+    }
+
+    if !ctx.accounts.submission.co_authors_accepted() {
+        return Err(error!(ErrorCode::CoAuthorsPending));
+    }
+
+    if ctx.accounts.challenge.is_completions_filled() {
+        return Err(error!(ErrorCode::ChallengeCompletionsFilled));
+    }
+
+    // Ensure the user has attempts left on the challenge and has waited out the cooldown since their last attempt
+    let answer_attempts = &mut ctx.accounts.answer_attempts;
+    answer_attempts.challenge = ctx.accounts.challenge.key();
+    answer_attempts.user_profile = ctx.accounts.user_profile.key();
+
+    let max_answer_attempts = ctx.accounts.challenge.max_answer_attempts;
+    if (max_answer_attempts > 0) && (answer_attempts.answer_attempts >= max_answer_attempts) {
+        return Err(error!(ErrorCode::AnswerAttemptsExhausted));
+    }
+
+    let answer_cooldown = ctx.accounts.challenge.answer_cooldown;
+    if (answer_attempts.answer_attempts > 0) && (now_ts < answer_attempts.last_answer_attempt_ts.try_add(answer_cooldown)?) {
+        return Err(error!(ErrorCode::AnswerCooldownActive));
+    }
+
+    // Record the attempt for both the user and the submission, which must be kept even when the answer is wrong
+    answer_attempts.answer_attempts.try_add_assign(1)?;
+    answer_attempts.last_answer_attempt_ts = now_ts;
+
+    let submission = &mut ctx.accounts.submission;
+    submission.answer_attempts.try_add_assign(1)?;
+    submission.last_answer_attempt_ts = now_ts;
+    submission.most_recent_engagement_ts = now_ts;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    // The answer is salted with the challenge's key, so that equal answers to different challenges have different hashes
+    let answer_hash = hashv(&[ctx.accounts.challenge.key().as_ref(), answer.as_bytes()]);
+    if answer_hash.to_bytes() != ctx.accounts.challenge.answer_hash.to_bytes() {
+        msg!("Incorrect answer submitted for submission account with address {} (attempt {})",
+             ctx.accounts.submission.key(), ctx.accounts.submission.answer_attempts);
+        return Ok(());
+    }
+
+    // Complete the submission with the challenge's full reputation, split with any co-authors
+    complete_submission(
+        CompletionAccounts {
+            crux: &ctx.accounts.crux,
+            challenge: &mut ctx.accounts.challenge,
+            submission: &mut ctx.accounts.submission,
+            user_profile: &mut ctx.accounts.user_profile,
+            bounty_vault: ctx.accounts.bounty_vault.to_account_info(),
+            profile_owner: ctx.accounts.profile_owner.to_account_info(),
+            bounty_token_accounts: BountyTokenAccounts {
+                crux_authority: &ctx.accounts.crux_authority,
+                bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                receiver_token_account: &ctx.accounts.profile_owner_token_account,
+                token_program: &ctx.accounts.token_program,
+            },
+        },
+        ctx.remaining_accounts,
+        MAX_SCORE_BPS,
+        now_ts,
+    )?;
+
+    msg!("Submission account with address {} completed with a correct answer", ctx.accounts.submission.key());
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::bounty::{refund_token_bounty_and_close_vault, BountyTokenAccounts};
+use crate::state::{Challenge, ChallengeStatus, Crux, UserProfile};
+use prog_common::{now_ts, close_account, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8)]
+pub struct UpdateChallengeStatus<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), moderator.key().as_ref()],
+              bump = bump_moderator_profile, has_one = crux, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault, has_one = bounty_funder)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK: Closed when the challenge is archived
+    #[account(mut)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    /// CHECK: Receives any unclaimed bounty along with the bounty vaults' rent when the challenge is archived
+    #[account(mut)]
+    pub bounty_funder: AccountInfo<'info>,
+
+    // Token accounts, only required when archiving a challenge which holds a token bounty
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = challenge.bounty_token_funder)]
+    pub bounty_token_funder_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateChallengeStatus>, new_challenge_status: ChallengeStatus) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    let previous_challenge_status = ctx.accounts.challenge.challenge_status;
+    if !previous_challenge_status.can_transition_to(new_challenge_status) {
+        return Err(error!(ErrorCode::InvalidChallengeStatusTransition));
+    }
+
+    // Archived challenges take no further completions, so any unclaimed bounty is returned to its funders and the vaults are closed
+    if new_challenge_status == ChallengeStatus::Archived {
+        if ctx.accounts.challenge.has_token_bounty() {
+            refund_token_bounty_and_close_vault(
+                &ctx.accounts.crux,
+                BountyTokenAccounts {
+                    crux_authority: &ctx.accounts.crux_authority,
+                    bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                    receiver_token_account: &ctx.accounts.bounty_token_funder_token_account,
+                    token_program: &ctx.accounts.token_program,
+                },
+                ctx.accounts.bounty_funder.to_account_info(),
+            )?;
+
+            msg!("{} tokens of unclaimed bounty returned to {}",
+                 ctx.accounts.challenge.bounty_token_amount, ctx.accounts.challenge.bounty_token_funder);
+
+            let challenge = &mut ctx.accounts.challenge;
+            challenge.bounty_token_amount = 0;
+        }
+
+        let unclaimed_bounty_lamports = ctx.accounts.challenge.bounty_lamports;
+        let bounty_funder = &mut ctx.accounts.bounty_funder;
+        let bounty_vault_account_info = &mut ctx.accounts.bounty_vault.to_account_info();
+        close_account(bounty_vault_account_info, bounty_funder)?;
+
+        msg!("{} lamports of unclaimed bounty returned to {}", unclaimed_bounty_lamports, ctx.accounts.bounty_funder.key());
+
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.bounty_lamports = 0;
+    }
+
+    // Update the challenge's status
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.challenge_status = new_challenge_status;
+
+    // Update the moderator profile's most recent engagement timestamp
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge account with address {} moved from status {:?} to status {:?}",
+         ctx.accounts.challenge.key(), previous_challenge_status, new_challenge_status);
+    Ok(())
+}
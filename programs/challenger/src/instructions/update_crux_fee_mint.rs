@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{Crux, TokenLedger};
+use prog_common::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(bump_crux_auth: u8, new_fee_mint: Option<Pubkey>)]
+pub struct UpdateCruxFeeMint<'info> {
+
+    // Crux and Crux Manager
+    #[account(mut, has_one = crux_manager, has_one = crux_authority)]
+    pub crux: Box<Account<'info, Crux>>,
+
+    #[account(mut)]
+    pub crux_manager: Signer<'info>,
+
+    /// CHECK:
+    #[account(seeds = [crux.key().as_ref()], bump = bump_crux_auth)]
+    pub crux_authority: AccountInfo<'info>,
+
+    // SPL token mint in which crux fees are to be charged, only passed when switching to token fees
+    #[account(address = new_fee_mint.unwrap_or_default())]
+    pub fee_mint: Option<Box<Account<'info, Mint>>>,
+
+    // Treasury token account for the fee mint, owned by the crux authority PDA
+    #[account(init_if_needed, seeds = [b"token_treasury".as_ref(), crux.key().as_ref(), new_fee_mint.unwrap_or_default().as_ref()], bump,
+              token::mint = fee_mint, token::authority = crux_authority, payer = crux_manager)]
+    pub crux_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // Ledger of the fee mint's tokens collected into and paid out of the treasury token account
+    #[account(init_if_needed, seeds = [b"token_ledger".as_ref(), crux.key().as_ref(), new_fee_mint.unwrap_or_default().as_ref()], bump,
+              payer = crux_manager, space = 8 + std::mem::size_of::<TokenLedger>())]
+    pub crux_token_ledger: Option<Box<Account<'info, TokenLedger>>>,
+
+    // misc
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateCruxFeeMint>, new_fee_mint: Option<Pubkey>) -> Result<()> {
+
+    // Without a fee mint the crux goes back to charging fees in lamports, while tokens already collected stay in the token treasury
+    let new_fee_mint = match new_fee_mint {
+        Some(new_fee_mint) => new_fee_mint,
+        None => {
+            let crux = &mut ctx.accounts.crux;
+            crux.fee_mint = Pubkey::default();
+            crux.crux_treasury_token_account = Pubkey::default();
+
+            msg!("Crux fees now charged in lamports");
+            return Ok(());
+        }
+    };
+
+    let (crux_treasury_token_account, crux_token_ledger) = match (&ctx.accounts.fee_mint, &ctx.accounts.crux_treasury_token_account, &mut ctx.accounts.crux_token_ledger) {
+        (Some(_), Some(crux_treasury_token_account), Some(crux_token_ledger)) => (crux_treasury_token_account, crux_token_ledger),
+        _ => return Err(error!(ErrorCode::TokenAccountsRequired)),
+    };
+
+    let crux = &mut ctx.accounts.crux;
+    crux.fee_mint = new_fee_mint;
+    crux.crux_treasury_token_account = crux_treasury_token_account.key();
+
+    // Totals carry over if the mint was used for fees before
+    crux_token_ledger.crux = crux.key();
+    crux_token_ledger.mint = new_fee_mint;
+
+    msg!("Crux fees now charged in token mint {} and collected in treasury token account {}",
+         new_fee_mint, crux_treasury_token_account.key());
+    Ok(())
+}
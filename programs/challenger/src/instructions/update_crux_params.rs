@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Crux, CruxFees};
+use crate::state::{Crux, CruxConstants, CruxFees, CruxPolicies};
+use prog_common::errors::ErrorCode;
 
 #[derive(Accounts)]
 pub struct UpdateCruxParams<'info> {
@@ -14,11 +15,25 @@ pub struct UpdateCruxParams<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<UpdateCruxParams>, new_crux_fees: CruxFees) -> Result<()> {
+pub fn handler(ctx: Context<UpdateCruxParams>, new_crux_fees: CruxFees, new_crux_constants: CruxConstants, new_crux_policies: CruxPolicies, new_appeal_fee: u64,
+               new_auto_accept_after_evaluation_deadline: bool) -> Result<()> {
+
+    // Ensure the new crux constants leave room for challenge and submission content
+    if !new_crux_constants.is_valid() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
 
     let crux = &mut ctx.accounts.crux;
     crux.crux_fees = new_crux_fees;
+    crux.crux_constants = new_crux_constants;
+    crux.crux_policies = new_crux_policies;
+    crux.appeal_fee = new_appeal_fee;
+    crux.auto_accept_after_evaluation_deadline = new_auto_accept_after_evaluation_deadline;
 
     msg!("Crux fees now {:?}", crux.crux_fees);
+    msg!("Crux constants now {:?}", crux.crux_constants);
+    msg!("Crux policies now {:?}", crux.crux_policies);
+    msg!("Crux appeal fee now {}", crux.appeal_fee);
+    msg!("Crux auto-accepts submissions after the evaluation deadline: {}", crux.auto_accept_after_evaluation_deadline);
     Ok(())
 }
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Crux, CruxPauseFlags};
+
+#[derive(Accounts)]
+pub struct UpdateCruxPauseFlags<'info> {
+
+    // Crux and Crux Manager
+    #[account(mut, has_one = crux_manager)]
+    pub crux: Box<Account<'info, Crux>>,
+    pub crux_manager: Signer<'info>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateCruxPauseFlags>, new_crux_pause_flags: CruxPauseFlags) -> Result<()> {
+
+    let crux = &mut ctx.accounts.crux;
+    crux.crux_pause_flags = new_crux_pause_flags;
+
+    msg!("Crux pause flags now {:?}", crux.crux_pause_flags);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::bounty::{complete_submission, moved_completion_account_count, BountyTokenAccounts, CompletionAccounts};
+use crate::state::{Challenge, Crux, Submission, SubmissionState, UserProfile, MAX_SCORE_BPS};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_bounty_vault: u8)]
+pub struct VerifySubmission<'info> {
+
+    // Crux
+    pub crux: Box<Account<'info, Crux>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), crux.key().as_ref(), profile_owner.key().as_ref()],
+              bump = bump_user_profile, has_one = crux, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), crux.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = crux, has_one = challenge_seed, has_one = bounty_vault)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"bounty_vault".as_ref(), challenge.key().as_ref()], bump = bump_bounty_vault)]
+    pub bounty_vault: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref(),
+                      &challenge.submission_index_seed(submission.submission_index)],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    /// CHECK: Verifier program named by the challenge, checked in the handler
+    #[account(executable)]
+    pub verifier_program: AccountInfo<'info>,
+
+    // Token accounts, only required when the challenge holds a token bounty
+    /// CHECK:
+    #[account(address = crux.crux_authority)]
+    pub crux_authority: Option<AccountInfo<'info>>,
+
+    #[account(mut, address = challenge.bounty_token_vault)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut, token::mint = challenge.bounty_token_mint, token::authority = profile_owner)]
+    pub profile_owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, VerifySubmission<'info>>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if ctx.accounts.crux.crux_pause_flags.submissions_paused {
+        return Err(error!(ErrorCode::SubmissionsPaused));
+    }
+
+    // Completing a submission counts as an evaluation, so it is paused along with moderator evaluation
+    if ctx.accounts.crux.crux_pause_flags.evaluation_paused {
+        return Err(error!(ErrorCode::EvaluationPaused));
+    }
+
+    if !ctx.accounts.challenge.has_verifier() || (ctx.accounts.verifier_program.key() != ctx.accounts.challenge.verifier_program) {
+        return Err(error!(ErrorCode::ChallengeNotVerifiable));
+    }
+
+    // Ensure the challenge has not been closed or expired
+    if !ctx.accounts.challenge.is_open() {
+        return Err(error!(ErrorCode::ChallengeNotOpen));
+    }
+
+    if now_ts > ctx.accounts.challenge.challenge_expires_ts {
+        return Err(error!(ErrorCode::ChallengeExpired));
+    }
+
+    // Only pending submissions are verified, once any committed content has been revealed and every co-author has accepted
+    if ctx.accounts.submission.submission_state != SubmissionState::Pending {
+        return Err(error!(ErrorCode::InvalidSubmissionStateTransition));
+    }
+
+    if ctx.accounts.submission.awaiting_reveal {
+        return Err(error!(ErrorCode::SubmissionNotRevealed));
+    }
+
+    if !ctx.accounts.submission.co_authors_accepted() {
+        return Err(error!(ErrorCode::CoAuthorsPending));
+    }
+
+    if ctx.accounts.challenge.is_completions_filled() {
+        return Err(error!(ErrorCode::ChallengeCompletionsFilled));
+    }
+
+    // Remaining accounts start with the co-author profiles and the accounts of any completions moved down the reputation schedule,
+    // followed by the accounts passed through to the verifier
+    let co_author_count = ctx.accounts.submission.co_author_count as usize;
+    if ctx.remaining_accounts.len() < co_author_count {
+        return Err(error!(ErrorCode::CoAuthorProfilesRequired));
+    }
+    let moved_account_count = moved_completion_account_count(
+        &ctx.accounts.challenge,
+        ctx.accounts.submission.submission_posted_ts,
+        &ctx.remaining_accounts[co_author_count..],
+    )?;
+    let (completion_remaining_accounts, verifier_accounts) = ctx.remaining_accounts.split_at(co_author_count + moved_account_count);
+
+    // Call the verifier's verify instruction with the submitter's wallet and the challenge's verifier params
+    // No account is passed on as a signer, so that a signer repeated among the remaining accounts can't lend its signature to the verifier
+    let mut account_metas = vec![AccountMeta::new_readonly(ctx.accounts.profile_owner.key(), false)];
+    account_metas.extend(verifier_accounts.iter().map(|account| {
+        if account.is_writable {
+            AccountMeta::new(account.key(), false)
+        } else {
+            AccountMeta::new_readonly(account.key(), false)
+        }
+    }));
+
+    let mut data = hash(b"global:verify").to_bytes()[..8].to_vec();
+    data.extend_from_slice(&ctx.accounts.challenge.verifier_params);
+
+    let mut account_infos = vec![ctx.accounts.profile_owner.to_account_info()];
+    account_infos.extend_from_slice(verifier_accounts);
+    account_infos.push(ctx.accounts.verifier_program.to_account_info());
+
+    invoke(
+        &Instruction { program_id: ctx.accounts.verifier_program.key(), accounts: account_metas, data },
+        &account_infos,
+    )?;
+
+    // The verifier confirms the submission by returning true
+    let is_verified = match get_return_data() {
+        Some((program_id, return_data)) => (program_id == ctx.accounts.verifier_program.key()) && (return_data == [1]),
+        None => false,
+    };
+
+    if !is_verified {
+        return Err(error!(ErrorCode::VerificationFailed));
+    }
+
+    // Complete the submission with the challenge's full reputation, split with any co-authors
+    complete_submission(
+        CompletionAccounts {
+            crux: &ctx.accounts.crux,
+            challenge: &mut ctx.accounts.challenge,
+            submission: &mut ctx.accounts.submission,
+            user_profile: &mut ctx.accounts.user_profile,
+            bounty_vault: ctx.accounts.bounty_vault.to_account_info(),
+            profile_owner: ctx.accounts.profile_owner.to_account_info(),
+            bounty_token_accounts: BountyTokenAccounts {
+                crux_authority: &ctx.accounts.crux_authority,
+                bounty_token_vault: &ctx.accounts.bounty_token_vault,
+                receiver_token_account: &ctx.accounts.profile_owner_token_account,
+                token_program: &ctx.accounts.token_program,
+            },
+        },
+        completion_remaining_accounts,
+        MAX_SCORE_BPS,
+        now_ts,
+    )?;
+
+    ctx.accounts.submission.most_recent_engagement_ts = now_ts;
+    ctx.accounts.user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Submission account with address {} completed by verifier program {}", ctx.accounts.submission.key(), ctx.accounts.verifier_program.key());
+    Ok(())
+}
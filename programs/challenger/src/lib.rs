@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use instructions::*;
-use crate::state::{CruxFees, SubmissionState, Tags};
+use crate::state::{ChallengeEdit, ChallengeSettings, ChallengeStatus, CruxConstants, CruxFees, CruxPauseFlags, CruxPolicies, Evaluation, SubmissionDecision, SubmissionTeam, TreasuryPayout};
 
 declare_id!("CRuXQ86F4m6VfRHa7VACNbQKJoSioG3gcpui9BH2YNWa");
 
+pub mod bounty;
 pub mod instructions;
 pub mod state;
 
@@ -18,31 +19,102 @@ pub mod challenger{
         ctx: Context<InitCrux>,
         _bump_crux_auth: u8,
         crux_fees: CruxFees,
+        crux_constants: CruxConstants,
+        crux_policies: CruxPolicies,
+        appeal_fee: u64,
+        auto_accept_after_evaluation_deadline: bool,
     ) -> Result<()> {
         msg!("initializing crux");
         instructions::init_crux::handler(
             ctx,
-            crux_fees
+            crux_fees,
+            crux_constants,
+            crux_policies,
+            appeal_fee,
+            auto_accept_after_evaluation_deadline
         )
     }
 
     pub fn update_crux_params(
         ctx: Context<UpdateCruxParams>,
         new_crux_fees: CruxFees,
+        new_crux_constants: CruxConstants,
+        new_crux_policies: CruxPolicies,
+        new_appeal_fee: u64,
+        new_auto_accept_after_evaluation_deadline: bool,
     ) -> Result<()> {
-        msg!("updating crux fees");
+        msg!("updating crux params");
         instructions::update_crux_params::handler(
             ctx,
-            new_crux_fees
+            new_crux_fees,
+            new_crux_constants,
+            new_crux_policies,
+            new_appeal_fee,
+            new_auto_accept_after_evaluation_deadline
         )
     }
 
-    pub fn payout_from_treasury(
-        ctx: Context<PayoutFromTreasury>,
+    pub fn update_crux_pause_flags(
+        ctx: Context<UpdateCruxPauseFlags>,
+        new_crux_pause_flags: CruxPauseFlags,
+    ) -> Result<()> {
+        msg!("updating crux pause flags");
+        instructions::update_crux_pause_flags::handler(
+            ctx,
+            new_crux_pause_flags
+        )
+    }
+
+    pub fn propose_crux_manager(
+        ctx: Context<ProposeCruxManager>,
+    ) -> Result<()> {
+        msg!("proposing new crux manager");
+        instructions::propose_crux_manager::handler(ctx)
+    }
+
+    pub fn accept_crux_manager(
+        ctx: Context<AcceptCruxManager>,
+    ) -> Result<()> {
+        msg!("accepting crux manager role");
+        instructions::accept_crux_manager::handler(ctx)
+    }
+
+    pub fn update_crux_fee_mint(
+        ctx: Context<UpdateCruxFeeMint>,
+        _bump_crux_auth: u8,
+        new_fee_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        msg!("updating crux fee mint");
+        instructions::update_crux_fee_mint::handler(ctx, new_fee_mint)
+    }
+
+    pub fn payout_from_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayoutFromTreasury<'info>>,
         _bump_treasury: u8,
+        amount: u64,
+        additional_payouts: Vec<TreasuryPayout>,
     ) -> Result<()> {
         msg!("paying out funds from treasury");
-        instructions::payout_from_treasury::handler(ctx)
+        instructions::payout_from_treasury::handler(
+            ctx,
+            amount,
+            additional_payouts
+        )
+    }
+
+    pub fn payout_from_token_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayoutFromTokenTreasury<'info>>,
+        _bump_crux_auth: u8,
+        _bump_token_treasury: u8,
+        amount: u64,
+        additional_payouts: Vec<TreasuryPayout>,
+    ) -> Result<()> {
+        msg!("paying out tokens from treasury");
+        instructions::payout_from_token_treasury::handler(
+            ctx,
+            amount,
+            additional_payouts
+        )
     }
 
     pub fn close_crux(
@@ -102,20 +174,12 @@ pub mod challenger{
     pub fn create_challenge(
         ctx: Context<CreateChallenge>,
         _bump_moderator_profile: u8,
-        tags: Vec<Tags>,
-        title: String,
-        content_data_url: String,
-        challenge_expires_ts: u64,
-        reputation: u64,
+        challenge_settings: ChallengeSettings,
     ) -> Result<()> {
         msg!("creating challenge");
         instructions::create_challenge::handler(
             ctx,
-            tags,
-            title,
-            content_data_url,
-            challenge_expires_ts,
-            reputation
+            challenge_settings
         )
     }
 
@@ -123,20 +187,41 @@ pub mod challenger{
         ctx: Context<EditChallenge>,
         _bump_moderator_profile: u8,
         _bump_challenge: u8,
-        new_tags: Vec<Tags>,
-        new_title: String,
-        new_content_data_url: String,
-        new_challenge_expires_ts: u64,
-        new_reputation: u64,
+        challenge_edit: ChallengeEdit,
     ) -> Result<()> {
         msg!("editing challenge");
         instructions::edit_challenge::handler(
             ctx,
-            new_tags,
-            new_title,
-            new_content_data_url,
-            new_challenge_expires_ts,
-            new_reputation
+            challenge_edit
+        )
+    }
+
+    pub fn update_challenge_status(
+        ctx: Context<UpdateChallengeStatus>,
+        _bump_moderator_profile: u8,
+        _bump_challenge: u8,
+        new_challenge_status: ChallengeStatus,
+    ) -> Result<()> {
+        msg!("updating challenge status");
+        instructions::update_challenge_status::handler(
+            ctx,
+            new_challenge_status
+        )
+    }
+
+    pub fn fund_challenge_token_bounty(
+        ctx: Context<FundChallengeTokenBounty>,
+        _bump_crux_auth: u8,
+        _bump_moderator_profile: u8,
+        _bump_challenge: u8,
+        bounty_token_amount: u64,
+        bounty_token_per_completion: u64,
+    ) -> Result<()> {
+        msg!("funding challenge token bounty");
+        instructions::fund_challenge_token_bounty::handler(
+            ctx,
+            bounty_token_amount,
+            bounty_token_per_completion
         )
     }
 
@@ -144,6 +229,7 @@ pub mod challenger{
         ctx: Context<DeleteChallenge>,
         _bump_moderator_profile: u8,
         _bump_challenge: u8,
+        _bump_bounty_vault: u8,
     ) -> Result<()> {
         msg!("deleting challenge");
         instructions::delete_challenge::handler(ctx)
@@ -156,9 +242,25 @@ pub mod challenger{
         _bump_treasury: u8,
         _bump_user_profile: u8,
         _bump_challenge: u8,
+        content_data_url: String,
+        submission_index: u64,
+        submission_team: SubmissionTeam,
     ) -> Result<()> {
         msg!("creating submission");
-        instructions::create_submission::handler(ctx)
+        instructions::create_submission::handler(
+            ctx,
+            content_data_url,
+            submission_index,
+            submission_team
+        )
+    }
+
+    pub fn accept_co_authorship(
+        ctx: Context<AcceptCoAuthorship>,
+        _bump_co_author_profile: u8,
+    ) -> Result<()> {
+        msg!("accepting co-authorship");
+        instructions::accept_co_authorship::handler(ctx)
     }
 
     pub fn edit_submission(
@@ -166,9 +268,76 @@ pub mod challenger{
         _bump_user_profile: u8,
         _bump_challenge: u8,
         _bump_submission: u8,
+        new_content_data_url: String,
     ) -> Result<()> {
         msg!("editing submission");
-        instructions::edit_submission::handler(ctx)
+        instructions::edit_submission::handler(
+            ctx,
+            new_content_data_url
+        )
+    }
+
+    pub fn reveal_submission(
+        ctx: Context<RevealSubmission>,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+        content_data_url: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        msg!("revealing submission");
+        instructions::reveal_submission::handler(
+            ctx,
+            content_data_url,
+            salt
+        )
+    }
+
+    pub fn close_unrevealed_submission(
+        ctx: Context<CloseUnrevealedSubmission>,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+    ) -> Result<()> {
+        msg!("closing unrevealed submission");
+        instructions::close_unrevealed_submission::handler(ctx)
+    }
+
+    pub fn submit_answer<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitAnswer<'info>>,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+        _bump_bounty_vault: u8,
+        answer: String,
+    ) -> Result<()> {
+        msg!("submitting answer");
+        instructions::submit_answer::handler(
+            ctx,
+            answer
+        )
+    }
+
+    pub fn resolve_expired_submission<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveExpiredSubmission<'info>>,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+        _bump_bounty_vault: u8,
+    ) -> Result<()> {
+        msg!("resolving expired submission");
+        instructions::resolve_expired_submission::handler(ctx)
+    }
+
+    pub fn verify_submission<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifySubmission<'info>>,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+        _bump_bounty_vault: u8,
+    ) -> Result<()> {
+        msg!("verifying submission");
+        instructions::verify_submission::handler(ctx)
     }
 
     pub fn delete_submission(
@@ -192,23 +361,89 @@ pub mod challenger{
         instructions::delete_submission_moderator::handler(ctx)
     }
 
-    pub fn evaluate_submission(
-        ctx: Context<EvaluateSubmission>,
+    pub fn evaluate_submission<'info>(
+        ctx: Context<'_, '_, '_, 'info, EvaluateSubmission<'info>>,
         _bump_moderator_profile: u8,
         _bump_user_profile: u8,
         _bump_challenge: u8,
         _bump_submission: u8,
-        submission_state: SubmissionState,
+        _bump_bounty_vault: u8,
+        evaluation: Evaluation,
     ) -> Result<()> {
         msg!("evaluating submission");
         instructions::evaluate_submission::handler(
             ctx,
-            submission_state
+            evaluation
+        )
+    }
+
+    pub fn close_review(
+        ctx: Context<CloseReview>,
+        _bump_moderator_profile: u8,
+    ) -> Result<()> {
+        msg!("closing review");
+        instructions::close_review::handler(ctx)
+    }
+
+    pub fn appeal_submission(
+        ctx: Context<AppealSubmission>,
+        _bump_treasury: u8,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+    ) -> Result<()> {
+        msg!("appealing submission");
+        instructions::appeal_submission::handler(ctx)
+    }
+
+    pub fn resolve_appeal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveAppeal<'info>>,
+        _bump_treasury: u8,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+        _bump_bounty_vault: u8,
+        decision: SubmissionDecision,
+    ) -> Result<()> {
+        msg!("resolving appeal");
+        instructions::resolve_appeal::handler(
+            ctx,
+            decision
         )
     }
 
     //////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+    pub fn migrate_crux(
+        ctx: Context<MigrateCrux>,
+    ) -> Result<()> {
+        msg!("migrating crux");
+        instructions::migrate_crux::handler(ctx)
+    }
+
+    pub fn migrate_user_profile(
+        ctx: Context<MigrateUserProfile>,
+    ) -> Result<()> {
+        msg!("migrating user profile");
+        instructions::migrate_user_profile::handler(ctx)
+    }
+
+    pub fn migrate_challenge(
+        ctx: Context<MigrateChallenge>,
+    ) -> Result<()> {
+        msg!("migrating challenge");
+        instructions::migrate_challenge::handler(ctx)
+    }
+
+    pub fn migrate_submission(
+        ctx: Context<MigrateSubmission>,
+    ) -> Result<()> {
+        msg!("migrating submission");
+        instructions::migrate_submission::handler(ctx)
+    }
+
+    //////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
     pub fn close_account(
         ctx: Context<CloseAccount>,
     ) -> Result<()> {
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(80)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct AnswerAttempts {
+
+    // Auto-graded challenge which the answers were submitted to
+    pub challenge: Pubkey,
+
+    // Profile of the user submitting the answers
+    pub user_profile: Pubkey,
+
+    // ------------- Attempt counters
+
+    // Answers attempted across all of the user's submissions to the challenge, including deleted ones
+    pub answer_attempts: u64,
+
+    pub last_answer_attempt_ts: u64,
+
+}
@@ -1,6 +1,33 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Tags};
+use crate::state::{ChallengeStatus, RankedCompletion, Tags};
+use prog_common::{TryAdd, TryDiv, TrySub, errors::ErrorCode};
+
+pub const LATEST_CHALLENGE_VERSION: u16 = 13;
+
+// Number of leading completions which may earn a scheduled reputation bonus
+pub const REPUTATION_SCHEDULE_LENGTH: usize = 3;
+
+// Maximum number of challenges which must be completed before submitting to a challenge
+pub const MAX_PREREQUISITES: usize = 4;
+
+// Serialized length of the fields following the reputation value in each challenge layout version, indexed by version,
+// which every version has extended with fixed size fields only
+pub const CHALLENGE_LAYOUT_TAIL_LENGTHS: [usize; LATEST_CHALLENGE_VERSION as usize + 1] = [0, 194, 202, 234, 242, 250, 258, 306, 370, 386, 546, 682, 690, 691];
+
+// Fields of the original, unversioned challenge layout, decoded by migrations to find where an older challenge's variable length fields end
+#[derive(AnchorDeserialize)]
+pub struct LegacyChallenge {
+    pub crux: Pubkey,
+    pub challenge_seed: Pubkey,
+    pub challenge_posted_ts: u64,
+    pub challenge_expires_ts: u64,
+    pub tags: Vec<Tags>,
+    pub title: String,
+    pub content_data_url: String,
+    pub content_data_hash: Pubkey,
+    pub reputation: u64,
+}
 
 #[repr(C)]
 #[account]
@@ -31,4 +58,289 @@ pub struct Challenge {
     // ------------- Challenge reputation value
     pub reputation: u64,
 
+    // ------------- Account layout version
+
+    // Placed after the original fields so that older accounts remain a prefix of the current layout
+    pub version: u16,
+
+    // ------------- Challenge bounty
+
+    // Escrow PDA holding the challenge's lamport bounty
+    pub bounty_vault: Pubkey,
+
+    // Wallet which funded the bounty and receives any unclaimed lamports when the challenge is deleted
+    pub bounty_funder: Pubkey,
+
+    // Lamports remaining in escrow to be paid out to completed submissions
+    pub bounty_lamports: u64,
+
+    // Lamports paid out to each completed submission
+    pub bounty_per_completion: u64,
+
+    // ------------- Challenge token bounty
+
+    // SPL token mint of the challenge's token bounty (Pubkey::default() if no token bounty has been funded)
+    pub bounty_token_mint: Pubkey,
+
+    // Escrow token account owned by the crux authority PDA holding the challenge's token bounty
+    pub bounty_token_vault: Pubkey,
+
+    // Wallet which funded the token bounty and receives any unclaimed tokens when the challenge is deleted
+    pub bounty_token_funder: Pubkey,
+
+    // Tokens remaining in escrow to be paid out to completed submissions
+    pub bounty_token_amount: u64,
+
+    // Tokens paid out to each completed submission
+    pub bounty_token_per_completion: u64,
+
+    // ------------- Challenge review quorum
+
+    // Number of matching moderator votes required to complete or reject a submission
+    pub required_approvals: u64,
+
+    // ------------- Challenge author
+
+    // Profile of the moderator who created the challenge (Pubkey::default() for challenges created before it was recorded)
+    pub challenge_author: Pubkey,
+
+    // ------------- Challenge revision limit
+
+    // Maximum number of times a submission may be edited (0 for no limit)
+    pub max_revisions: u64,
+
+    // ------------- Challenge entries per user
+
+    // Maximum number of submissions a user may hold for the challenge (0 for a single submission seeded without an index)
+    pub max_submissions_per_user: u64,
+
+    // ------------- Challenge commit-reveal
+
+    // Seconds after the challenge expires during which committed submissions are revealed (0 if submissions are posted in the clear)
+    pub reveal_duration: u64,
+
+    // ------------- Challenge auto-grading
+
+    // Hash of the challenge's key and correct answer, allowing submissions to be completed without a moderator (Pubkey::default() if not auto-graded)
+    pub answer_hash: Pubkey,
+
+    // Maximum number of answers a submission may attempt (0 for no limit)
+    pub max_answer_attempts: u64,
+
+    // Seconds a submission must wait between answer attempts
+    pub answer_cooldown: u64,
+
+    // ------------- Challenge verifier
+
+    // Program called by verify_submission to check the submitter's on-chain activity (Pubkey::default() if not verified on-chain)
+    pub verifier_program: Pubkey,
+
+    // Parameters passed to the verifier program, such as the mint a submitter must hold
+    pub verifier_params: [u8; 32],
+
+    // ------------- Challenge schedule
+
+    // Submissions are accepted from this timestamp onwards (0 if open from creation)
+    pub challenge_opens_ts: u64,
+
+    // Submissions still pending after this timestamp are resolved by the crux's default (0 if there is no deadline)
+    pub evaluation_deadline_ts: u64,
+
+    // ------------- Challenge completions
+
+    // Maximum number of submissions which may be completed, after which the challenge closes (0 for no limit)
+    pub max_completions: u64,
+
+    // Number of submissions currently completed
+    pub completion_count: u64,
+
+    // Reputation earned by the earliest-posted completions in descending order, in place of the base reputation (0 for unused ranks)
+    pub reputation_schedule: [u64; REPUTATION_SCHEDULE_LENGTH],
+
+    // Completed submissions holding each rank of the reputation schedule (default for free ranks)
+    pub ranked_completions: [RankedCompletion; REPUTATION_SCHEDULE_LENGTH],
+
+    // ------------- Challenge prerequisites
+
+    // Challenges a user must have completed before submitting, followed by Pubkey::default() for unused entries
+    pub prerequisites: [Pubkey; MAX_PREREQUISITES],
+
+    // Reputation score a user profile must hold before submitting
+    pub min_reputation_score: u64,
+
+    // ------------- Challenge recurrence
+
+    // Length in seconds of each period of a recurring challenge, in which a user may submit once (0 if not recurring)
+    pub period_length: u64,
+
+    // ------------- Challenge status
+
+    // Lifecycle status set by moderators, with submissions only accepted while the challenge is open
+    // Open is the first variant so that challenges created before statuses existed remain open
+    pub challenge_status: ChallengeStatus,
+
+}
+
+impl Challenge {
+
+    pub fn has_token_bounty(&self) -> bool {
+        self.bounty_token_mint != Pubkey::default()
+    }
+
+    pub fn is_commit_reveal(&self) -> bool {
+        self.reveal_duration > 0
+    }
+
+    pub fn is_auto_graded(&self) -> bool {
+        self.answer_hash != Pubkey::default()
+    }
+
+    pub fn has_verifier(&self) -> bool {
+        self.verifier_program != Pubkey::default()
+    }
+
+    pub fn prerequisites(&self) -> &[Pubkey] {
+        let prerequisite_count = self.prerequisites.iter().take_while(|prerequisite| **prerequisite != Pubkey::default()).count();
+        &self.prerequisites[..prerequisite_count]
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.challenge_status == ChallengeStatus::Open
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.challenge_status == ChallengeStatus::Archived
+    }
+
+    pub fn is_recurring(&self) -> bool {
+        self.period_length > 0
+    }
+
+    // Periods of a recurring challenge are counted from when it opens
+    pub fn periods_start_ts(&self) -> u64 {
+        self.challenge_opens_ts.max(self.challenge_posted_ts)
+    }
+
+    pub fn period_index(&self, now_ts: u64) -> Result<u64> {
+        now_ts.try_sub(self.periods_start_ts())?.try_div(self.period_length)
+    }
+
+    pub fn is_completions_filled(&self) -> bool {
+        (self.max_completions > 0) && (self.completion_count >= self.max_completions)
+    }
+
+    // Validates the schedule and reputation settings shared by new and edited challenges
+    // A challenge opens before it expires and is not evaluated until it has expired and any committed submissions could be revealed,
+    // while scheduled reputation descends from the earliest completion and never falls below the base reputation
+    pub fn validate_schedule(
+        challenge_opens_ts: u64,
+        challenge_expires_ts: u64,
+        evaluation_deadline_ts: u64,
+        reveal_duration: u64,
+        reputation: u64,
+        reputation_schedule: &[u64; REPUTATION_SCHEDULE_LENGTH],
+    ) -> Result<()> {
+        if (challenge_opens_ts >= challenge_expires_ts)
+            || ((evaluation_deadline_ts > 0) && (evaluation_deadline_ts < challenge_expires_ts.try_add(reveal_duration)?)) {
+            return Err(error!(ErrorCode::InvalidChallengeSchedule));
+        }
+
+        let is_valid_reputation_schedule = reputation_schedule.iter().enumerate().all(|(rank, &scheduled_reputation)| {
+            (scheduled_reputation == 0) || ((scheduled_reputation >= reputation) && ((rank == 0) || (reputation_schedule[rank - 1] >= scheduled_reputation)))
+        });
+
+        if !is_valid_reputation_schedule {
+            return Err(error!(ErrorCode::InvalidReputationSchedule));
+        }
+
+        Ok(())
+    }
+
+    pub fn is_evaluation_deadline_passed(&self, now_ts: u64) -> bool {
+        (self.evaluation_deadline_ts > 0) && (now_ts > self.evaluation_deadline_ts)
+    }
+
+    // Records a submission's completion and returns the reputation it earns at its rank among the completions in posting order,
+    // along with the later-posted completions it moves down the reputation schedule and the reputation each earns at its new rank
+    pub fn record_completion(&mut self, submission: Pubkey, submission_posted_ts: u64) -> Result<(u64, Vec<(Pubkey, u64)>)> {
+        if self.is_completions_filled() {
+            return Err(error!(ErrorCode::ChallengeCompletionsFilled));
+        }
+
+        self.completion_count.try_add_assign(1)?;
+
+        // Filling the last completion closes the challenge, so that clients no longer list it as open
+        if self.is_completions_filled() && self.is_open() {
+            self.challenge_status = ChallengeStatus::Closed;
+        }
+
+        let (rank, free_rank) = match self.completion_rank(submission_posted_ts) {
+            Some(completion_rank) => completion_rank,
+            None => return Ok((self.reputation, vec![])),
+        };
+
+        // Later-posted holders move down one rank each until a free rank is reached, the last scheduled holder falling back to the base reputation
+        let mut moved_completions = vec![];
+        for moved_rank in (rank..free_rank).rev() {
+            let ranked = self.ranked_completions[moved_rank];
+            let reputation = match self.reputation_schedule.get(moved_rank + 1) {
+                Some(&scheduled_reputation) if scheduled_reputation > 0 => {
+                    self.ranked_completions[moved_rank + 1] = ranked;
+                    scheduled_reputation
+                }
+                _ => self.reputation,
+            };
+            moved_completions.push((ranked.submission, reputation));
+        }
+        moved_completions.reverse();
+
+        self.ranked_completions[rank] = RankedCompletion { submission, submission_posted_ts };
+        Ok((self.reputation_schedule[rank], moved_completions))
+    }
+
+    // Returns the scheduled rank a completion posted at the given timestamp takes, right behind the earlier-posted holders, along with
+    // the first free rank from there, so that the later-posted holders in between move down (None if no scheduled rank is left)
+    pub fn completion_rank(&self, submission_posted_ts: u64) -> Option<(usize, usize)> {
+        let scheduled_ranks = self.reputation_schedule.iter().take_while(|&&scheduled_reputation| scheduled_reputation > 0).count();
+
+        let rank = self.ranked_completions[..scheduled_ranks].iter()
+            .rposition(|ranked| (ranked.submission != Pubkey::default()) && (ranked.submission_posted_ts <= submission_posted_ts))
+            .map_or(0, |earlier_rank| earlier_rank + 1);
+
+        if rank >= scheduled_ranks {
+            return None;
+        }
+
+        let free_rank = (rank..scheduled_ranks)
+            .find(|&free_rank| self.ranked_completions[free_rank].submission == Pubkey::default())
+            .unwrap_or(scheduled_ranks);
+
+        Some((rank, free_rank))
+    }
+
+    // Releases a revoked completion along with any rank it held
+    pub fn revoke_completion(&mut self, submission: Pubkey) -> Result<()> {
+
+        // Challenges migrated from before completions were counted may revoke completions they never recorded
+        if self.completion_count > 0 {
+            self.completion_count.try_sub_assign(1)?;
+        }
+
+        if let Some(rank) = self.ranked_completions.iter().position(|ranked| ranked.submission == submission) {
+            self.ranked_completions[rank] = RankedCompletion::default();
+        }
+
+        Ok(())
+    }
+
+    // Challenges accepting a single submission per user keep the original submission PDA seeds,
+    // while recurring challenges seed each submission with its period index
+    pub fn submission_index_seed(&self, submission_index: u64) -> Vec<u8> {
+        if (self.max_submissions_per_user == 0) && !self.is_recurring() {
+            Vec::new()
+        } else {
+            submission_index.to_le_bytes().to_vec()
+        }
+    }
+
 }
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Tags;
+
+// New values replacing a challenge's info when it is edited
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ChallengeEdit {
+
+    pub tags: Vec<Tags>,
+
+    pub title: String,
+
+    pub content_data_url: String,
+
+    pub challenge_expires_ts: u64,
+
+    pub reputation: u64,
+}
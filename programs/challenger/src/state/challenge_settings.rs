@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Tags, REPUTATION_SCHEDULE_LENGTH};
+
+// Settings chosen by the moderator creating a challenge, see the Challenge account for what each one controls
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ChallengeSettings {
+
+    // ------------- Challenge Info
+    pub tags: Vec<Tags>,
+
+    pub title: String,
+
+    pub content_data_url: String,
+
+    pub challenge_expires_ts: u64,
+
+    pub reputation: u64,
+
+    // ------------- Challenge bounty
+    pub bounty_lamports: u64,
+
+    pub bounty_per_completion: u64,
+
+    // ------------- Challenge review
+    pub required_approvals: u64,
+
+    pub max_revisions: u64,
+
+    pub max_submissions_per_user: u64,
+
+    pub reveal_duration: u64,
+
+    // ------------- Challenge auto-grading and verifier
+    pub answer_hash: Pubkey,
+
+    pub max_answer_attempts: u64,
+
+    pub answer_cooldown: u64,
+
+    pub verifier_program: Pubkey,
+
+    pub verifier_params: [u8; 32],
+
+    // ------------- Challenge schedule and completions
+    pub challenge_opens_ts: u64,
+
+    pub evaluation_deadline_ts: u64,
+
+    pub max_completions: u64,
+
+    pub reputation_schedule: [u64; REPUTATION_SCHEDULE_LENGTH],
+
+    // ------------- Challenge eligibility
+    pub prerequisites: Vec<Pubkey>,
+
+    pub min_reputation_score: u64,
+
+    pub period_length: u64,
+
+    // Drafts are created without accepting submissions until a moderator opens them
+    pub is_draft: bool,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+// #[proc_macros::assert_size(1)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ChallengeStatus {
+    Open,
+    Draft,
+    Closed,
+    Archived
+}
+
+impl ChallengeStatus {
+
+    // Moderators may publish a draft, close an open challenge early, reopen a closed challenge or archive it
+    pub fn can_transition_to(&self, new_challenge_status: ChallengeStatus) -> bool {
+        matches!(
+            (self, new_challenge_status),
+            (ChallengeStatus::Draft, ChallengeStatus::Open)
+                | (ChallengeStatus::Open, ChallengeStatus::Closed)
+                | (ChallengeStatus::Closed, ChallengeStatus::Open)
+                | (ChallengeStatus::Closed, ChallengeStatus::Archived)
+        )
+    }
+}
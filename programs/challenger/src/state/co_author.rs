@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(56)] // +7 to make it divisible by 8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct CoAuthor {
+
+    // Profile of the team member named on the submission
+    pub user_profile: Pubkey,
+
+    // Weight of the member's share of the awarded reputation
+    pub weight: u64,
+
+    // Reputation credited to the member's profile while the submission is completed
+    pub awarded_reputation: u64,
+
+    // Set once the member has accepted co-authorship of the submission
+    pub accepted: bool,
+}
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
-pub const LATEST_CRUX_VERSION: u16 = 0;
-pub use crate::state::{CruxCounts, CruxFees};
+pub const LATEST_CRUX_VERSION: u16 = 5;
+pub use crate::state::{CruxConstants, CruxCounts, CruxFees, CruxLedger, CruxPauseFlags, CruxPolicies};
 
-#[proc_macros::assert_size(176)] // +5 to make it divisible by 8
+#[proc_macros::assert_size(336)] // +15 to make it divisible by 8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
@@ -28,6 +28,46 @@ pub struct Crux {
 
     pub crux_counts: CruxCounts,
 
+    // --------------- Crux constants
+
+    pub crux_constants: CruxConstants,
+
+    // --------------- Crux fee token
+
+    // SPL token mint in which crux fees are charged (Pubkey::default() if fees are charged in lamports)
+    pub fee_mint: Pubkey,
+
+    // Token account owned by the crux authority PDA which collects fees charged in fee_mint
+    pub crux_treasury_token_account: Pubkey,
+
+    // --------------- Crux treasury ledger
+
+    // Lamport totals only, token totals are kept per mint in TokenLedger PDAs
+    pub crux_ledger: CruxLedger,
+
+    // --------------- Crux manager handover
+
+    // Proposed new crux manager which must accept before taking over (Pubkey::default() if none)
+    pub pending_crux_manager: Pubkey,
+
+    // --------------- Crux pause flags
+
+    pub crux_pause_flags: CruxPauseFlags,
+
+    // --------------- Crux policies
+
+    pub crux_policies: CruxPolicies,
+
+    // --------------- Crux appeal fee
+
+    // Fee charged in the crux's fee currency to appeal a rejected submission, refunded if the appeal succeeds
+    pub appeal_fee: u64,
+
+    // ------------- Evaluation deadline policy
+
+    // Whether submissions left pending past their challenge's evaluation deadline are accepted rather than rejected
+    pub auto_accept_after_evaluation_deadline: bool,
+
 }
 
 impl Crux {
@@ -36,4 +76,8 @@ impl Crux {
         [self.crux_authority_seed.as_ref(), &self.crux_authority_bump_seed]
     }
 
+    pub fn has_fee_mint(&self) -> bool {
+        self.fee_mint != Pubkey::default()
+    }
+
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(24)] // divisible by 8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CruxConstants {
+
+    pub max_tags_length: u64,
+
+    pub max_title_length: u64,
+
+    pub max_url_length: u64,
+}
+
+impl Default for CruxConstants {
+
+    // Limits which were hardcoded before crux constants were made configurable
+    fn default() -> Self {
+        CruxConstants {
+            max_tags_length: 3,
+            max_title_length: 256,
+            max_url_length: 256,
+        }
+    }
+}
+
+impl CruxConstants {
+
+    // Zero limits would reject every challenge and submission
+    pub fn is_valid(&self) -> bool {
+        (self.max_tags_length > 0) && (self.max_title_length > 0) && (self.max_url_length > 0)
+    }
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(16)] // divisible by 8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CruxLedger {
+
+    // Lifetime lamports collected into and paid out of the crux treasury
+    pub total_fees_collected: u64,
+
+    pub total_paid_out: u64,
+}
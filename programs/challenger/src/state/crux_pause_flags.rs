@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(4)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct CruxPauseFlags {
+
+    pub profile_creation_paused: bool,
+
+    pub challenge_creation_paused: bool,
+
+    // Blocks both creating and editing submissions
+    pub submissions_paused: bool,
+
+    pub evaluation_paused: bool,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(1)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct CruxPolicies {
+
+    // Prevents the moderator who created a challenge from evaluating its submissions
+    pub forbid_author_evaluation: bool,
+}
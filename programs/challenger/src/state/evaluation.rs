@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SubmissionDecision;
+
+// A moderator's vote on a submission, recorded in a review
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct Evaluation {
+
+    pub decision: SubmissionDecision,
+
+    pub feedback_content_data_url: String,
+}
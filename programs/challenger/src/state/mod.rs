@@ -1,17 +1,51 @@
+pub mod answer_attempts;
 pub mod challenge;
+pub mod challenge_edit;
+pub mod challenge_settings;
+pub mod challenge_status;
+pub mod co_author;
 pub mod crux;
+pub mod crux_constants;
 pub mod crux_counts;
 pub mod crux_fees;
+pub mod crux_ledger;
+pub mod crux_pause_flags;
+pub mod crux_policies;
+pub mod evaluation;
+pub mod ranked_completion;
+pub mod review;
 pub mod submission;
+pub mod submission_decision;
+pub mod submission_entries;
 pub mod submission_state;
+pub mod submission_team;
 pub mod tags;
+pub mod token_ledger;
+pub mod treasury_payout;
 pub mod user_profile;
 
+pub use answer_attempts::*;
 pub use challenge::*;
+pub use challenge_edit::*;
+pub use challenge_settings::*;
+pub use challenge_status::*;
+pub use co_author::*;
 pub use crux::*;
+pub use crux_constants::*;
 pub use crux_counts::*;
 pub use crux_fees::*;
+pub use crux_ledger::*;
+pub use crux_pause_flags::*;
+pub use crux_policies::*;
+pub use evaluation::*;
+pub use ranked_completion::*;
+pub use review::*;
 pub use submission::*;
+pub use submission_decision::*;
+pub use submission_entries::*;
 pub use submission_state::*;
+pub use submission_team::*;
 pub use tags::*;
+pub use token_ledger::*;
+pub use treasury_payout::*;
 pub use user_profile::*;
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(40)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct RankedCompletion {
+
+    // Completed submission holding this rank in the challenge's reputation schedule
+    pub submission: Pubkey,
+
+    // Posted timestamp of the submission, by which completions are ranked
+    pub submission_posted_ts: u64,
+}
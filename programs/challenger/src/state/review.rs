@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{SubmissionState};
+
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Review {
+
+    // Submission which the review belongs to
+    pub submission: Pubkey,
+
+    // Posted timestamp of the submission, telling apart reviews of a submission which was deleted and created again at the same address
+    pub submission_posted_ts: u64,
+
+    // Profile of the moderator casting the vote
+    pub moderator_profile: Pubkey,
+
+    // Review round of the submission in which the vote was cast
+    pub review_round: u64,
+
+    // ------------- Timestamps
+    pub review_ts: u64,
+
+    // ------------- Vote
+    pub submission_state: SubmissionState,
+
+    pub score_bps: u64,
+
+    // ------------- Reviewer feedback
+    pub feedback_content_data_hash: Pubkey,
+
+    pub feedback_content_data_url: String,
+
+}
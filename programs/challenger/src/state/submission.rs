@@ -1,6 +1,25 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{SubmissionState};
+use crate::state::{CoAuthor, SubmissionState, UserProfile};
+use prog_common::{TryAdd, TryDiv, TryMul, TrySub, errors::ErrorCode};
+
+pub const LATEST_SUBMISSION_VERSION: u16 = 11;
+
+// Scores are given in basis points of the challenge reputation
+pub const MAX_SCORE_BPS: u64 = 10_000;
+
+// Number of previous content data hashes kept in a submission's revision history
+pub const REVISION_HISTORY_LENGTH: usize = 4;
+
+// Maximum number of co-authors named on a team submission
+pub const MAX_CO_AUTHORS: usize = 4;
+
+// Serialized length of the original, unversioned submission layout including its discriminator, which ended after the submission state
+pub const LEGACY_SUBMISSION_LENGTH: usize = 8 + 113;
+
+// Serialized length of the fields following the submission state in each submission layout version, indexed by version,
+// excluding the characters of the content data url introduced by version 7
+pub const SUBMISSION_LAYOUT_TAIL_LENGTHS: [usize; LATEST_SUBMISSION_VERSION as usize + 1] = [0, 2, 11, 19, 51, 123, 267, 271, 279, 484, 517, 533];
 
 #[repr(C)]
 #[account]
@@ -24,4 +43,169 @@ pub struct Submission {
     // Is Challenge completed
     pub submission_state: SubmissionState,
 
+    // ------------- Account layout version
+
+    // Placed after the original fields so that older accounts remain a prefix of the current layout
+    pub version: u16,
+
+    // ------------- Rewards
+
+    // Reputation credited to the submitter's profile while the submission is completed, reversed if the completion is revoked
+    pub awarded_reputation: u64,
+
+    // Bounty shares are paid out at most once, even if a revoked completion is later reinstated
+    pub bounty_paid: bool,
+
+    // Score given by the evaluating moderator in basis points (zero unless completed)
+    pub score_bps: u64,
+
+    // ------------- Review quorum
+
+    // Incremented each time a quorum decides the submission, so that moderators can vote again on a later revocation
+    pub review_round: u64,
+
+    // Votes cast in the current review round
+    pub approval_count: u64,
+
+    pub rejection_count: u64,
+
+    // Sum of the scores given by approving votes in the current review round
+    pub approval_score_bps_total: u64,
+
+    // ------------- Appeal
+
+    // Content data hash of the user's appeal against a rejection
+    pub appeal_content_data_hash: Pubkey,
+
+    // Appeal fee held by the crux treasury, refunded if the appeal succeeds
+    pub appeal_fee_paid: u64,
+
+    // Mint in which the appeal fee was paid (Pubkey::default() if paid in lamports)
+    pub appeal_fee_mint: Pubkey,
+
+    // ------------- Revisions
+
+    // Number of times the submission has been edited
+    pub revision_count: u64,
+
+    // Ring buffer of replaced content data hashes, the hash replaced by revision n is stored at n % REVISION_HISTORY_LENGTH
+    pub previous_content_data_hashes: [Pubkey; REVISION_HISTORY_LENGTH],
+
+    // Votes requesting changes in the current review round
+    pub revision_request_count: u64,
+
+    // ------------- Submission content
+
+    // The account is sized to the length of the url
+    pub content_data_url: String,
+
+    // ------------- Submission entry
+
+    // Index seeding the submission PDA when the challenge accepts multiple submissions per user
+    pub submission_index: u64,
+
+    // ------------- Team
+
+    // Weight of the submitter's share of the awarded reputation
+    pub submitter_weight: u64,
+
+    // Number of co-authors in use at the start of co_authors
+    pub co_author_count: u8,
+
+    pub co_authors: [CoAuthor; MAX_CO_AUTHORS],
+
+    // ------------- Commit-reveal
+
+    // Hash of the submitter's profile, content data hash, content data url and salt, committed while the challenge is open
+    pub content_commitment: Pubkey,
+
+    // Set while the committed content is yet to be revealed, during which the submission cannot be evaluated
+    pub awaiting_reveal: bool,
+
+    // ------------- Auto-graded answers
+
+    // Answers attempted by this submission on an auto-graded challenge, whose limit and cooldown are enforced across all of the
+    // user's submissions by their AnswerAttempts PDA
+    pub answer_attempts: u64,
+
+    pub last_answer_attempt_ts: u64,
+
+}
+
+impl Submission {
+
+    // Serialized length of a submission at the given layout version, whose content data url is only stored from version 7
+    pub fn layout_length(version: u16, content_data_url_length: usize) -> usize {
+        let url_length = if version >= 7 { content_data_url_length } else { 0 };
+        LEGACY_SUBMISSION_LENGTH + SUBMISSION_LAYOUT_TAIL_LENGTHS[version as usize] + url_length
+    }
+
+    pub fn co_authors(&self) -> &[CoAuthor] {
+        &self.co_authors[..self.co_author_count as usize]
+    }
+
+    // Team submissions are only valid once every co-author has accepted
+    pub fn co_authors_accepted(&self) -> bool {
+        self.co_authors().iter().all(|co_author| co_author.accepted)
+    }
+
+    // Credits each co-author's weighted share of the awarded reputation to their profile in the challenge's crux, passed in order as
+    // remaining accounts, and returns the share left to the submitter
+    pub fn credit_co_authors<'info>(&mut self, crux: Pubkey, co_author_profiles: &[AccountInfo<'info>], awarded_reputation: u64) -> Result<u64> {
+        let mut total_weight = self.submitter_weight;
+        for co_author in self.co_authors() {
+            total_weight.try_add_assign(co_author.weight)?;
+        }
+
+        let mut submitter_reputation = awarded_reputation;
+
+        for index in 0..self.co_author_count as usize {
+            let co_author = &mut self.co_authors[index];
+            let mut co_author_profile = load_co_author_profile(co_author_profiles, index, co_author.user_profile, crux)?;
+
+            let co_author_reputation = awarded_reputation.try_mul(co_author.weight)?.try_div(total_weight)?;
+
+            co_author_profile.challenges_completed.try_add_assign(1)?;
+            co_author_profile.reputation_score.try_add_assign(co_author_reputation)?;
+            co_author_profile.exit(&crate::ID)?;
+
+            co_author.awarded_reputation = co_author_reputation;
+            submitter_reputation.try_sub_assign(co_author_reputation)?;
+        }
+
+        Ok(submitter_reputation)
+    }
+
+    // Reverses the reputation credited to each co-author's profile in the challenge's crux, passed in order as remaining accounts
+    pub fn reverse_co_authors<'info>(&mut self, crux: Pubkey, co_author_profiles: &[AccountInfo<'info>]) -> Result<()> {
+        for index in 0..self.co_author_count as usize {
+            let co_author = &mut self.co_authors[index];
+            let mut co_author_profile = load_co_author_profile(co_author_profiles, index, co_author.user_profile, crux)?;
+
+            co_author_profile.challenges_completed.try_sub_assign(1)?;
+            co_author_profile.reputation_score.try_sub_assign(co_author.awarded_reputation)?;
+            co_author_profile.exit(&crate::ID)?;
+
+            co_author.awarded_reputation = 0;
+        }
+
+        Ok(())
+    }
+
+}
+
+// Loads the co-author profile passed at the given index, which must belong to the same crux as the submission's challenge
+// so that a team submission can't credit reputation to profiles in another crux
+pub fn load_co_author_profile<'info>(co_author_profiles: &[AccountInfo<'info>], index: usize, co_author_profile_key: Pubkey, crux: Pubkey) -> Result<Account<'info, UserProfile>> {
+    let co_author_profile_info = match co_author_profiles.get(index) {
+        Some(co_author_profile_info) if co_author_profile_info.key() == co_author_profile_key => co_author_profile_info,
+        _ => return Err(error!(ErrorCode::CoAuthorProfilesRequired)),
+    };
+
+    let co_author_profile: Account<UserProfile> = Account::try_from(co_author_profile_info)?;
+    if co_author_profile.crux != crux {
+        return Err(error!(ErrorCode::InvalidCoAuthors));
+    }
+
+    Ok(co_author_profile)
 }
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SubmissionState;
+
+// Outcome given to a submission by a moderator's vote or an appeal resolution
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SubmissionDecision {
+
+    pub submission_state: SubmissionState,
+
+    // Score in basis points for a completion (0 for other decisions)
+    pub score_bps: u64,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(80)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct SubmissionEntries {
+
+    // Challenge accepting multiple submissions per user
+    pub challenge: Pubkey,
+
+    // Profile of the user making the submissions
+    pub user_profile: Pubkey,
+
+    // ------------- Entry counters
+
+    // Submissions currently held by the user for the challenge
+    pub entry_count: u64,
+
+    // Index seeding the user's next submission PDA, never reused so that each entry has a distinct address
+    pub next_submission_index: u64,
+
+}
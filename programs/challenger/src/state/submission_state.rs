@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::MAX_SCORE_BPS;
+
 // Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
 
 // #[proc_macros::assert_size(8)]
@@ -7,5 +9,33 @@ use anchor_lang::prelude::*;
 pub enum SubmissionState {
     Completed,
     Rejected,
-    Pending
+    Pending,
+    Appealed,
+    NeedsRevision
+}
+
+impl SubmissionState {
+
+    // Moderators may accept, reject or request changes to a pending submission, reject a submission awaiting revision,
+    // revoke a completion or overturn a rejection
+    pub fn can_transition_to(&self, new_submission_state: SubmissionState) -> bool {
+        matches!(
+            (self, new_submission_state),
+            (SubmissionState::Pending, SubmissionState::Completed)
+                | (SubmissionState::Pending, SubmissionState::Rejected)
+                | (SubmissionState::Pending, SubmissionState::NeedsRevision)
+                | (SubmissionState::NeedsRevision, SubmissionState::Rejected)
+                | (SubmissionState::Completed, SubmissionState::Rejected)
+                | (SubmissionState::Rejected, SubmissionState::Completed)
+        )
+    }
+
+    // Completions are scored between 1 and MAX_SCORE_BPS basis points, all other decisions are unscored
+    pub fn is_valid_score(&self, score_bps: u64) -> bool {
+        if *self == SubmissionState::Completed {
+            (score_bps > 0) && (score_bps <= MAX_SCORE_BPS)
+        } else {
+            score_bps == 0
+        }
+    }
 }
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+// Co-authors named on a team submission along with each member's weight
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct SubmissionTeam {
+
+    // Profiles of the co-authors, who must each accept before the submission is evaluated (empty for a solo submission)
+    pub co_author_profiles: Vec<Pubkey>,
+
+    // Weights of the submitter followed by each co-author (empty for an equal split)
+    pub weights: Vec<u64>,
+}
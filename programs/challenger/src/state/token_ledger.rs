@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(80)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct TokenLedger {
+
+    pub crux: Pubkey,
+
+    // SPL token mint whose treasury token account the ledger tracks
+    pub mint: Pubkey,
+
+    // Lifetime tokens of the mint collected into and paid out of the crux's treasury token account
+    pub total_token_fees_collected: u64,
+
+    pub total_token_paid_out: u64,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TreasuryPayout {
+
+    // Receiving account, which must also be passed in as a writable remaining account
+    pub receiver: Pubkey,
+
+    pub amount: u64,
+}
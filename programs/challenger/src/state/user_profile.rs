@@ -1,6 +1,14 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(144)] // +5 to make it divisible by 8
+use crate::state::Challenge;
+use prog_common::{TryAdd, TryDiv, TrySub};
+
+pub const LATEST_USER_PROFILE_VERSION: u16 = 2;
+
+// Number of most recent periods of the streak challenge whose completions are recorded individually
+pub const STREAK_WINDOW_LENGTH: u64 = 64;
+
+#[proc_macros::assert_size(224)] // +5 to make it divisible by 8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
@@ -34,4 +42,126 @@ pub struct UserProfile {
 
     pub is_moderator: bool,
 
+    // ------------- account layout version
+
+    // Placed after the original fields so that older accounts remain a prefix of the current layout
+    pub version: u16,
+
+    // ------------- recurring challenge streak
+
+    // Recurring challenge in which the user's streak of completed periods is kept, along with the start and length of its periods
+    pub streak_challenge: Pubkey,
+
+    pub streak_periods_start_ts: u64,
+
+    pub streak_period_length: u64,
+
+    // Most recent period of the streak challenge which the user completed
+    pub streak_period_index: u64,
+
+    // Completed periods of the streak challenge, where bit i records period streak_period_index - i
+    pub streak_periods: u64,
+
+    // Number of consecutive completed periods ending at the period just before those recorded in streak_periods
+    pub streak_carry: u64,
+
+    // Number of consecutive periods completed up to streak_period_index, recomputed from the completed periods (see current_streak)
+    pub streak: u64,
+
+}
+
+impl UserProfile {
+
+    // Counts the consecutive completed periods of the streak challenge ending at the given period
+    fn streak_ending_at(&self, period_index: u64) -> u64 {
+        if period_index > self.streak_period_index {
+            return 0;
+        }
+
+        // Periods older than the recorded ones are only known through the carried run, which ends just before them
+        let offset = self.streak_period_index - period_index;
+        if offset >= STREAK_WINDOW_LENGTH {
+            return self.streak_carry.saturating_sub(offset - STREAK_WINDOW_LENGTH);
+        }
+
+        let run = (self.streak_periods >> offset).trailing_ones() as u64;
+        if run == STREAK_WINDOW_LENGTH - offset {
+            run + self.streak_carry
+        } else {
+            run
+        }
+    }
+
+    // Records a completed period of a recurring challenge and recomputes the streak from the completed periods, so that
+    // completions evaluated out of order count as they would have in order
+    // The streak is kept for one recurring challenge at a time, and only moves to another challenge once it has lapsed
+    pub fn record_period_completion(&mut self, challenge: &Account<Challenge>, period_index: u64, now_ts: u64) -> Result<()> {
+        if self.streak_challenge != challenge.key() {
+            if self.current_streak(now_ts)? > 0 {
+                return Ok(());
+            }
+
+            self.streak_challenge = challenge.key();
+            self.streak_periods_start_ts = challenge.periods_start_ts();
+            self.streak_period_length = challenge.period_length;
+            self.streak_period_index = period_index;
+            self.streak_periods = 1;
+            self.streak_carry = 0;
+        } else if period_index > self.streak_period_index {
+            // Periods leaving the recorded window are carried over for as long as they extend the run ending just before it
+            self.streak_carry = match period_index.checked_sub(STREAK_WINDOW_LENGTH) {
+                Some(carried_period_index) => self.streak_ending_at(carried_period_index),
+                None => 0,
+            };
+
+            let shift = period_index - self.streak_period_index;
+            let recorded_periods = if shift < STREAK_WINDOW_LENGTH { self.streak_periods << shift } else { 0 };
+            self.streak_periods = recorded_periods | 1;
+            self.streak_period_index = period_index;
+        } else {
+            let offset = self.streak_period_index - period_index;
+            if offset < STREAK_WINDOW_LENGTH {
+                self.streak_periods |= 1 << offset;
+            } else if offset - STREAK_WINDOW_LENGTH == self.streak_carry {
+                self.streak_carry.try_add_assign(1)?;
+            }
+        }
+
+        self.streak = self.streak_ending_at(self.streak_period_index);
+        Ok(())
+    }
+
+    // Removes a revoked period completion and recomputes the streak, cutting short any run which passed through the period
+    pub fn revoke_period_completion(&mut self, challenge: Pubkey, period_index: u64) {
+        if (self.streak_challenge != challenge) || (period_index > self.streak_period_index) {
+            return;
+        }
+
+        let offset = self.streak_period_index - period_index;
+        if offset < STREAK_WINDOW_LENGTH {
+            self.streak_periods &= !(1 << offset);
+        } else if offset - STREAK_WINDOW_LENGTH < self.streak_carry {
+            self.streak_carry = offset - STREAK_WINDOW_LENGTH;
+        }
+
+        self.streak = self.streak_ending_at(self.streak_period_index);
+    }
+
+    // The streak runs up to the current period of the streak challenge if it has been completed, or else up to the previous period,
+    // and has lapsed once neither was completed
+    pub fn current_streak(&self, now_ts: u64) -> Result<u64> {
+        if self.streak_period_length == 0 {
+            return Ok(0);
+        }
+
+        let current_period_index = now_ts.try_sub(self.streak_periods_start_ts)?.try_div(self.streak_period_length)?;
+        let current_streak = self.streak_ending_at(current_period_index);
+
+        if (current_streak == 0) && (current_period_index > 0) {
+            Ok(self.streak_ending_at(current_period_index - 1))
+        } else {
+            Ok(current_streak)
+        }
+    }
+
 }
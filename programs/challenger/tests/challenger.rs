@@ -0,0 +1,3175 @@
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorSerialize, Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    clock::Clock,
+    hash::hashv,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction::{self, SystemError},
+    system_program,
+    sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+use challenger::state::{
+    AnswerAttempts, Challenge, ChallengeEdit, ChallengeSettings, ChallengeStatus, Crux, CruxConstants, CruxFees, CruxPauseFlags, CruxPolicies, Evaluation, Review, Submission,
+    SubmissionDecision, SubmissionState, SubmissionTeam, Tags, TokenLedger, TreasuryPayout, UserProfile,
+    CHALLENGE_LAYOUT_TAIL_LENGTHS, LATEST_CHALLENGE_VERSION, LATEST_CRUX_VERSION, LATEST_SUBMISSION_VERSION, LATEST_USER_PROFILE_VERSION,
+    LEGACY_SUBMISSION_LENGTH, MAX_SCORE_BPS, REPUTATION_SCHEDULE_LENGTH,
+};
+use prog_common::errors::ErrorCode;
+
+// --------------------------------------- helpers
+
+async fn setup() -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("challenger", challenger::id(), processor!(challenger::entry));
+    program_test.add_program("token_holding_verifier", token_holding_verifier::id(), processor!(token_holding_verifier::entry));
+    program_test.start_with_context().await
+}
+
+async fn process(ctx: &mut ProgramTestContext, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+    // Always use a fresh blockhash so that retrying an identical transaction is not deduplicated
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+
+    let mut all_signers: Vec<&Keypair> = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&ctx.payer.pubkey()), &all_signers, blockhash);
+    ctx.banks_client.process_transaction(tx).await
+}
+
+async fn fund_wallet(ctx: &mut ProgramTestContext) -> Keypair {
+    let wallet = Keypair::new();
+    let ix = system_instruction::transfer(&ctx.payer.pubkey(), &wallet.pubkey(), 1_000_000_000);
+    process(ctx, &[ix], &[]).await.unwrap();
+    wallet
+}
+
+async fn create_mint(ctx: &mut ProgramTestContext) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let ixs = [
+        system_instruction::create_account(
+            &ctx.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_mint(&spl_token::id(), &mint.pubkey(), &ctx.payer.pubkey(), None, 0).unwrap(),
+    ];
+    process(ctx, &ixs, &[&mint]).await.unwrap();
+    mint.pubkey()
+}
+
+async fn create_token_account(ctx: &mut ProgramTestContext, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+    let token_account = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let ixs = [
+        system_instruction::create_account(
+            &ctx.payer.pubkey(),
+            &token_account.pubkey(),
+            rent.minimum_balance(spl_token::state::Account::LEN),
+            spl_token::state::Account::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_account(&spl_token::id(), &token_account.pubkey(), mint, owner).unwrap(),
+        spl_token::instruction::mint_to(&spl_token::id(), mint, &token_account.pubkey(), &ctx.payer.pubkey(), &[], amount).unwrap(),
+    ];
+    process(ctx, &ixs, &[&token_account]).await.unwrap();
+    token_account.pubkey()
+}
+
+async fn token_balance(ctx: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = ctx.banks_client.get_account(*token_account).await.unwrap().unwrap();
+    spl_token::state::Account::unpack(&account.data).unwrap().amount
+}
+
+async fn fetch_crux(ctx: &mut ProgramTestContext, crux: &Pubkey) -> Crux {
+    let account = ctx.banks_client.get_account(*crux).await.unwrap().unwrap();
+    Crux::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+fn find_pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &challenger::id())
+}
+
+struct TestCrux {
+    crux: Keypair,
+    crux_authority: Pubkey,
+    bump_crux_auth: u8,
+    crux_treasury: Pubkey,
+    bump_treasury: u8,
+}
+
+async fn init_crux(ctx: &mut ProgramTestContext, crux_fees: CruxFees) -> TestCrux {
+    let crux = Keypair::new();
+    let (crux_authority, bump_crux_auth) = find_pda(&[crux.pubkey().as_ref()]);
+    let (crux_treasury, bump_treasury) = find_pda(&[b"treasury".as_ref(), crux.pubkey().as_ref()]);
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::InitCrux {
+            crux: crux.pubkey(),
+            crux_manager: ctx.payer.pubkey(),
+            crux_authority,
+            crux_treasury,
+            rent: sysvar::rent::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::InitCrux {
+            _bump_crux_auth: bump_crux_auth,
+            crux_fees,
+            crux_constants: CruxConstants { max_tags_length: 3, max_title_length: 256, max_url_length: 256 },
+            crux_policies: CruxPolicies::default(),
+            appeal_fee: 0,
+            auto_accept_after_evaluation_deadline: false,
+        }
+        .data(),
+    };
+    process(ctx, &[ix], &[&crux]).await.unwrap();
+
+    TestCrux { crux, crux_authority, bump_crux_auth, crux_treasury, bump_treasury }
+}
+
+// Switches the crux's fee mint, or back to lamport fees without one
+fn update_crux_fee_mint_ix(test_crux: &TestCrux, crux_manager: &Pubkey, fee_mint: Option<Pubkey>) -> Instruction {
+    let crux = test_crux.crux.pubkey();
+    let token_accounts = fee_mint.map(|fee_mint| {
+        let (crux_treasury_token_account, _) = find_pda(&[b"token_treasury".as_ref(), crux.as_ref(), fee_mint.as_ref()]);
+        let (crux_token_ledger, _) = find_pda(&[b"token_ledger".as_ref(), crux.as_ref(), fee_mint.as_ref()]);
+        (crux_treasury_token_account, crux_token_ledger)
+    });
+
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::UpdateCruxFeeMint {
+            crux,
+            crux_manager: *crux_manager,
+            crux_authority: test_crux.crux_authority,
+            fee_mint,
+            crux_treasury_token_account: token_accounts.map(|(crux_treasury_token_account, _)| crux_treasury_token_account),
+            crux_token_ledger: token_accounts.map(|(_, crux_token_ledger)| crux_token_ledger),
+            rent: sysvar::rent::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::UpdateCruxFeeMint { _bump_crux_auth: test_crux.bump_crux_auth, new_fee_mint: fee_mint }.data(),
+    }
+}
+
+// Switches the crux's fee mint, returning the mint's treasury token account and token ledger
+async fn update_crux_fee_mint(ctx: &mut ProgramTestContext, test_crux: &TestCrux, fee_mint: &Pubkey) -> (Pubkey, Pubkey) {
+    let crux = test_crux.crux.pubkey();
+    let (crux_treasury_token_account, _) = find_pda(&[b"token_treasury".as_ref(), crux.as_ref(), fee_mint.as_ref()]);
+    let (crux_token_ledger, _) = find_pda(&[b"token_ledger".as_ref(), crux.as_ref(), fee_mint.as_ref()]);
+
+    let ix = update_crux_fee_mint_ix(test_crux, &ctx.payer.pubkey(), Some(*fee_mint));
+    process(ctx, &[ix], &[]).await.unwrap();
+
+    (crux_treasury_token_account, crux_token_ledger)
+}
+
+fn create_user_profile_ix(test_crux: &TestCrux, profile_owner: &Pubkey, token_accounts: Option<(Pubkey, Pubkey, Pubkey)>) -> Instruction {
+    let crux = test_crux.crux.pubkey();
+    let (user_profile, _) = find_pda(&[b"user_profile".as_ref(), crux.as_ref(), profile_owner.as_ref()]);
+
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::CreateUserProfile {
+            crux,
+            crux_treasury: test_crux.crux_treasury,
+            profile_owner: *profile_owner,
+            user_profile,
+            profile_owner_token_account: token_accounts.map(|(from, _, _)| from),
+            crux_treasury_token_account: token_accounts.map(|(_, to, _)| to),
+            crux_token_ledger: token_accounts.map(|(_, _, ledger)| ledger),
+            token_program: token_accounts.map(|_| spl_token::id()),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::CreateUserProfile { _bump_treasury: test_crux.bump_treasury }.data(),
+    }
+}
+
+struct TestModerator {
+    wallet: Keypair,
+    moderator: Pubkey,
+    moderator_profile: Pubkey,
+    bump_moderator_profile: u8,
+}
+
+// Creates a moderator profile for the crux manager
+async fn create_moderator(ctx: &mut ProgramTestContext, test_crux: &TestCrux) -> TestModerator {
+    let wallet = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+    create_moderator_with_wallet(ctx, test_crux, wallet).await
+}
+
+async fn create_moderator_with_wallet(ctx: &mut ProgramTestContext, test_crux: &TestCrux, wallet: Keypair) -> TestModerator {
+    let crux = test_crux.crux.pubkey();
+    let crux_manager = ctx.payer.pubkey();
+    let moderator = wallet.pubkey();
+    let (moderator_profile, bump_moderator_profile) = find_pda(&[b"user_profile".as_ref(), crux.as_ref(), moderator.as_ref()]);
+
+    let ixs = [
+        create_user_profile_ix(test_crux, &moderator, None),
+        Instruction {
+            program_id: challenger::id(),
+            accounts: challenger::accounts::AddModerator {
+                crux,
+                crux_manager,
+                profile_owner: moderator,
+                user_profile: moderator_profile,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: challenger::instruction::AddModerator { _bump_user_profile: bump_moderator_profile }.data(),
+        },
+    ];
+    process(ctx, &ixs, &[&wallet]).await.unwrap();
+
+    TestModerator { wallet, moderator, moderator_profile, bump_moderator_profile }
+}
+
+struct TestChallenge {
+    challenge: Pubkey,
+    bump_challenge: u8,
+    challenge_seed: Pubkey,
+    bounty_vault: Pubkey,
+    bump_bounty_vault: u8,
+}
+
+async fn create_challenge(
+    ctx: &mut ProgramTestContext,
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    reputation: u64,
+    bounty_lamports: u64,
+    bounty_per_completion: u64,
+    required_approvals: u64,
+) -> TestChallenge {
+    let challenge_config = ChallengeConfig { reputation, bounty_lamports, bounty_per_completion, required_approvals, ..ChallengeConfig::default() };
+    create_challenge_with_config(ctx, test_crux, test_moderator, challenge_config).await
+}
+
+// Optional challenge settings, defaulting to a single unlimited-revision submission per user
+struct ChallengeConfig {
+    reputation: u64,
+    bounty_lamports: u64,
+    bounty_per_completion: u64,
+    required_approvals: u64,
+    max_revisions: u64,
+    max_submissions_per_user: u64,
+    reveal_duration: u64,
+    answer: Option<&'static str>,
+    max_answer_attempts: u64,
+    answer_cooldown: u64,
+    verifier_program: Pubkey,
+    verifier_params: [u8; 32],
+    opens_after: u64,
+    evaluation_deadline_after_expiry: Option<u64>,
+    max_completions: u64,
+    reputation_schedule: [u64; REPUTATION_SCHEDULE_LENGTH],
+    prerequisites: Vec<Pubkey>,
+    min_reputation_score: u64,
+    period_length: u64,
+    is_draft: bool,
+}
+
+impl Default for ChallengeConfig {
+    fn default() -> Self {
+        ChallengeConfig {
+            reputation: 100,
+            bounty_lamports: 0,
+            bounty_per_completion: 0,
+            required_approvals: 1,
+            max_revisions: 0,
+            max_submissions_per_user: 0,
+            reveal_duration: 0,
+            answer: None,
+            max_answer_attempts: 0,
+            answer_cooldown: 0,
+            verifier_program: Pubkey::default(),
+            verifier_params: [0; 32],
+            opens_after: 0,
+            evaluation_deadline_after_expiry: None,
+            max_completions: 0,
+            reputation_schedule: [0; REPUTATION_SCHEDULE_LENGTH],
+            prerequisites: vec![],
+            min_reputation_score: 0,
+            period_length: 0,
+            is_draft: false,
+        }
+    }
+}
+
+async fn create_challenge_with_config(
+    ctx: &mut ProgramTestContext,
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    challenge_config: ChallengeConfig,
+) -> TestChallenge {
+    try_create_challenge_with_config(ctx, test_crux, test_moderator, challenge_config).await.unwrap()
+}
+
+async fn try_create_challenge_with_config(
+    ctx: &mut ProgramTestContext,
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    challenge_config: ChallengeConfig,
+) -> Result<TestChallenge, BanksClientError> {
+    let crux = test_crux.crux.pubkey();
+    let challenge_seed = Pubkey::new_unique();
+    let (challenge, bump_challenge) = find_pda(&[b"challenge".as_ref(), crux.as_ref(), challenge_seed.as_ref()]);
+    let (bounty_vault, bump_bounty_vault) = find_pda(&[b"bounty_vault".as_ref(), challenge.as_ref()]);
+    let now_ts = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp as u64;
+    let challenge_expires_ts = now_ts + 86_400;
+
+    let mut ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::CreateChallenge {
+            crux,
+            moderator: test_moderator.moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            challenge,
+            challenge_seed,
+            bounty_vault,
+            content_data_hash: Pubkey::new_unique(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::CreateChallenge {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            challenge_settings: ChallengeSettings {
+                tags: vec![Tags::Development],
+                title: "Test challenge".to_string(),
+                content_data_url: "https://example.com/challenge".to_string(),
+                challenge_expires_ts,
+                reputation: challenge_config.reputation,
+                bounty_lamports: challenge_config.bounty_lamports,
+                bounty_per_completion: challenge_config.bounty_per_completion,
+                required_approvals: challenge_config.required_approvals,
+                max_revisions: challenge_config.max_revisions,
+                max_submissions_per_user: challenge_config.max_submissions_per_user,
+                reveal_duration: challenge_config.reveal_duration,
+                answer_hash: challenge_config
+                    .answer
+                    .map(|answer| Pubkey::new_from_array(hashv(&[challenge.as_ref(), answer.as_bytes()]).to_bytes()))
+                    .unwrap_or_default(),
+                max_answer_attempts: challenge_config.max_answer_attempts,
+                answer_cooldown: challenge_config.answer_cooldown,
+                verifier_program: challenge_config.verifier_program,
+                verifier_params: challenge_config.verifier_params,
+                challenge_opens_ts: if challenge_config.opens_after > 0 { now_ts + challenge_config.opens_after } else { 0 },
+                evaluation_deadline_ts: challenge_config
+                    .evaluation_deadline_after_expiry
+                    .map(|seconds| challenge_expires_ts + seconds)
+                    .unwrap_or(0),
+                max_completions: challenge_config.max_completions,
+                reputation_schedule: challenge_config.reputation_schedule,
+                prerequisites: challenge_config.prerequisites.clone(),
+                min_reputation_score: challenge_config.min_reputation_score,
+                period_length: challenge_config.period_length,
+                is_draft: challenge_config.is_draft,
+            },
+        }
+        .data(),
+    };
+    ix.accounts.extend(challenge_config.prerequisites.iter().map(|prerequisite| AccountMeta::new_readonly(*prerequisite, false)));
+    process(ctx, &[ix], &[]).await?;
+
+    Ok(TestChallenge { challenge, bump_challenge, challenge_seed, bounty_vault, bump_bounty_vault })
+}
+
+struct TestSubmission {
+    user: Keypair,
+    user_profile: Pubkey,
+    bump_user_profile: u8,
+    submission: Pubkey,
+    bump_submission: u8,
+    submission_posted_ts: u64,
+}
+
+// Creates a new user with a profile and a submission to the given challenge
+async fn create_submission(ctx: &mut ProgramTestContext, test_crux: &TestCrux, test_challenge: &TestChallenge) -> TestSubmission {
+    let user = fund_wallet(ctx).await;
+    let ix = create_user_profile_ix(test_crux, &user.pubkey(), None);
+    process(ctx, &[ix], &[&user]).await.unwrap();
+
+    submit_as(ctx, test_crux, test_challenge, user).await
+}
+
+// Submits to the given challenge from a wallet which already has a user profile
+async fn submit_as(ctx: &mut ProgramTestContext, test_crux: &TestCrux, test_challenge: &TestChallenge, user: Keypair) -> TestSubmission {
+    submit_entry_as(ctx, test_crux, test_challenge, user, None).await.unwrap()
+}
+
+// Submits with the given index to a challenge accepting multiple submissions per user, or without one otherwise
+async fn submit_entry_as(
+    ctx: &mut ProgramTestContext,
+    test_crux: &TestCrux,
+    test_challenge: &TestChallenge,
+    user: Keypair,
+    submission_index: Option<u64>,
+) -> Result<TestSubmission, BanksClientError> {
+    submit_with_config(ctx, test_crux, test_challenge, user, SubmissionConfig { submission_index, ..SubmissionConfig::default() }).await
+}
+
+// Optional submission settings, defaulting to a single submission by one user posted in the clear
+struct SubmissionConfig {
+    submission_index: Option<u64>,
+    co_author_profiles: Vec<Pubkey>,
+    weights: Vec<u64>,
+    content_data_hash: Pubkey,
+    content_data_url: String,
+    prerequisite_submissions: Vec<Pubkey>,
+    period_index: Option<u64>,
+}
+
+impl Default for SubmissionConfig {
+    fn default() -> Self {
+        SubmissionConfig {
+            submission_index: None,
+            co_author_profiles: vec![],
+            weights: vec![],
+            content_data_hash: Pubkey::new_unique(),
+            content_data_url: "https://example.com/submission".to_string(),
+            prerequisite_submissions: vec![],
+            period_index: None,
+        }
+    }
+}
+
+async fn submit_with_config(
+    ctx: &mut ProgramTestContext,
+    test_crux: &TestCrux,
+    test_challenge: &TestChallenge,
+    user: Keypair,
+    submission_config: SubmissionConfig,
+) -> Result<TestSubmission, BanksClientError> {
+    let submission_index = submission_config.submission_index;
+    let crux = test_crux.crux.pubkey();
+    let (user_profile, bump_user_profile) = find_pda(&[b"user_profile".as_ref(), crux.as_ref(), user.pubkey().as_ref()]);
+    let index_seed = submission_index.or(submission_config.period_index).map(|index| index.to_le_bytes().to_vec()).unwrap_or_default();
+    let (submission, bump_submission) = find_pda(&[b"submission".as_ref(), test_challenge.challenge.as_ref(), user_profile.as_ref(), &index_seed]);
+    let submission_entries = submission_index
+        .map(|_| find_pda(&[b"submission_entries".as_ref(), test_challenge.challenge.as_ref(), user_profile.as_ref()]).0);
+
+    let co_author_profiles = submission_config.co_author_profiles.clone();
+    let mut ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::CreateSubmission {
+            crux,
+            crux_treasury: test_crux.crux_treasury,
+            profile_owner: user.pubkey(),
+            user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            submission,
+            submission_entries,
+            content_data_hash: submission_config.content_data_hash,
+            profile_owner_token_account: None,
+            crux_treasury_token_account: None,
+            crux_token_ledger: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::CreateSubmission {
+            _bump_treasury: test_crux.bump_treasury,
+            _bump_user_profile: bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            content_data_url: submission_config.content_data_url,
+            submission_index: submission_index.or(submission_config.period_index).unwrap_or(0),
+            submission_team: SubmissionTeam { co_author_profiles: submission_config.co_author_profiles, weights: submission_config.weights },
+        }
+        .data(),
+    };
+    ix.accounts.extend(
+        submission_config.prerequisite_submissions.iter().map(|prerequisite_submission| AccountMeta::new_readonly(*prerequisite_submission, false)),
+    );
+    ix.accounts.extend(co_author_profiles.iter().map(|co_author_profile| AccountMeta::new_readonly(*co_author_profile, false)));
+    process(ctx, &[ix], &[&user]).await?;
+
+    let submission_posted_ts = fetch_account::<Submission>(ctx, &submission).await.submission_posted_ts;
+    Ok(TestSubmission { user, user_profile, bump_user_profile, submission, bump_submission, submission_posted_ts })
+}
+
+fn accept_co_authorship_ix(test_crux: &TestCrux, test_challenge: &TestChallenge, submission: &Pubkey, co_author: &Pubkey, bump_co_author_profile: u8) -> Instruction {
+    let crux = test_crux.crux.pubkey();
+    let (co_author_profile, _) = find_pda(&[b"user_profile".as_ref(), crux.as_ref(), co_author.as_ref()]);
+
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::AcceptCoAuthorship {
+            crux,
+            co_author: *co_author,
+            co_author_profile,
+            challenge: test_challenge.challenge,
+            submission: *submission,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::AcceptCoAuthorship { _bump_co_author_profile: bump_co_author_profile }.data(),
+    }
+}
+
+// Review PDA of a moderator's vote on the given submission in the given review round
+fn review_address(test_submission: &TestSubmission, moderator_profile: &Pubkey, review_round: u64) -> Pubkey {
+    find_pda(&[
+        b"review".as_ref(),
+        test_submission.submission.as_ref(),
+        moderator_profile.as_ref(),
+        &test_submission.submission_posted_ts.to_le_bytes(),
+        &review_round.to_le_bytes(),
+    ])
+    .0
+}
+
+fn evaluate_submission_accounts(
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    test_challenge: &TestChallenge,
+    test_submission: &TestSubmission,
+    review_round: u64,
+) -> challenger::accounts::EvaluateSubmission {
+    let review = review_address(test_submission, &test_moderator.moderator_profile, review_round);
+
+    challenger::accounts::EvaluateSubmission {
+        crux: test_crux.crux.pubkey(),
+        moderator: test_moderator.moderator,
+        moderator_profile: test_moderator.moderator_profile,
+        profile_owner: test_submission.user.pubkey(),
+        user_profile: test_submission.user_profile,
+        challenge: test_challenge.challenge,
+        challenge_seed: test_challenge.challenge_seed,
+        bounty_vault: test_challenge.bounty_vault,
+        submission: test_submission.submission,
+        review,
+        feedback_content_data_hash: Pubkey::new_unique(),
+        crux_authority: None,
+        bounty_token_vault: None,
+        profile_owner_token_account: None,
+        token_program: None,
+        system_program: system_program::id(),
+    }
+}
+
+fn evaluate_submission_ix(
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    test_challenge: &TestChallenge,
+    test_submission: &TestSubmission,
+    review_round: u64,
+    submission_state: SubmissionState,
+    score_bps: u64,
+) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: evaluate_submission_accounts(test_crux, test_moderator, test_challenge, test_submission, review_round).to_account_metas(None),
+        data: challenger::instruction::EvaluateSubmission {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+            evaluation: Evaluation {
+                decision: SubmissionDecision { submission_state, score_bps },
+                feedback_content_data_url: "https://example.com/feedback".to_string(),
+            },
+        }
+        .data(),
+    }
+}
+
+fn edit_submission_ix(
+    test_crux: &TestCrux,
+    test_challenge: &TestChallenge,
+    test_submission: &TestSubmission,
+    new_content_data_hash: Pubkey,
+    new_content_data_url: &str,
+) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::EditSubmission {
+            crux: test_crux.crux.pubkey(),
+            profile_owner: test_submission.user.pubkey(),
+            user_profile: test_submission.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            submission: test_submission.submission,
+            new_content_data_hash,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::EditSubmission {
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+            new_content_data_url: new_content_data_url.to_string(),
+        }
+        .data(),
+    }
+}
+
+fn delete_submission_ix(test_crux: &TestCrux, test_challenge: &TestChallenge, test_submission: &TestSubmission, submission_entries: Option<Pubkey>) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::DeleteSubmission {
+            crux: test_crux.crux.pubkey(),
+            profile_owner: test_submission.user.pubkey(),
+            user_profile: test_submission.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            submission: test_submission.submission,
+            submission_entries,
+            receiver: test_submission.user.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::DeleteSubmission {
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+        }
+        .data(),
+    }
+}
+
+// Moves the cluster clock forward by the given number of seconds
+async fn advance_clock(ctx: &mut ProgramTestContext, seconds: i64) {
+    let mut clock = ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += seconds;
+    ctx.set_sysvar(&clock);
+}
+
+async fn fetch_account<T: AccountDeserialize>(ctx: &mut ProgramTestContext, address: &Pubkey) -> T {
+    let account = ctx.banks_client.get_account(*address).await.unwrap().unwrap();
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+// Asserts that a transaction failed with the given custom error code
+fn assert_error_code<T>(result: Result<T, BanksClientError>, expected: impl Into<u32>) {
+    let expected = expected.into();
+    match result.map_err(|error| error.unwrap()) {
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(code))) => assert_eq!(code, expected),
+        Err(error) => panic!("expected error code {expected}, got {error:?}"),
+        Ok(_) => panic!("expected error code {expected}, but the transaction succeeded"),
+    }
+}
+
+// --------------------------------------- token fees
+
+#[tokio::test]
+async fn test_token_fees_collected_and_paid_out() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 100, submission_fee: 50 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    let fee_mint = create_mint(&mut ctx).await;
+    let (crux_treasury_token_account, crux_token_ledger) = update_crux_fee_mint(&mut ctx, &test_crux, &fee_mint).await;
+
+    // Profile creation without token accounts is rejected once fees are charged in a token
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    assert_error_code(process(&mut ctx, &[ix], &[&user]).await, ErrorCode::TokenAccountsRequired);
+
+    // Profile fee is collected in the crux's treasury token account
+    let user_token_account = create_token_account(&mut ctx, &fee_mint, &user.pubkey(), 1_000).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), Some((user_token_account, crux_treasury_token_account, crux_token_ledger)));
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &user_token_account).await, 900);
+    assert_eq!(token_balance(&mut ctx, &crux_treasury_token_account).await, 100);
+
+    // Switching the fee mint collects later fees in the new mint's treasury token account and ledger
+    let new_fee_mint = create_mint(&mut ctx).await;
+    let (new_crux_treasury_token_account, new_crux_token_ledger) = update_crux_fee_mint(&mut ctx, &test_crux, &new_fee_mint).await;
+
+    let new_user = fund_wallet(&mut ctx).await;
+    let new_user_token_account = create_token_account(&mut ctx, &new_fee_mint, &new_user.pubkey(), 1_000).await;
+    let token_accounts = Some((new_user_token_account, new_crux_treasury_token_account, new_crux_token_ledger));
+    let ix = create_user_profile_ix(&test_crux, &new_user.pubkey(), token_accounts);
+    process(&mut ctx, &[ix], &[&new_user]).await.unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &new_crux_treasury_token_account).await, 100);
+
+    // Crux manager splits the previous fee mint's treasury token account between two receivers
+    let receiver_token_account = create_token_account(&mut ctx, &fee_mint, &Pubkey::new_unique(), 0).await;
+    let additional_receiver_token_account = create_token_account(&mut ctx, &fee_mint, &Pubkey::new_unique(), 0).await;
+    let (_, bump_token_treasury) = find_pda(&[b"token_treasury".as_ref(), crux.as_ref(), fee_mint.as_ref()]);
+    let mut accounts = challenger::accounts::PayoutFromTokenTreasury {
+        crux,
+        crux_manager: ctx.payer.pubkey(),
+        crux_authority: test_crux.crux_authority,
+        mint: fee_mint,
+        crux_treasury_token_account,
+        crux_token_ledger,
+        receiver_token_account,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(additional_receiver_token_account, false));
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts,
+        data: challenger::instruction::PayoutFromTokenTreasury {
+            _bump_crux_auth: test_crux.bump_crux_auth,
+            _bump_token_treasury: bump_token_treasury,
+            amount: 60,
+            additional_payouts: vec![TreasuryPayout { receiver: additional_receiver_token_account, amount: 40 }],
+        }
+        .data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &crux_treasury_token_account).await, 0);
+    assert_eq!(token_balance(&mut ctx, &receiver_token_account).await, 60);
+    assert_eq!(token_balance(&mut ctx, &additional_receiver_token_account).await, 40);
+
+    // Each mint's ledger only records its own tokens
+    let token_ledger: TokenLedger = fetch_account(&mut ctx, &crux_token_ledger).await;
+    assert_eq!(token_ledger.mint, fee_mint);
+    assert_eq!(token_ledger.total_token_fees_collected, 100);
+    assert_eq!(token_ledger.total_token_paid_out, 100);
+
+    let new_token_ledger: TokenLedger = fetch_account(&mut ctx, &new_crux_token_ledger).await;
+    assert_eq!(new_token_ledger.mint, new_fee_mint);
+    assert_eq!(new_token_ledger.total_token_fees_collected, 100);
+    assert_eq!(new_token_ledger.total_token_paid_out, 0);
+
+    let crux_ledger = fetch_crux(&mut ctx, &crux).await.crux_ledger;
+    assert_eq!(crux_ledger.total_fees_collected, 0);
+}
+
+#[tokio::test]
+async fn test_crux_fees_switch_between_token_and_lamports() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 100, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+    let crux_manager = ctx.payer.pubkey();
+    let fee_mint = create_mint(&mut ctx).await;
+
+    // Switching to a fee mint requires its treasury token account and ledger
+    let mut ix = update_crux_fee_mint_ix(&test_crux, &crux_manager, None);
+    ix.data = challenger::instruction::UpdateCruxFeeMint { _bump_crux_auth: test_crux.bump_crux_auth, new_fee_mint: Some(fee_mint) }.data();
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::TokenAccountsRequired);
+
+    let (crux_treasury_token_account, crux_token_ledger) = update_crux_fee_mint(&mut ctx, &test_crux, &fee_mint).await;
+
+    let create_token_fee_profile = |user: &Keypair, user_token_account: Pubkey| {
+        create_user_profile_ix(&test_crux, &user.pubkey(), Some((user_token_account, crux_treasury_token_account, crux_token_ledger)))
+    };
+    let user = fund_wallet(&mut ctx).await;
+    let user_token_account = create_token_account(&mut ctx, &fee_mint, &user.pubkey(), 1_000).await;
+    process(&mut ctx, &[create_token_fee_profile(&user, user_token_account)], &[&user]).await.unwrap();
+
+    // Without a fee mint the crux charges lamports again
+    process(&mut ctx, &[update_crux_fee_mint_ix(&test_crux, &crux_manager, None)], &[]).await.unwrap();
+
+    let crux_acct = fetch_crux(&mut ctx, &crux).await;
+    assert_eq!(crux_acct.fee_mint, Pubkey::default());
+    assert_eq!(crux_acct.crux_treasury_token_account, Pubkey::default());
+
+    let user = fund_wallet(&mut ctx).await;
+    process(&mut ctx, &[create_user_profile_ix(&test_crux, &user.pubkey(), None)], &[&user]).await.unwrap();
+    assert_eq!(fetch_crux(&mut ctx, &crux).await.crux_ledger.total_fees_collected, 100);
+    assert_eq!(token_balance(&mut ctx, &crux_treasury_token_account).await, 100);
+
+    // Switching back to the token keeps the mint's treasury token account and ledger totals
+    update_crux_fee_mint(&mut ctx, &test_crux, &fee_mint).await;
+
+    let user = fund_wallet(&mut ctx).await;
+    let user_token_account = create_token_account(&mut ctx, &fee_mint, &user.pubkey(), 1_000).await;
+    process(&mut ctx, &[create_token_fee_profile(&user, user_token_account)], &[&user]).await.unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &crux_treasury_token_account).await, 200);
+    let token_ledger: TokenLedger = fetch_account(&mut ctx, &crux_token_ledger).await;
+    assert_eq!(token_ledger.total_token_fees_collected, 200);
+    assert_eq!(fetch_crux(&mut ctx, &crux).await.crux_ledger.total_fees_collected, 100);
+}
+
+// --------------------------------------- lamport treasury
+
+fn payout_from_treasury_ix(test_crux: &TestCrux, crux_manager: &Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::PayoutFromTreasury {
+            crux: test_crux.crux.pubkey(),
+            crux_manager: *crux_manager,
+            crux_treasury: test_crux.crux_treasury,
+            receiver: Pubkey::new_unique(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::PayoutFromTreasury {
+            _bump_treasury: test_crux.bump_treasury,
+            amount,
+            additional_payouts: vec![],
+        }
+        .data(),
+    }
+}
+
+#[tokio::test]
+async fn test_partial_treasury_payout_recorded_in_ledger() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 2_000_000, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+
+    let crux_manager = ctx.payer.pubkey();
+
+    // Payouts exceeding the collected fees are rejected
+    let ix = payout_from_treasury_ix(&test_crux, &crux_manager, 2_000_001);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InsufficientTreasuryBalance);
+
+    let ix = payout_from_treasury_ix(&test_crux, &crux_manager, 1_500_000);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let crux_ledger = fetch_crux(&mut ctx, &crux).await.crux_ledger;
+    assert_eq!(crux_ledger.total_fees_collected, 2_000_000);
+    assert_eq!(crux_ledger.total_paid_out, 1_500_000);
+}
+
+// --------------------------------------- lamport bounties
+
+#[tokio::test]
+async fn test_lamport_bounty_paid_on_completion_and_refunded_on_delete() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    // Fund a lamport bounty of 400_000 paying 150_000 per completion
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 10, 400_000, 150_000, 1).await;
+    let moderator = test_moderator.moderator;
+    let bounty_vault = test_challenge.bounty_vault;
+
+    let vault_rent = ctx.banks_client.get_rent().await.unwrap().minimum_balance(8);
+    assert_eq!(ctx.banks_client.get_balance(bounty_vault).await.unwrap(), vault_rent + 400_000);
+
+    // Two users submit and are marked completed, each receiving 150_000 lamports
+    for _ in 0..2 {
+        let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+        let user_lamports_before = ctx.banks_client.get_balance(test_submission.user.pubkey()).await.unwrap();
+
+        let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        process(&mut ctx, &[ix], &[]).await.unwrap();
+
+        assert_eq!(ctx.banks_client.get_balance(test_submission.user.pubkey()).await.unwrap(), user_lamports_before + 150_000);
+    }
+
+    assert_eq!(ctx.banks_client.get_balance(bounty_vault).await.unwrap(), vault_rent + 100_000);
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.bounty_lamports, 100_000);
+
+    // Deleting the challenge returns the unclaimed 100_000 lamports and the vault's rent to the funder and closes the vault
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::DeleteChallenge {
+            crux,
+            moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_vault,
+            bounty_funder: moderator,
+            receiver: Pubkey::new_unique(),
+            crux_authority: None,
+            bounty_token_vault: None,
+            bounty_token_funder_token_account: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::DeleteChallenge {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+        }
+        .data(),
+    };
+    let moderator_lamports_before = ctx.banks_client.get_balance(moderator).await.unwrap();
+    let transaction_fee = ctx.genesis_config().fee_rate_governor.lamports_per_signature;
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    assert_eq!(ctx.banks_client.get_balance(moderator).await.unwrap(), moderator_lamports_before + vault_rent + 100_000 - transaction_fee);
+    assert!(ctx.banks_client.get_account(bounty_vault).await.unwrap().is_none());
+}
+
+// --------------------------------------- token bounties
+
+// Funds a token bounty from the moderator's token account, returning the bounty token vault
+#[allow(clippy::too_many_arguments)]
+async fn fund_challenge_token_bounty(
+    ctx: &mut ProgramTestContext,
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    test_challenge: &TestChallenge,
+    bounty_token_mint: &Pubkey,
+    moderator_token_account: &Pubkey,
+    bounty_token_amount: u64,
+    bounty_token_per_completion: u64,
+) -> Pubkey {
+    let (bounty_token_vault, _) = find_pda(&[b"bounty_token_vault".as_ref(), test_challenge.challenge.as_ref(), bounty_token_mint.as_ref()]);
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::FundChallengeTokenBounty {
+            crux: test_crux.crux.pubkey(),
+            crux_authority: test_crux.crux_authority,
+            moderator: test_moderator.moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_token_mint: *bounty_token_mint,
+            bounty_token_vault,
+            moderator_token_account: *moderator_token_account,
+            rent: sysvar::rent::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::FundChallengeTokenBounty {
+            _bump_crux_auth: test_crux.bump_crux_auth,
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            bounty_token_amount,
+            bounty_token_per_completion,
+        }
+        .data(),
+    };
+    process(ctx, &[ix], &[]).await.unwrap();
+
+    bounty_token_vault
+}
+
+#[tokio::test]
+async fn test_token_bounty_paid_on_completion_and_refunded_on_delete() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    // Create the challenge without a lamport bounty
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 10, 0, 0, 1).await;
+    let moderator = test_moderator.moderator;
+    let challenge = test_challenge.challenge;
+
+    // Fund a token bounty of 400 paying 150 per completion
+    let bounty_token_mint = create_mint(&mut ctx).await;
+    let moderator_token_account = create_token_account(&mut ctx, &bounty_token_mint, &moderator, 1_000).await;
+    let bounty_token_vault = fund_challenge_token_bounty(&mut ctx, &test_crux, &test_moderator, &test_challenge, &bounty_token_mint, &moderator_token_account, 400, 150).await;
+
+    assert_eq!(token_balance(&mut ctx, &moderator_token_account).await, 600);
+    assert_eq!(token_balance(&mut ctx, &bounty_token_vault).await, 400);
+
+    // Two users submit and are marked completed, each receiving 150 tokens
+    for _ in 0..2 {
+        let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+        let user_token_account = create_token_account(&mut ctx, &bounty_token_mint, &test_submission.user.pubkey(), 0).await;
+
+        let mut ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        ix.accounts = challenger::accounts::EvaluateSubmission {
+            crux_authority: Some(test_crux.crux_authority),
+            bounty_token_vault: Some(bounty_token_vault),
+            profile_owner_token_account: Some(user_token_account),
+            token_program: Some(spl_token::id()),
+            ..evaluate_submission_accounts(&test_crux, &test_moderator, &test_challenge, &test_submission, 0)
+        }
+        .to_account_metas(None);
+        process(&mut ctx, &[ix], &[]).await.unwrap();
+
+        assert_eq!(token_balance(&mut ctx, &user_token_account).await, 150);
+    }
+
+    assert_eq!(token_balance(&mut ctx, &bounty_token_vault).await, 100);
+
+    // Deleting the challenge returns the unclaimed 100 tokens to the funder and closes the vault
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::DeleteChallenge {
+            crux,
+            moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_vault: test_challenge.bounty_vault,
+            bounty_funder: moderator,
+            receiver: moderator,
+            crux_authority: Some(test_crux.crux_authority),
+            bounty_token_vault: Some(bounty_token_vault),
+            bounty_token_funder_token_account: Some(moderator_token_account),
+            token_program: Some(spl_token::id()),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::DeleteChallenge {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+        }
+        .data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &moderator_token_account).await, 700);
+    assert!(ctx.banks_client.get_account(bounty_token_vault).await.unwrap().is_none());
+}
+
+// --------------------------------------- crux constants
+
+fn edit_challenge_ix(test_crux: &TestCrux, test_moderator: &TestModerator, test_challenge: &TestChallenge, challenge_edit: ChallengeEdit) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::EditChallenge {
+            crux: test_crux.crux.pubkey(),
+            moderator: test_moderator.moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            new_content_data_hash: Pubkey::new_unique(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::EditChallenge {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            challenge_edit,
+        }
+        .data(),
+    }
+}
+
+#[tokio::test]
+async fn test_crux_constants_limit_challenge_content() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+    let crux_manager = ctx.payer.pubkey();
+
+    let update_crux_constants_ix = |new_crux_constants: CruxConstants| Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::UpdateCruxParams { crux, crux_manager, system_program: system_program::id() }.to_account_metas(None),
+        data: challenger::instruction::UpdateCruxParams {
+            new_crux_fees: CruxFees { profile_fee: 0, submission_fee: 0 },
+            new_crux_constants,
+            new_crux_policies: CruxPolicies::default(),
+            new_appeal_fee: 0,
+            new_auto_accept_after_evaluation_deadline: false,
+        }
+        .data(),
+    };
+
+    // Zero limits would make every challenge and submission invalid
+    for crux_constants in [
+        CruxConstants { max_tags_length: 0, ..CruxConstants::default() },
+        CruxConstants { max_title_length: 0, ..CruxConstants::default() },
+        CruxConstants { max_url_length: 0, ..CruxConstants::default() },
+    ] {
+        assert_error_code(process(&mut ctx, &[update_crux_constants_ix(crux_constants)], &[]).await, ErrorCode::InvalidParameter);
+    }
+
+    // Tighten the limits to exactly fit the test challenge's single tag, title and url
+    let tight_crux_constants = CruxConstants { max_tags_length: 1, max_title_length: 14, max_url_length: 29 };
+    process(&mut ctx, &[update_crux_constants_ix(tight_crux_constants)], &[]).await.unwrap();
+
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+
+    let now_ts = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp as u64;
+    let edit_ix = |new_tags: Vec<Tags>, new_title: &str, new_content_data_url: &str| {
+        edit_challenge_ix(&test_crux, &test_moderator, &test_challenge, ChallengeEdit {
+            tags: new_tags,
+            title: new_title.to_string(),
+            content_data_url: new_content_data_url.to_string(),
+            challenge_expires_ts: now_ts + 86_400,
+            reputation: 100,
+        })
+    };
+
+    // Edits are held to the crux's limits
+    let ix = edit_ix(vec![Tags::Development, Tags::Ideas], "Test challenge", "https://example.com/challenge");
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidTagsVectorInput);
+    let ix = edit_ix(vec![Tags::Development], "Test challenge 2", "https://example.com/challenge");
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidTitleStringInput);
+    let ix = edit_ix(vec![Tags::Development], "Test challenge", "https://example.com/challenge2");
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidUrlStringInput);
+
+    let ix = edit_ix(vec![Tags::Ideas], "Edited", "https://example.com/edited");
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.title, "Edited");
+
+    // Once the title limit drops below the test challenge's title, new challenges are refused
+    let tighter_crux_constants = CruxConstants { max_title_length: 13, ..tight_crux_constants };
+    process(&mut ctx, &[update_crux_constants_ix(tighter_crux_constants)], &[]).await.unwrap();
+
+    let result = try_create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, ChallengeConfig::default()).await;
+    assert_error_code(result, ErrorCode::InvalidTitleStringInput);
+}
+
+// --------------------------------------- crux manager handover
+
+#[tokio::test]
+async fn test_crux_manager_handover_requires_acceptance() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    let new_crux_manager = fund_wallet(&mut ctx).await;
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::ProposeCruxManager {
+            crux,
+            crux_manager: ctx.payer.pubkey(),
+            new_crux_manager: new_crux_manager.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::ProposeCruxManager {}.data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let accept_ix = |signer: &Pubkey| Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::AcceptCruxManager {
+            crux,
+            new_crux_manager: *signer,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::AcceptCruxManager {}.data(),
+    };
+
+    // Only the proposed key can complete the handover
+    let impostor = fund_wallet(&mut ctx).await;
+    assert_error_code(process(&mut ctx, &[accept_ix(&impostor.pubkey())], &[&impostor]).await, ErrorCode::NotPendingCruxManager);
+    assert_eq!(fetch_crux(&mut ctx, &crux).await.crux_manager, ctx.payer.pubkey());
+
+    process(&mut ctx, &[accept_ix(&new_crux_manager.pubkey())], &[&new_crux_manager]).await.unwrap();
+
+    let crux_account = fetch_crux(&mut ctx, &crux).await;
+    assert_eq!(crux_account.crux_manager, new_crux_manager.pubkey());
+    assert_eq!(crux_account.pending_crux_manager, Pubkey::default());
+}
+
+// --------------------------------------- submission evaluation
+
+#[tokio::test]
+async fn test_reputation_credited_once_and_reversed_on_revocation() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 10, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let evaluate = |review_round: u64, submission_state: SubmissionState, score_bps: u64| {
+        evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, review_round, submission_state, score_bps)
+    };
+
+    process(&mut ctx, &[evaluate(0, SubmissionState::Completed, MAX_SCORE_BPS)], &[]).await.unwrap();
+
+    // Completing an already completed submission, or moving it back to pending, is not a valid transition
+    assert_error_code(process(&mut ctx, &[evaluate(1, SubmissionState::Completed, MAX_SCORE_BPS)], &[]).await, ErrorCode::InvalidSubmissionStateTransition);
+    assert_error_code(process(&mut ctx, &[evaluate(1, SubmissionState::Pending, 0)], &[]).await, ErrorCode::InvalidSubmissionStateTransition);
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+    assert_eq!(user_profile.challenges_completed, 1);
+    assert_eq!(user_profile.reputation_score, 10);
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.awarded_reputation, 10);
+
+    // Revoking the completion reverses exactly what was credited
+    process(&mut ctx, &[evaluate(1, SubmissionState::Rejected, 0)], &[]).await.unwrap();
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+    assert_eq!(user_profile.challenges_completed, 0);
+    assert_eq!(user_profile.reputation_score, 0);
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.awarded_reputation, 0);
+    assert_eq!(submission.submission_state, SubmissionState::Rejected);
+}
+
+#[tokio::test]
+async fn test_scored_completion_awards_share_of_reputation() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 200, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let evaluate = |review_round: u64, submission_state: SubmissionState, score_bps: u64| {
+        evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, review_round, submission_state, score_bps)
+    };
+
+    // Scores above the maximum, unscored completions and scored rejections are refused
+    assert_error_code(process(&mut ctx, &[evaluate(0, SubmissionState::Completed, MAX_SCORE_BPS + 1)], &[]).await, ErrorCode::InvalidScore);
+    assert_error_code(process(&mut ctx, &[evaluate(0, SubmissionState::Completed, 0)], &[]).await, ErrorCode::InvalidScore);
+    assert_error_code(process(&mut ctx, &[evaluate(0, SubmissionState::Rejected, 100)], &[]).await, ErrorCode::InvalidScore);
+
+    process(&mut ctx, &[evaluate(0, SubmissionState::Completed, 7_500)], &[]).await.unwrap();
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 150);
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.score_bps, 7_500);
+    assert_eq!(submission.awarded_reputation, 150);
+}
+
+#[tokio::test]
+async fn test_submission_decided_only_at_quorum() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let first_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let second_wallet = fund_wallet(&mut ctx).await;
+    let second_moderator = create_moderator_with_wallet(&mut ctx, &test_crux, second_wallet).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &first_moderator, 100, 0, 0, 2).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let evaluate = |test_moderator: &TestModerator, score_bps: u64| {
+        evaluate_submission_ix(&test_crux, test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, score_bps)
+    };
+
+    // A single approval is recorded but does not decide the submission, and the same moderator cannot vote twice
+    process(&mut ctx, &[evaluate(&first_moderator, MAX_SCORE_BPS)], &[]).await.unwrap();
+    assert_error_code(process(&mut ctx, &[evaluate(&first_moderator, MAX_SCORE_BPS)], &[]).await, SystemError::AccountAlreadyInUse as u32);
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Pending);
+    assert_eq!(submission.approval_count, 1);
+
+    // The vote is recorded in the moderator's review PDA along with their feedback
+    let review = review_address(&test_submission, &first_moderator.moderator_profile, 0);
+    let review: Review = fetch_account(&mut ctx, &review).await;
+    assert_eq!(review.moderator_profile, first_moderator.moderator_profile);
+    assert_eq!(review.submission_state, SubmissionState::Completed);
+    assert_eq!(review.feedback_content_data_url, "https://example.com/feedback");
+
+    // The second approval reaches quorum and the submission is scored with the average of both votes
+    process(&mut ctx, &[evaluate(&second_moderator, 5_000)], &[&second_moderator.wallet]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+    assert_eq!(submission.score_bps, 7_500);
+    assert_eq!(submission.review_round, 1);
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 75);
+}
+
+#[tokio::test]
+async fn test_revision_requests_and_revision_history() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, ChallengeConfig { max_revisions: 2, ..ChallengeConfig::default() }).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let original: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+
+    // A moderator asks for changes, which the submitter addresses with a revision
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::NeedsRevision, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::NeedsRevision);
+
+    let first_revision = Pubkey::new_unique();
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, first_revision, "https://example.com/submission");
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Pending);
+    assert_eq!(submission.revision_count, 1);
+    assert_eq!(submission.content_data_hash, first_revision);
+    assert_eq!(submission.previous_content_data_hashes[0], original.content_data_hash);
+
+    // The second revision is recorded, and further edits exceed the challenge's limit
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), "https://example.com/submission");
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.revision_count, 2);
+    assert_eq!(submission.previous_content_data_hashes[1], first_revision);
+
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), "https://example.com/submission");
+    assert_error_code(process(&mut ctx, &[ix], &[&test_submission.user]).await, ErrorCode::RevisionLimitReached);
+}
+
+#[tokio::test]
+async fn test_submission_content_data_url_stored_and_resized_on_edit() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.content_data_url, "https://example.com/submission");
+
+    // Empty urls and urls longer than the crux's limit are refused
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), "");
+    assert_error_code(process(&mut ctx, &[ix], &[&test_submission.user]).await, ErrorCode::InvalidUrlStringInput);
+
+    let too_long_url = "a".repeat(257);
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), &too_long_url);
+    assert_error_code(process(&mut ctx, &[ix], &[&test_submission.user]).await, ErrorCode::InvalidUrlStringInput);
+
+    // A longer url grows the account, paid for by the submitter
+    let longer_url = format!("https://example.com/{}", "a".repeat(200));
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), &longer_url);
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.content_data_url, longer_url);
+
+    // A shorter url shrinks the account back to its exact size, leaving no stale bytes after the submission
+    let shorter_url = "https://example.com/s";
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), shorter_url);
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(test_submission.submission).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + std::mem::size_of::<Submission>() + shorter_url.len());
+
+    let mut serialized: Vec<u8> = Vec::new();
+    let submission = Submission::try_deserialize(&mut account.data.as_slice()).unwrap();
+    submission.try_serialize(&mut serialized).unwrap();
+    assert_eq!(submission.content_data_url, shorter_url);
+    assert!(account.data[serialized.len()..].iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn test_multiple_submissions_per_user() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { max_submissions_per_user: 2, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+    let copy_user = |user: &Keypair| Keypair::from_bytes(&user.to_bytes()).unwrap();
+
+    // Entries must be submitted with the next index, up to the challenge's limit
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(1)).await, ErrorCode::InvalidSubmissionIndex);
+    let first_entry = submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(0)).await.unwrap();
+    submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(1)).await.unwrap();
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(2)).await, ErrorCode::SubmissionLimitReached);
+
+    // Several entries count once towards the challenges the user submitted to
+    let user_profile: UserProfile = fetch_account(&mut ctx, &first_entry.user_profile).await;
+    assert_eq!(user_profile.challenges_submitted, 1);
+    assert_eq!(fetch_crux(&mut ctx, &test_crux.crux.pubkey()).await.crux_counts.submission_count, 2);
+
+    // Deleting an entry frees a slot for a new entry, which takes the next unused index
+    let (submission_entries, _) = find_pda(&[b"submission_entries".as_ref(), test_challenge.challenge.as_ref(), first_entry.user_profile.as_ref()]);
+    let ix = delete_submission_ix(&test_crux, &test_challenge, &first_entry, Some(submission_entries));
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &first_entry.user_profile).await;
+    assert_eq!(user_profile.challenges_submitted, 1);
+
+    submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(2)).await.unwrap();
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &first_entry.user_profile).await;
+    assert_eq!(user_profile.challenges_submitted, 1);
+    assert_eq!(fetch_crux(&mut ctx, &test_crux.crux.pubkey()).await.crux_counts.submission_count, 2);
+}
+
+#[tokio::test]
+async fn test_team_submission_requires_acceptance_and_splits_reputation() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+
+    let mut members = Vec::new();
+    for _ in 0..3 {
+        let wallet = fund_wallet(&mut ctx).await;
+        let ix = create_user_profile_ix(&test_crux, &wallet.pubkey(), None);
+        process(&mut ctx, &[ix], &[&wallet]).await.unwrap();
+
+        let (user_profile, bump_user_profile) = find_pda(&[b"user_profile".as_ref(), test_crux.crux.pubkey().as_ref(), wallet.pubkey().as_ref()]);
+        members.push((wallet, user_profile, bump_user_profile));
+    }
+    let co_author_profiles = vec![members[1].1, members[2].1];
+
+    // Co-authors must be distinct from the submitter
+    let submitter = Keypair::from_bytes(&members[0].0.to_bytes()).unwrap();
+    let result = submit_with_config(&mut ctx, &test_crux, &test_challenge, submitter, SubmissionConfig {
+        co_author_profiles: vec![members[0].1],
+        ..SubmissionConfig::default()
+    })
+    .await;
+    assert_error_code(result, ErrorCode::InvalidCoAuthors);
+
+    let submitter = Keypair::from_bytes(&members[0].0.to_bytes()).unwrap();
+    let test_submission = submit_with_config(&mut ctx, &test_crux, &test_challenge, submitter, SubmissionConfig {
+        co_author_profiles: co_author_profiles.clone(),
+        weights: vec![2, 1, 1],
+        ..SubmissionConfig::default()
+    })
+    .await
+    .unwrap();
+
+    let evaluate = || {
+        let mut ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        ix.accounts.extend(co_author_profiles.iter().map(|co_author_profile| AccountMeta::new(*co_author_profile, false)));
+        ix
+    };
+
+    // The submission cannot be evaluated until every co-author has accepted
+    assert_error_code(process(&mut ctx, &[evaluate()], &[]).await, ErrorCode::CoAuthorsPending);
+
+    for (wallet, _, bump_user_profile) in &members[1..] {
+        let ix = accept_co_authorship_ix(&test_crux, &test_challenge, &test_submission.submission, &wallet.pubkey(), *bump_user_profile);
+        process(&mut ctx, &[ix], &[wallet]).await.unwrap();
+    }
+
+    // Reputation is split by the declared weights and every member is credited with the completion
+    process(&mut ctx, &[evaluate()], &[]).await.unwrap();
+
+    for ((_, user_profile, _), expected_reputation) in members.iter().zip([50, 25, 25]) {
+        let user_profile: UserProfile = fetch_account(&mut ctx, user_profile).await;
+        assert_eq!(user_profile.reputation_score, expected_reputation);
+        assert_eq!(user_profile.challenges_completed, 1);
+    }
+}
+
+#[tokio::test]
+async fn test_team_submission_rejects_co_authors_from_another_crux() {
+    let mut ctx = setup().await;
+    let victim_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let attacker_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let attacker_moderator = create_moderator(&mut ctx, &attacker_crux).await;
+    let attacker_challenge = create_challenge(&mut ctx, &attacker_crux, &attacker_moderator, 100, 0, 0, 1).await;
+
+    // The same wallet holds a profile in each crux
+    let wallet = fund_wallet(&mut ctx).await;
+    let ixs = [create_user_profile_ix(&victim_crux, &wallet.pubkey(), None), create_user_profile_ix(&attacker_crux, &wallet.pubkey(), None)];
+    process(&mut ctx, &ixs, &[&wallet]).await.unwrap();
+    let (victim_profile, bump_victim_profile) = find_pda(&[b"user_profile".as_ref(), victim_crux.crux.pubkey().as_ref(), wallet.pubkey().as_ref()]);
+
+    // A submission in the attacker's crux can't name a profile from the victim's crux as co-author
+    let submitter = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&attacker_crux, &submitter.pubkey(), None);
+    process(&mut ctx, &[ix], &[&submitter]).await.unwrap();
+
+    let result = submit_with_config(&mut ctx, &attacker_crux, &attacker_challenge, submitter, SubmissionConfig {
+        co_author_profiles: vec![victim_profile],
+        ..SubmissionConfig::default()
+    })
+    .await;
+    assert_error_code(result, ErrorCode::InvalidCoAuthors);
+
+    // Nor can a victim profile accept co-authorship of a submission to a challenge in another crux
+    let co_author = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&attacker_crux, &co_author.pubkey(), None);
+    process(&mut ctx, &[ix], &[&co_author]).await.unwrap();
+    let (co_author_profile, _) = find_pda(&[b"user_profile".as_ref(), attacker_crux.crux.pubkey().as_ref(), co_author.pubkey().as_ref()]);
+
+    let submitter = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&attacker_crux, &submitter.pubkey(), None);
+    process(&mut ctx, &[ix], &[&submitter]).await.unwrap();
+    let test_submission = submit_with_config(&mut ctx, &attacker_crux, &attacker_challenge, submitter, SubmissionConfig {
+        co_author_profiles: vec![co_author_profile],
+        ..SubmissionConfig::default()
+    })
+    .await
+    .unwrap();
+
+    let ix = accept_co_authorship_ix(&victim_crux, &attacker_challenge, &test_submission.submission, &wallet.pubkey(), bump_victim_profile);
+    assert_error_code(process(&mut ctx, &[ix], &[&wallet]).await, anchor_lang::error::ErrorCode::ConstraintHasOne);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_submission() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { reveal_duration: 3_600, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let commit = |user: &Keypair, content_data_hash: &Pubkey, content_data_url: &str, salt: &[u8; 32]| {
+        let (user_profile, _) = find_pda(&[b"user_profile".as_ref(), test_crux.crux.pubkey().as_ref(), user.pubkey().as_ref()]);
+        Pubkey::new_from_array(hashv(&[user_profile.as_ref(), content_data_hash.as_ref(), content_data_url.as_bytes(), salt]).to_bytes())
+    };
+
+    // Two users commit, only one of whom will reveal
+    let mut test_submissions = Vec::new();
+    let content_data_hash = Pubkey::new_unique();
+    let salt = [7u8; 32];
+    for _ in 0..2 {
+        let user = fund_wallet(&mut ctx).await;
+        let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+        process(&mut ctx, &[ix], &[&user]).await.unwrap();
+
+        let submission_config = SubmissionConfig {
+            content_data_hash: commit(&user, &content_data_hash, "https://example.com/reveal", &salt),
+            content_data_url: String::new(),
+            ..SubmissionConfig::default()
+        };
+        test_submissions.push(submit_with_config(&mut ctx, &test_crux, &test_challenge, user, submission_config).await.unwrap());
+    }
+    let (revealer, unrevealed) = (&test_submissions[0], &test_submissions[1]);
+
+    let reveal_ix = |content_data_url: &str| Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::RevealSubmission {
+            crux: test_crux.crux.pubkey(),
+            profile_owner: revealer.user.pubkey(),
+            user_profile: revealer.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            submission: revealer.submission,
+            content_data_hash,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::RevealSubmission {
+            _bump_user_profile: revealer.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: revealer.bump_submission,
+            content_data_url: content_data_url.to_string(),
+            salt,
+        }
+        .data(),
+    };
+    let evaluate_ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, revealer, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+
+    // Committed submissions can be neither evaluated nor revealed while the challenge is open
+    assert_error_code(process(&mut ctx, std::slice::from_ref(&evaluate_ix), &[]).await, ErrorCode::SubmissionNotRevealed);
+    assert_error_code(process(&mut ctx, &[reveal_ix("https://example.com/reveal")], &[&revealer.user]).await, ErrorCode::RevealNotOpen);
+
+    // After expiry the reveal must match the commitment, after which the submission can be evaluated
+    advance_clock(&mut ctx, 86_401).await;
+    assert_error_code(process(&mut ctx, &[reveal_ix("https://example.com/other")], &[&revealer.user]).await, ErrorCode::InvalidReveal);
+    process(&mut ctx, &[reveal_ix("https://example.com/reveal")], &[&revealer.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &revealer.submission).await;
+    assert_eq!(submission.content_data_hash, content_data_hash);
+    assert_eq!(submission.content_data_url, "https://example.com/reveal");
+    process(&mut ctx, &[evaluate_ix], &[]).await.unwrap();
+
+    // Anyone may close the unrevealed submission once the reveal period ends, refunding its rent to the submitter
+    let close_ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::CloseUnrevealedSubmission {
+            crux: test_crux.crux.pubkey(),
+            profile_owner: unrevealed.user.pubkey(),
+            user_profile: unrevealed.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            submission: unrevealed.submission,
+            submission_entries: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::CloseUnrevealedSubmission {
+            _bump_user_profile: unrevealed.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: unrevealed.bump_submission,
+        }
+        .data(),
+    };
+    assert_error_code(process(&mut ctx, std::slice::from_ref(&close_ix), &[]).await, ErrorCode::RevealPeriodNotOver);
+
+    advance_clock(&mut ctx, 3_600).await;
+    let submitter_balance = ctx.banks_client.get_balance(unrevealed.user.pubkey()).await.unwrap();
+    let submission_rent = ctx.banks_client.get_balance(unrevealed.submission).await.unwrap();
+    process(&mut ctx, &[close_ix], &[]).await.unwrap();
+
+    assert!(ctx.banks_client.get_account(unrevealed.submission).await.unwrap().is_none());
+    assert_eq!(ctx.banks_client.get_balance(unrevealed.user.pubkey()).await.unwrap(), submitter_balance + submission_rent);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_rejected_for_auto_graded_and_verified_challenges() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+
+    // Answers and verifications are only accepted before expiry, while committed submissions are only revealed after it
+    for challenge_config in [
+        ChallengeConfig { reveal_duration: 3_600, answer: Some("42"), ..ChallengeConfig::default() },
+        ChallengeConfig { reveal_duration: 3_600, verifier_program: token_holding_verifier::id(), ..ChallengeConfig::default() },
+    ] {
+        let result = try_create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+        assert_error_code(result, ErrorCode::CommitRevealNotSupported);
+    }
+}
+
+fn submit_answer_ix(test_crux: &TestCrux, test_challenge: &TestChallenge, test_submission: &TestSubmission, answer: &str) -> Instruction {
+    let (answer_attempts, _) = find_pda(&[b"answer_attempts".as_ref(), test_challenge.challenge.as_ref(), test_submission.user_profile.as_ref()]);
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::SubmitAnswer {
+            crux: test_crux.crux.pubkey(),
+            profile_owner: test_submission.user.pubkey(),
+            user_profile: test_submission.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_vault: test_challenge.bounty_vault,
+            submission: test_submission.submission,
+            answer_attempts,
+            crux_authority: None,
+            bounty_token_vault: None,
+            profile_owner_token_account: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::SubmitAnswer {
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+            answer: answer.to_string(),
+        }
+        .data(),
+    }
+}
+
+#[tokio::test]
+async fn test_auto_graded_answers_with_attempt_limit_and_cooldown() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { answer: Some("42"), max_answer_attempts: 2, answer_cooldown: 60, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let first_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let second_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    // A wrong answer is recorded as an attempt, and further answers must wait for the cooldown
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &first_submission, "41")], &[&first_submission.user]).await.unwrap();
+    let submission: Submission = fetch_account(&mut ctx, &first_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Pending);
+    assert_eq!(submission.answer_attempts, 1);
+
+    assert_error_code(process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &first_submission, "42")], &[&first_submission.user]).await, ErrorCode::AnswerCooldownActive);
+
+    // Auto grading is held back while evaluation is paused
+    advance_clock(&mut ctx, 60).await;
+    let crux_manager = ctx.payer.pubkey();
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags { evaluation_paused: true, ..CruxPauseFlags::default() });
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    assert_error_code(process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &first_submission, "42")], &[&first_submission.user]).await, ErrorCode::EvaluationPaused);
+
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags::default());
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // The correct answer completes the submission without a moderator
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &first_submission, "42")], &[&first_submission.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &first_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &first_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 100);
+    assert_eq!(user_profile.challenges_completed, 1);
+
+    // Attempts are limited per submission
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &second_submission, "1")], &[&second_submission.user]).await.unwrap();
+    advance_clock(&mut ctx, 60).await;
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &second_submission, "2")], &[&second_submission.user]).await.unwrap();
+    advance_clock(&mut ctx, 60).await;
+    assert_error_code(process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &second_submission, "42")], &[&second_submission.user]).await, ErrorCode::AnswerAttemptsExhausted);
+}
+
+#[tokio::test]
+async fn test_answer_attempts_kept_across_deleted_and_additional_submissions() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let copy_user = |user: &Keypair| Keypair::from_bytes(&user.to_bytes()).unwrap();
+
+    // Deleting an auto-graded submission and submitting again doesn't reset the user's attempts or cooldown
+    let challenge_config = ChallengeConfig { answer: Some("42"), max_answer_attempts: 2, answer_cooldown: 60, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let user = copy_user(&test_submission.user);
+
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &test_submission, "1")], &[&user]).await.unwrap();
+
+    let ix = delete_submission_ix(&test_crux, &test_challenge, &test_submission, None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+    let resubmission = submit_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user)).await;
+
+    let ix = submit_answer_ix(&test_crux, &test_challenge, &resubmission, "2");
+    assert_error_code(process(&mut ctx, &[ix], &[&user]).await, ErrorCode::AnswerCooldownActive);
+
+    advance_clock(&mut ctx, 60).await;
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &resubmission, "2")], &[&user]).await.unwrap();
+
+    let answer_attempts: AnswerAttempts = fetch_account(&mut ctx, &find_pda(&[b"answer_attempts".as_ref(), test_challenge.challenge.as_ref(), resubmission.user_profile.as_ref()]).0).await;
+    assert_eq!(answer_attempts.answer_attempts, 2);
+
+    advance_clock(&mut ctx, 60).await;
+    let ix = submit_answer_ix(&test_crux, &test_challenge, &resubmission, "42");
+    assert_error_code(process(&mut ctx, &[ix], &[&user]).await, ErrorCode::AnswerAttemptsExhausted);
+
+    // Attempts are shared by all of a user's entries to a challenge accepting several submissions per user
+    let challenge_config = ChallengeConfig { answer: Some("42"), max_answer_attempts: 1, max_submissions_per_user: 2, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let first_entry = submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(0)).await.unwrap();
+    let second_entry = submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_user(&user), Some(1)).await.unwrap();
+
+    process(&mut ctx, &[submit_answer_ix(&test_crux, &test_challenge, &first_entry, "1")], &[&user]).await.unwrap();
+    let ix = submit_answer_ix(&test_crux, &test_challenge, &second_entry, "42");
+    assert_error_code(process(&mut ctx, &[ix], &[&user]).await, ErrorCode::AnswerAttemptsExhausted);
+}
+
+// Completions moved down the reputation schedule are passed after the co-author profiles, each with its user and co-author profiles
+fn moved_completion_metas(moved_completions: &[(&TestSubmission, &[Pubkey])]) -> Vec<AccountMeta> {
+    moved_completions
+        .iter()
+        .flat_map(|(test_submission, co_author_profiles)| {
+            [AccountMeta::new(test_submission.submission, false), AccountMeta::new(test_submission.user_profile, false)]
+                .into_iter()
+                .chain(co_author_profiles.iter().map(|co_author_profile| AccountMeta::new(*co_author_profile, false)))
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_verifier_program_completes_submission() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let required_mint = create_mint(&mut ctx).await;
+    let challenge_config = ChallengeConfig {
+        verifier_program: token_holding_verifier::id(),
+        verifier_params: required_mint.to_bytes(),
+        reputation_schedule: [300, 200, 0],
+        ..ChallengeConfig::default()
+    };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    advance_clock(&mut ctx, 10).await;
+    let later_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let verify_ix_moving = |token_account: Pubkey, moved_completions: &[(&TestSubmission, &[Pubkey])]| {
+        let mut ix = Instruction {
+            program_id: challenger::id(),
+            accounts: challenger::accounts::VerifySubmission {
+                crux: test_crux.crux.pubkey(),
+                profile_owner: test_submission.user.pubkey(),
+                user_profile: test_submission.user_profile,
+                challenge: test_challenge.challenge,
+                challenge_seed: test_challenge.challenge_seed,
+                bounty_vault: test_challenge.bounty_vault,
+                submission: test_submission.submission,
+                verifier_program: token_holding_verifier::id(),
+                crux_authority: None,
+                bounty_token_vault: None,
+                profile_owner_token_account: None,
+                token_program: None,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: challenger::instruction::VerifySubmission {
+                _bump_user_profile: test_submission.bump_user_profile,
+                _bump_challenge: test_challenge.bump_challenge,
+                _bump_submission: test_submission.bump_submission,
+                _bump_bounty_vault: test_challenge.bump_bounty_vault,
+            }
+            .data(),
+        };
+        ix.accounts.extend(moved_completion_metas(moved_completions));
+        ix.accounts.push(AccountMeta::new_readonly(token_account, false));
+        ix
+    };
+    let verify_ix = |token_account: Pubkey| verify_ix_moving(token_account, &[]);
+
+    // An empty token account does not satisfy the verifier
+    let empty_token_account = create_token_account(&mut ctx, &required_mint, &test_submission.user.pubkey(), 0).await;
+    assert_error_code(process(&mut ctx, &[verify_ix(empty_token_account)], &[&test_submission.user]).await, ErrorCode::VerificationFailed);
+
+    // Verification is held back while evaluation is paused
+    let token_account = create_token_account(&mut ctx, &required_mint, &test_submission.user.pubkey(), 1).await;
+    let crux_manager = ctx.payer.pubkey();
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags { evaluation_paused: true, ..CruxPauseFlags::default() });
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    assert_error_code(process(&mut ctx, &[verify_ix(token_account)], &[&test_submission.user]).await, ErrorCode::EvaluationPaused);
+
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags::default());
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // Verification is refused while the challenge is closed
+    process(&mut ctx, &[update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, ChallengeStatus::Closed)], &[]).await.unwrap();
+    assert_error_code(process(&mut ctx, &[verify_ix(token_account)], &[&test_submission.user]).await, ErrorCode::ChallengeNotOpen);
+
+    process(&mut ctx, &[update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, ChallengeStatus::Open)], &[]).await.unwrap();
+
+    // A later-posted submission completed first is moved down a rank, its accounts passed ahead of the verifier's
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &later_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    assert_error_code(process(&mut ctx, &[verify_ix(token_account)], &[&test_submission.user]).await, ErrorCode::RankedCompletionsRequired);
+
+    // Holding the required mint completes the submission
+    let ix = verify_ix_moving(token_account, &[(&later_submission, &[])]);
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 300);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &later_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 200);
+}
+
+#[tokio::test]
+async fn test_challenge_schedule_and_default_resolution_after_evaluation_deadline() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    let update_crux_params_ix = |auto_accept_after_evaluation_deadline: bool| Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::UpdateCruxParams { crux, crux_manager: ctx.payer.pubkey(), system_program: system_program::id() }
+            .to_account_metas(None),
+        data: challenger::instruction::UpdateCruxParams {
+            new_crux_fees: CruxFees { profile_fee: 0, submission_fee: 0 },
+            new_crux_constants: CruxConstants { max_tags_length: 3, max_title_length: 256, max_url_length: 256 },
+            new_crux_policies: CruxPolicies::default(),
+            new_appeal_fee: 0,
+            new_auto_accept_after_evaluation_deadline: auto_accept_after_evaluation_deadline,
+        }
+        .data(),
+    };
+    let auto_accept_ix = update_crux_params_ix(true);
+    let auto_reject_ix = update_crux_params_ix(false);
+    process(&mut ctx, &[auto_accept_ix], &[]).await.unwrap();
+
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig {
+        bounty_lamports: 1_000_000,
+        bounty_per_completion: 500_000,
+        opens_after: 100,
+        evaluation_deadline_after_expiry: Some(100),
+        ..ChallengeConfig::default()
+    };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    // Submissions are refused until the challenge opens
+    let early_user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &early_user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&early_user]).await.unwrap();
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, early_user, None).await, ErrorCode::ChallengeNotOpen);
+
+    advance_clock(&mut ctx, 100).await;
+    let accepted_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let rejected_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let resolve_expired_submission_ix = |test_submission: &TestSubmission| Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::ResolveExpiredSubmission {
+            crux,
+            profile_owner: test_submission.user.pubkey(),
+            user_profile: test_submission.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_vault: test_challenge.bounty_vault,
+            submission: test_submission.submission,
+            crux_authority: None,
+            bounty_token_vault: None,
+            profile_owner_token_account: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::ResolveExpiredSubmission {
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+        }
+        .data(),
+    };
+
+    // Pending submissions can only be resolved by default once the evaluation deadline has passed
+    assert_error_code(process(&mut ctx, &[resolve_expired_submission_ix(&accepted_submission)], &[]).await, ErrorCode::EvaluationDeadlineNotReached);
+
+    advance_clock(&mut ctx, 86_400 + 100).await;
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &accepted_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::EvaluationDeadlinePassed);
+
+    // Anyone can resolve the submission with the crux's default
+    let user_lamports_before = ctx.banks_client.get_balance(accepted_submission.user.pubkey()).await.unwrap();
+    process(&mut ctx, &[resolve_expired_submission_ix(&accepted_submission)], &[]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &accepted_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+    assert_eq!(submission.score_bps, MAX_SCORE_BPS);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &accepted_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 100);
+    assert_eq!(ctx.banks_client.get_balance(accepted_submission.user.pubkey()).await.unwrap(), user_lamports_before + 500_000);
+
+    // Once resolved, a submission is no longer pending
+    assert_error_code(process(&mut ctx, &[resolve_expired_submission_ix(&accepted_submission)], &[]).await, ErrorCode::InvalidSubmissionStateTransition);
+
+    process(&mut ctx, &[auto_reject_ix], &[]).await.unwrap();
+    process(&mut ctx, &[resolve_expired_submission_ix(&rejected_submission)], &[]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &rejected_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Rejected);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &rejected_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 0);
+}
+
+#[tokio::test]
+async fn test_challenge_schedule_validated_on_creation_and_edit() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+
+    // Commit-reveal challenges can't be evaluated before committed submissions could be revealed
+    let challenge_config = ChallengeConfig { reveal_duration: 3_600, evaluation_deadline_after_expiry: Some(1_800), ..ChallengeConfig::default() };
+    let result = try_create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    assert_error_code(result, ErrorCode::InvalidChallengeSchedule);
+
+    let challenge_config = ChallengeConfig {
+        reveal_duration: 3_600,
+        opens_after: 7_200,
+        evaluation_deadline_after_expiry: Some(3_600),
+        reputation_schedule: [300, 200, 0],
+        ..ChallengeConfig::default()
+    };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+
+    let edit_ix = |challenge_expires_ts: u64, reputation: u64| {
+        edit_challenge_ix(&test_crux, &test_moderator, &test_challenge, ChallengeEdit {
+            tags: vec![Tags::Development],
+            title: "Test challenge".to_string(),
+            content_data_url: "https://example.com/challenge".to_string(),
+            challenge_expires_ts,
+            reputation,
+        })
+    };
+
+    // Edits are held to the schedule and scheduled reputation enforced on creation
+    let ix = edit_ix(challenge.challenge_opens_ts, 100);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidChallengeSchedule);
+    let ix = edit_ix(challenge.challenge_expires_ts + 1, 100);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidChallengeSchedule);
+    let ix = edit_ix(challenge.challenge_expires_ts, 250);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidReputationSchedule);
+
+    let ix = edit_ix(challenge.challenge_expires_ts - 1, 200);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.reputation, 200);
+}
+
+#[tokio::test]
+async fn test_capped_completions_with_ranked_reputation_schedule() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { max_completions: 2, reputation_schedule: [300, 200, 0], ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let first_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    advance_clock(&mut ctx, 10).await;
+    let second_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    advance_clock(&mut ctx, 10).await;
+    let third_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    // The earliest-posted completions earn the scheduled reputation
+    for test_submission in [&first_submission, &second_submission] {
+        let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        process(&mut ctx, &[ix], &[]).await.unwrap();
+    }
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &first_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 300);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &second_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 200);
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.completion_count, 2);
+
+    // Once the maximum number of completions is reached, the challenge closes and no more submissions are completed or accepted
+    assert_eq!(challenge.challenge_status, ChallengeStatus::Closed);
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &third_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::ChallengeCompletionsFilled);
+
+    let late_user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &late_user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&late_user]).await.unwrap();
+    let copy_late_user = || Keypair::from_bytes(&late_user.to_bytes()).unwrap();
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_late_user(), None).await, ErrorCode::ChallengeNotOpen);
+
+    // Even when reopened by a moderator, the filled challenge refuses new submissions
+    process(&mut ctx, &[update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, ChallengeStatus::Open)], &[]).await.unwrap();
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, copy_late_user(), None).await, ErrorCode::ChallengeCompletionsFilled);
+
+    // Revoking a completion releases its place and rank to the next completed submission
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &second_submission, 1, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &third_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &second_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 0);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &third_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 200);
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.completion_count, 2);
+    assert_eq!(challenge.challenge_status, ChallengeStatus::Closed);
+    assert_eq!(challenge.ranked_completions[0].submission, first_submission.submission);
+    assert_eq!(challenge.ranked_completions[1].submission, third_submission.submission);
+}
+
+#[tokio::test]
+async fn test_ranked_reputation_for_completions_out_of_posted_order() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { reputation_schedule: [300, 200, 150], ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let mut test_submissions = Vec::new();
+    for _ in 0..3 {
+        test_submissions.push(create_submission(&mut ctx, &test_crux, &test_challenge).await);
+        advance_clock(&mut ctx, 10).await;
+    }
+
+    // The last-posted submission is a team submission, whose co-author shares in each change of rank
+    let co_author = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &co_author.pubkey(), None);
+    process(&mut ctx, &[ix], &[&co_author]).await.unwrap();
+    let (co_author_profile, bump_co_author_profile) = find_pda(&[b"user_profile".as_ref(), test_crux.crux.pubkey().as_ref(), co_author.pubkey().as_ref()]);
+    let co_author_profiles = [co_author_profile];
+
+    let submitter = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &submitter.pubkey(), None);
+    process(&mut ctx, &[ix], &[&submitter]).await.unwrap();
+    let submission_config = SubmissionConfig { co_author_profiles: co_author_profiles.to_vec(), weights: vec![1, 1], ..SubmissionConfig::default() };
+    let team_submission = submit_with_config(&mut ctx, &test_crux, &test_challenge, submitter, submission_config).await.unwrap();
+    let ix = accept_co_authorship_ix(&test_crux, &test_challenge, &team_submission.submission, &co_author.pubkey(), bump_co_author_profile);
+    process(&mut ctx, &[ix], &[&co_author]).await.unwrap();
+
+    let mut ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &team_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    ix.accounts.extend(co_author_profiles.iter().map(|co_author_profile| AccountMeta::new(*co_author_profile, false)));
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // An earlier-posted completion evaluated later takes its rank in posting order, moving the later-posted completions down
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submissions[2], 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::RankedCompletionsRequired);
+
+    for evaluated_index in [2, 1, 0] {
+        let mut moved_completions: Vec<(&TestSubmission, &[Pubkey])> =
+            test_submissions[evaluated_index + 1..].iter().map(|test_submission| (test_submission, &[][..])).collect();
+        moved_completions.push((&team_submission, &co_author_profiles));
+
+        let mut ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submissions[evaluated_index], 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        ix.accounts.extend(moved_completion_metas(&moved_completions));
+        process(&mut ctx, &[ix], &[]).await.unwrap();
+    }
+
+    for (test_submission, expected_reputation) in test_submissions.iter().zip([300, 200, 150]) {
+        let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+        assert_eq!(user_profile.reputation_score, expected_reputation);
+    }
+
+    // The team submission fell out of the schedule to the base reputation, still split with its co-author
+    let user_profile: UserProfile = fetch_account(&mut ctx, &team_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 50);
+    let user_profile: UserProfile = fetch_account(&mut ctx, &co_author_profile).await;
+    assert_eq!(user_profile.reputation_score, 50);
+    assert_eq!(user_profile.challenges_completed, 1);
+    let submission: Submission = fetch_account(&mut ctx, &team_submission.submission).await;
+    assert_eq!(submission.awarded_reputation, 50);
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    for (ranked, test_submission) in challenge.ranked_completions.iter().zip(&test_submissions) {
+        assert_eq!(ranked.submission, test_submission.submission);
+    }
+
+    // A revoked completion frees its rank for the next completion posted between its neighbours
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submissions[1], 1, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submissions[1], 2, SubmissionState::Completed, MAX_SCORE_BPS);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submissions[1].user_profile).await;
+    assert_eq!(user_profile.reputation_score, 200);
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.completion_count, 4);
+    assert_eq!(challenge.ranked_completions[1].submission, test_submissions[1].submission);
+}
+
+#[tokio::test]
+async fn test_prerequisites_and_minimum_reputation_gate_submissions() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let first_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+
+    let challenge_config = ChallengeConfig { prerequisites: vec![first_challenge.challenge], ..ChallengeConfig::default() };
+    let second_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let challenge_config = ChallengeConfig { min_reputation_score: 100, ..ChallengeConfig::default() };
+    let third_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    // Prerequisites must name existing challenges
+    let challenge_config = ChallengeConfig { prerequisites: vec![Pubkey::new_unique()], ..ChallengeConfig::default() };
+    assert_error_code(
+        try_create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await,
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+
+    let first_submission = create_submission(&mut ctx, &test_crux, &first_challenge).await;
+    let user = first_submission.user.insecure_clone();
+    let other_user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &other_user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&other_user]).await.unwrap();
+
+    // Submissions to the second challenge are refused until the first challenge has been completed
+    let submission_config = SubmissionConfig { prerequisite_submissions: vec![first_submission.submission], ..SubmissionConfig::default() };
+    let result = submit_with_config(&mut ctx, &test_crux, &second_challenge, user.insecure_clone(), submission_config).await;
+    assert_error_code(result, ErrorCode::PrerequisiteNotCompleted);
+
+    // Submissions to the third challenge are refused until the user has earned enough reputation
+    let result = submit_entry_as(&mut ctx, &test_crux, &third_challenge, user.insecure_clone(), None).await;
+    assert_error_code(result, ErrorCode::InsufficientReputation);
+
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &first_challenge, &first_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // The completed submission must be passed, and only counts for the user who completed it
+    let result = submit_with_config(&mut ctx, &test_crux, &second_challenge, user.insecure_clone(), SubmissionConfig::default()).await;
+    assert_error_code(result, ErrorCode::PrerequisiteNotCompleted);
+
+    let submission_config = SubmissionConfig { prerequisite_submissions: vec![first_submission.submission], ..SubmissionConfig::default() };
+    let result = submit_with_config(&mut ctx, &test_crux, &second_challenge, other_user.insecure_clone(), submission_config).await;
+    assert_error_code(result, ErrorCode::PrerequisiteNotCompleted);
+
+    let submission_config = SubmissionConfig { prerequisite_submissions: vec![first_submission.submission], ..SubmissionConfig::default() };
+    submit_with_config(&mut ctx, &test_crux, &second_challenge, user.insecure_clone(), submission_config).await.unwrap();
+
+    let result = submit_entry_as(&mut ctx, &test_crux, &third_challenge, other_user, None).await;
+    assert_error_code(result, ErrorCode::InsufficientReputation);
+    submit_entry_as(&mut ctx, &test_crux, &third_challenge, user, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_recurring_challenge_submissions_per_period_and_streaks() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { period_length: 100, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+    let challenge_config = ChallengeConfig { period_length: 100, ..ChallengeConfig::default() };
+    let other_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+    let (user_profile_key, _) = find_pda(&[b"user_profile".as_ref(), test_crux.crux.pubkey().as_ref(), user.pubkey().as_ref()]);
+
+    // Each completed period is rewarded, and consecutive periods extend the user's streak until one is missed
+    for (seconds_elapsed, period_index, expected_streak) in [(0, 0, 1), (100, 1, 2), (100, 2, 3), (200, 4, 1)] {
+        advance_clock(&mut ctx, seconds_elapsed).await;
+
+        // A missed period lapses the stored streak until the next completion
+        if period_index == 4 {
+            let now_ts = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp as u64;
+            let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+            assert_eq!(user_profile.streak, 3);
+            assert_eq!(user_profile.current_streak(now_ts).unwrap(), 0);
+        }
+
+        let submission_config = SubmissionConfig { period_index: Some(period_index), ..SubmissionConfig::default() };
+        let test_submission = submit_with_config(&mut ctx, &test_crux, &test_challenge, user.insecure_clone(), submission_config).await.unwrap();
+
+        // Only one submission is accepted per period, and only for the current period
+        let submission_config = SubmissionConfig { period_index: Some(period_index), ..SubmissionConfig::default() };
+        assert_error_code(submit_with_config(&mut ctx, &test_crux, &test_challenge, user.insecure_clone(), submission_config).await, SystemError::AccountAlreadyInUse as u32);
+        let submission_config = SubmissionConfig { period_index: Some(period_index + 1), ..SubmissionConfig::default() };
+        assert_error_code(submit_with_config(&mut ctx, &test_crux, &test_challenge, user.insecure_clone(), submission_config).await, ErrorCode::InvalidSubmissionIndex);
+
+        let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        process(&mut ctx, &[ix], &[]).await.unwrap();
+
+        // Completing another recurring challenge while the streak is alive leaves the streak with the first challenge
+        if period_index == 1 {
+            let submission_config = SubmissionConfig { period_index: Some(period_index), ..SubmissionConfig::default() };
+            let other_submission = submit_with_config(&mut ctx, &test_crux, &other_challenge, user.insecure_clone(), submission_config).await.unwrap();
+            let ix = evaluate_submission_ix(&test_crux, &test_moderator, &other_challenge, &other_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+            process(&mut ctx, &[ix], &[]).await.unwrap();
+        }
+
+        let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+        assert_eq!(user_profile.streak_challenge, test_challenge.challenge);
+        assert_eq!(user_profile.streak, expected_streak);
+        assert_eq!(user_profile.streak_period_index, period_index);
+    }
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+    assert_eq!(user_profile.challenges_completed, 5);
+    assert_eq!(user_profile.reputation_score, 500);
+}
+
+#[tokio::test]
+async fn test_streak_recomputed_from_completed_periods_in_any_order() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { period_length: 100, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+    let (user_profile_key, _) = find_pda(&[b"user_profile".as_ref(), test_crux.crux.pubkey().as_ref(), user.pubkey().as_ref()]);
+
+    let mut submissions = vec![];
+    for period_index in 0..3 {
+        if period_index > 0 {
+            advance_clock(&mut ctx, 100).await;
+        }
+        let submission_config = SubmissionConfig { period_index: Some(period_index), ..SubmissionConfig::default() };
+        submissions.push(submit_with_config(&mut ctx, &test_crux, &test_challenge, user.insecure_clone(), submission_config).await.unwrap());
+    }
+
+    // Periods evaluated out of order still join into one run once the gap is filled
+    for (period_index, expected_streak) in [(2, 1), (0, 1), (1, 3)] {
+        let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &submissions[period_index], 0, SubmissionState::Completed, MAX_SCORE_BPS);
+        process(&mut ctx, &[ix], &[]).await.unwrap();
+
+        let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+        assert_eq!(user_profile.streak, expected_streak);
+        assert_eq!(user_profile.streak_period_index, 2);
+    }
+
+    // Revoking a completed period breaks the run it belonged to
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &submissions[1], 1, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+    assert_eq!(user_profile.streak, 1);
+
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &submissions[1], 2, SubmissionState::Completed, MAX_SCORE_BPS);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+    assert_eq!(user_profile.streak, 3);
+
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &submissions[2], 1, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    let now_ts = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp as u64;
+    let user_profile: UserProfile = fetch_account(&mut ctx, &user_profile_key).await;
+    assert_eq!(user_profile.streak, 0);
+    assert_eq!(user_profile.current_streak(now_ts).unwrap(), 2);
+}
+
+fn update_challenge_status_accounts(
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    test_challenge: &TestChallenge,
+) -> challenger::accounts::UpdateChallengeStatus {
+    challenger::accounts::UpdateChallengeStatus {
+        crux: test_crux.crux.pubkey(),
+        moderator: test_moderator.moderator,
+        moderator_profile: test_moderator.moderator_profile,
+        challenge: test_challenge.challenge,
+        challenge_seed: test_challenge.challenge_seed,
+        bounty_vault: test_challenge.bounty_vault,
+        bounty_funder: test_moderator.moderator,
+        crux_authority: None,
+        bounty_token_vault: None,
+        bounty_token_funder_token_account: None,
+        token_program: None,
+        system_program: system_program::id(),
+    }
+}
+
+fn update_challenge_status_ix(
+    test_crux: &TestCrux,
+    test_moderator: &TestModerator,
+    test_challenge: &TestChallenge,
+    new_challenge_status: ChallengeStatus,
+) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: update_challenge_status_accounts(test_crux, test_moderator, test_challenge).to_account_metas(None),
+        data: challenger::instruction::UpdateChallengeStatus {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            new_challenge_status,
+        }
+        .data(),
+    }
+}
+
+#[tokio::test]
+async fn test_challenge_status_lifecycle() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let challenge_config = ChallengeConfig { is_draft: true, ..ChallengeConfig::default() };
+    let test_challenge = create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, challenge_config).await;
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.challenge_status, ChallengeStatus::Draft);
+    assert_eq!(challenge.version, LATEST_CHALLENGE_VERSION);
+    assert_eq!(challenge.title, "Test challenge");
+    assert_eq!(challenge.challenge_author, test_moderator.moderator_profile);
+    assert_eq!(challenge.bounty_vault, test_challenge.bounty_vault);
+
+    let update_challenge_status_ix =
+        |new_challenge_status: ChallengeStatus| update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, new_challenge_status);
+
+    let mut users = vec![];
+    for _ in 0..3 {
+        let user = fund_wallet(&mut ctx).await;
+        let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+        process(&mut ctx, &[ix], &[&user]).await.unwrap();
+        users.push(user);
+    }
+
+    // Drafts take no submissions until they are published
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, users[0].insecure_clone(), None).await, ErrorCode::ChallengeNotOpen);
+
+    process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Open)], &[]).await.unwrap();
+    let test_submission = submit_entry_as(&mut ctx, &test_crux, &test_challenge, users[0].insecure_clone(), None).await.unwrap();
+
+    // Closed challenges take no new submissions or edits until they are reopened, and only closed challenges can be archived
+    assert_error_code(process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Archived)], &[]).await, ErrorCode::InvalidChallengeStatusTransition);
+    process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Closed)], &[]).await.unwrap();
+
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, users[1].insecure_clone(), None).await, ErrorCode::ChallengeNotOpen);
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), "https://example.com/edited");
+    assert_error_code(process(&mut ctx, &[ix], &[&test_submission.user]).await, ErrorCode::ChallengeNotOpen);
+
+    process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Open)], &[]).await.unwrap();
+    submit_entry_as(&mut ctx, &test_crux, &test_challenge, users[1].insecure_clone(), None).await.unwrap();
+
+    // Archived challenges keep their data and can no longer be reopened or deleted
+    process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Closed)], &[]).await.unwrap();
+    process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Archived)], &[]).await.unwrap();
+    assert_error_code(process(&mut ctx, &[update_challenge_status_ix(ChallengeStatus::Open)], &[]).await, ErrorCode::InvalidChallengeStatusTransition);
+    assert_error_code(submit_entry_as(&mut ctx, &test_crux, &test_challenge, users[2].insecure_clone(), None).await, ErrorCode::ChallengeNotOpen);
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::DeleteChallenge {
+            crux: test_crux.crux.pubkey(),
+            moderator: test_moderator.moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_vault: test_challenge.bounty_vault,
+            bounty_funder: test_moderator.moderator,
+            receiver: test_moderator.moderator,
+            crux_authority: None,
+            bounty_token_vault: None,
+            bounty_token_funder_token_account: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::DeleteChallenge {
+            _bump_moderator_profile: test_moderator.bump_moderator_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+        }
+        .data(),
+    };
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::ChallengeArchived);
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.challenge_status, ChallengeStatus::Archived);
+}
+
+#[tokio::test]
+async fn test_archiving_refunds_unclaimed_bounty() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 1_000_000, 400_000, 1).await;
+    let moderator = test_moderator.moderator;
+
+    let bounty_token_mint = create_mint(&mut ctx).await;
+    let moderator_token_account = create_token_account(&mut ctx, &bounty_token_mint, &moderator, 1_000).await;
+    let bounty_token_vault = fund_challenge_token_bounty(&mut ctx, &test_crux, &test_moderator, &test_challenge, &bounty_token_mint, &moderator_token_account, 400, 150).await;
+
+    // One submission is completed and paid, while another is still pending when the challenge is archived
+    let completed_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let pending_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let user_token_account = create_token_account(&mut ctx, &bounty_token_mint, &completed_submission.user.pubkey(), 0).await;
+
+    let mut ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &completed_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    ix.accounts = challenger::accounts::EvaluateSubmission {
+        crux_authority: Some(test_crux.crux_authority),
+        bounty_token_vault: Some(bounty_token_vault),
+        profile_owner_token_account: Some(user_token_account),
+        token_program: Some(spl_token::id()),
+        ..evaluate_submission_accounts(&test_crux, &test_moderator, &test_challenge, &completed_submission, 0)
+    }
+    .to_account_metas(None);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    process(&mut ctx, &[update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, ChallengeStatus::Closed)], &[]).await.unwrap();
+
+    // Archiving a challenge holding a token bounty requires the token accounts
+    let ix = update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, ChallengeStatus::Archived);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::TokenAccountsRequired);
+
+    // Archiving returns the unclaimed 600_000 lamports and 250 tokens to the funder and closes both vaults
+    let moderator_lamports = ctx.banks_client.get_balance(moderator).await.unwrap();
+    let bounty_vault_lamports = ctx.banks_client.get_balance(test_challenge.bounty_vault).await.unwrap();
+    let bounty_token_vault_lamports = ctx.banks_client.get_balance(bounty_token_vault).await.unwrap();
+    let fee = ctx.genesis_config().fee_rate_governor.lamports_per_signature;
+
+    let mut ix = update_challenge_status_ix(&test_crux, &test_moderator, &test_challenge, ChallengeStatus::Archived);
+    ix.accounts = challenger::accounts::UpdateChallengeStatus {
+        crux_authority: Some(test_crux.crux_authority),
+        bounty_token_vault: Some(bounty_token_vault),
+        bounty_token_funder_token_account: Some(moderator_token_account),
+        token_program: Some(spl_token::id()),
+        ..update_challenge_status_accounts(&test_crux, &test_moderator, &test_challenge)
+    }
+    .to_account_metas(None);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let challenge: Challenge = fetch_account(&mut ctx, &test_challenge.challenge).await;
+    assert_eq!(challenge.challenge_status, ChallengeStatus::Archived);
+    assert_eq!(challenge.bounty_lamports, 0);
+    assert_eq!(challenge.bounty_token_amount, 0);
+    assert_eq!(bounty_vault_lamports, 600_000 + ctx.banks_client.get_rent().await.unwrap().minimum_balance(8));
+    assert_eq!(token_balance(&mut ctx, &moderator_token_account).await, 850);
+    assert!(ctx.banks_client.get_account(test_challenge.bounty_vault).await.unwrap().is_none());
+    assert!(ctx.banks_client.get_account(bounty_token_vault).await.unwrap().is_none());
+    assert_eq!(
+        ctx.banks_client.get_balance(moderator).await.unwrap(),
+        moderator_lamports + bounty_vault_lamports + bounty_token_vault_lamports - fee
+    );
+
+    // Submissions to an archived challenge can no longer be decided
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &pending_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::ChallengeArchived);
+}
+
+#[tokio::test]
+async fn test_moderators_cannot_judge_own_submissions_or_challenges() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+
+    // Forbid challenge authors from evaluating their own challenges
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::UpdateCruxParams {
+            crux: test_crux.crux.pubkey(),
+            crux_manager: ctx.payer.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::UpdateCruxParams {
+            new_crux_fees: CruxFees { profile_fee: 0, submission_fee: 0 },
+            new_crux_constants: CruxConstants { max_tags_length: 3, max_title_length: 256, max_url_length: 256 },
+            new_crux_policies: CruxPolicies { forbid_author_evaluation: true },
+            new_appeal_fee: 0,
+            new_auto_accept_after_evaluation_deadline: false,
+        }
+        .data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let author = create_moderator(&mut ctx, &test_crux).await;
+    let submitter_wallet = fund_wallet(&mut ctx).await;
+    let submitter = create_moderator_with_wallet(&mut ctx, &test_crux, Keypair::from_bytes(&submitter_wallet.to_bytes()).unwrap()).await;
+    let reviewer_wallet = fund_wallet(&mut ctx).await;
+    let reviewer = create_moderator_with_wallet(&mut ctx, &test_crux, reviewer_wallet).await;
+
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &author, 10, 0, 0, 1).await;
+    let test_submission = submit_as(&mut ctx, &test_crux, &test_challenge, submitter_wallet).await;
+
+    let evaluate = |test_moderator: &TestModerator| {
+        evaluate_submission_ix(&test_crux, test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS)
+    };
+
+    assert_error_code(process(&mut ctx, &[evaluate(&submitter)], &[&submitter.wallet]).await, ErrorCode::SelfEvaluationForbidden);
+    assert_error_code(process(&mut ctx, &[evaluate(&author)], &[]).await, ErrorCode::AuthorEvaluationForbidden);
+    process(&mut ctx, &[evaluate(&reviewer)], &[&reviewer.wallet]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+}
+
+fn appeal_submission_ix(test_crux: &TestCrux, test_challenge: &TestChallenge, test_submission: &TestSubmission) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::AppealSubmission {
+            crux: test_crux.crux.pubkey(),
+            crux_treasury: test_crux.crux_treasury,
+            profile_owner: test_submission.user.pubkey(),
+            user_profile: test_submission.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            submission: test_submission.submission,
+            appeal_content_data_hash: Pubkey::new_unique(),
+            profile_owner_token_account: None,
+            crux_treasury_token_account: None,
+            crux_token_ledger: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::AppealSubmission {
+            _bump_treasury: test_crux.bump_treasury,
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+        }
+        .data(),
+    }
+}
+
+// Upholds an appeal, passing the resolver's review of the rejected round when the resolver is a moderator rather than the crux manager
+fn resolve_appeal_ix(
+    test_crux: &TestCrux,
+    test_challenge: &TestChallenge,
+    test_submission: &TestSubmission,
+    resolver: &Pubkey,
+    resolver_profile: Option<Pubkey>,
+) -> Instruction {
+    let previous_review = resolver_profile.map(|resolver_profile| review_address(test_submission, &resolver_profile, 0));
+
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::ResolveAppeal {
+            crux: test_crux.crux.pubkey(),
+            crux_treasury: test_crux.crux_treasury,
+            resolver: *resolver,
+            resolver_profile,
+            previous_review,
+            profile_owner: test_submission.user.pubkey(),
+            user_profile: test_submission.user_profile,
+            challenge: test_challenge.challenge,
+            challenge_seed: test_challenge.challenge_seed,
+            bounty_vault: test_challenge.bounty_vault,
+            submission: test_submission.submission,
+            crux_authority: None,
+            bounty_token_vault: None,
+            profile_owner_token_account: None,
+            crux_treasury_token_account: None,
+            crux_token_ledger: None,
+            profile_owner_fee_token_account: None,
+            token_program: None,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::ResolveAppeal {
+            _bump_treasury: test_crux.bump_treasury,
+            _bump_user_profile: test_submission.bump_user_profile,
+            _bump_challenge: test_challenge.bump_challenge,
+            _bump_submission: test_submission.bump_submission,
+            _bump_bounty_vault: test_challenge.bump_bounty_vault,
+            decision: SubmissionDecision { submission_state: SubmissionState::Completed, score_bps: MAX_SCORE_BPS },
+        }
+        .data(),
+    }
+}
+
+#[tokio::test]
+async fn test_appeal_resolved_by_independent_resolver_with_fee_refund() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+    let crux_manager = ctx.payer.pubkey();
+    let appeal_fee = 1_000_000;
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::UpdateCruxParams { crux, crux_manager, system_program: system_program::id() }.to_account_metas(None),
+        data: challenger::instruction::UpdateCruxParams {
+            new_crux_fees: CruxFees { profile_fee: 0, submission_fee: 0 },
+            new_crux_constants: CruxConstants { max_tags_length: 3, max_title_length: 256, max_url_length: 256 },
+            new_crux_policies: CruxPolicies::default(),
+            new_appeal_fee: appeal_fee,
+            new_auto_accept_after_evaluation_deadline: false,
+        }
+        .data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let author = create_moderator(&mut ctx, &test_crux).await;
+    let rejecter_wallet = fund_wallet(&mut ctx).await;
+    let rejecter = create_moderator_with_wallet(&mut ctx, &test_crux, rejecter_wallet).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &author, 10, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let user = test_submission.user.pubkey();
+
+    let ix = evaluate_submission_ix(&test_crux, &rejecter, &test_challenge, &test_submission, 0, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[&rejecter.wallet]).await.unwrap();
+
+    // The user appeals, paying the appeal fee into the treasury
+    let ix = appeal_submission_ix(&test_crux, &test_challenge, &test_submission);
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Appealed);
+    assert_eq!(submission.appeal_fee_paid, appeal_fee);
+
+    // The moderator who rejected the submission cannot resolve its appeal
+    let ix = resolve_appeal_ix(&test_crux, &test_challenge, &test_submission, &rejecter.moderator, Some(rejecter.moderator_profile));
+    assert_error_code(process(&mut ctx, &[ix], &[&rejecter.wallet]).await, ErrorCode::InvalidAppealResolver);
+
+    // The fee cannot be refunded once it has been paid out of the treasury, as that would leave the treasury below rent exemption
+    let minimum_balance_for_rent_exemption = ctx.banks_client.get_rent().await.unwrap().minimum_balance(16);
+    let ix = system_instruction::transfer(&crux_manager, &test_crux.crux_treasury, minimum_balance_for_rent_exemption);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    let ix = payout_from_treasury_ix(&test_crux, &crux_manager, appeal_fee);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    let ix = resolve_appeal_ix(&test_crux, &test_challenge, &test_submission, &crux_manager, None);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InsufficientTreasuryBalance);
+
+    let ix = system_instruction::transfer(&crux_manager, &test_crux.crux_treasury, appeal_fee);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // The crux manager upholds the appeal and the fee is refunded
+    let user_lamports_before = ctx.banks_client.get_balance(user).await.unwrap();
+    let treasury_lamports_before = ctx.banks_client.get_balance(test_crux.crux_treasury).await.unwrap();
+    let ix = resolve_appeal_ix(&test_crux, &test_challenge, &test_submission, &crux_manager, None);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+    assert_eq!(ctx.banks_client.get_balance(user).await.unwrap(), user_lamports_before + appeal_fee);
+    assert_eq!(ctx.banks_client.get_balance(test_crux.crux_treasury).await.unwrap(), treasury_lamports_before - appeal_fee);
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+    assert_eq!(submission.appeal_fee_paid, 0);
+
+    let user_profile: UserProfile = fetch_account(&mut ctx, &test_submission.user_profile).await;
+    assert_eq!(user_profile.reputation_score, 10);
+}
+
+#[tokio::test]
+async fn test_crux_manager_cannot_resolve_appeals_on_own_or_co_authored_submissions() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux_manager = ctx.payer.pubkey();
+
+    let manager = create_moderator(&mut ctx, &test_crux).await;
+    let rejecter_wallet = fund_wallet(&mut ctx).await;
+    let rejecter = create_moderator_with_wallet(&mut ctx, &test_crux, rejecter_wallet).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &manager, 10, 0, 0, 1).await;
+
+    // The crux manager submits on their own behalf
+    let own_submission = submit_as(&mut ctx, &test_crux, &test_challenge, Keypair::from_bytes(&manager.wallet.to_bytes()).unwrap()).await;
+
+    // Another user submits with the crux manager as a co-author
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+    let co_authored_submission = submit_with_config(&mut ctx, &test_crux, &test_challenge, user, SubmissionConfig {
+        co_author_profiles: vec![manager.moderator_profile],
+        weights: vec![1, 1],
+        ..SubmissionConfig::default()
+    })
+    .await
+    .unwrap();
+    let ix = accept_co_authorship_ix(&test_crux, &test_challenge, &co_authored_submission.submission, &crux_manager, manager.bump_moderator_profile);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    for test_submission in [&own_submission, &co_authored_submission] {
+        let mut ix = evaluate_submission_ix(&test_crux, &rejecter, &test_challenge, test_submission, 0, SubmissionState::Rejected, 0);
+        ix.accounts.extend(
+            fetch_account::<Submission>(&mut ctx, &test_submission.submission).await.co_authors().iter()
+                .map(|co_author| AccountMeta::new(co_author.user_profile, false)),
+        );
+        process(&mut ctx, &[ix], &[&rejecter.wallet]).await.unwrap();
+
+        let ix = appeal_submission_ix(&test_crux, &test_challenge, test_submission);
+        process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+        // Resolving as the crux manager does not bypass the conflict check
+        let ix = resolve_appeal_ix(&test_crux, &test_challenge, test_submission, &crux_manager, None);
+        assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::SelfEvaluationForbidden);
+
+        let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+        assert_eq!(submission.submission_state, SubmissionState::Appealed);
+    }
+}
+
+#[tokio::test]
+async fn test_review_closed_once_submission_is_deleted() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let review = evaluate_submission_accounts(&test_crux, &test_moderator, &test_challenge, &test_submission, 0).review;
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let close_review_ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::CloseReview {
+            crux,
+            moderator: test_moderator.moderator,
+            moderator_profile: test_moderator.moderator_profile,
+            submission: test_submission.submission,
+            review,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::CloseReview { _bump_moderator_profile: test_moderator.bump_moderator_profile }.data(),
+    };
+
+    // Reviews are kept for as long as their submission exists
+    assert_error_code(process(&mut ctx, std::slice::from_ref(&close_review_ix), &[]).await, ErrorCode::SubmissionNotClosed);
+
+    let ix = delete_submission_ix(&test_crux, &test_challenge, &test_submission, None);
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+
+    // The submission is created again at the same address, and the same moderator can review it afresh
+    advance_clock(&mut ctx, 1).await;
+    let user = Keypair::from_bytes(&test_submission.user.to_bytes()).unwrap();
+    let recreated_submission = submit_as(&mut ctx, &test_crux, &test_challenge, user).await;
+    assert_eq!(recreated_submission.submission, test_submission.submission);
+
+    let recreated_review = review_address(&recreated_submission, &test_moderator.moderator_profile, 0);
+    assert_ne!(recreated_review, review);
+
+    let ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &recreated_submission, 0, SubmissionState::Rejected, 0);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // The review of the deleted submission no longer refers to a live submission, so its rent is returned to the moderator who cast it
+    let review_rent = ctx.banks_client.get_balance(review).await.unwrap();
+    let moderator_lamports_before = ctx.banks_client.get_balance(test_moderator.moderator).await.unwrap();
+    let transaction_fee = ctx.genesis_config().fee_rate_governor.lamports_per_signature;
+    process(&mut ctx, std::slice::from_ref(&close_review_ix), &[]).await.unwrap();
+
+    assert_eq!(ctx.banks_client.get_balance(test_moderator.moderator).await.unwrap(), moderator_lamports_before + review_rent - transaction_fee);
+    assert!(ctx.banks_client.get_account(review).await.unwrap().is_none());
+
+    // The review of the live submission is kept
+    let mut close_recreated_review_ix = close_review_ix;
+    close_recreated_review_ix.accounts[4].pubkey = recreated_review;
+    assert_error_code(process(&mut ctx, &[close_recreated_review_ix], &[]).await, ErrorCode::SubmissionNotClosed);
+}
+
+// --------------------------------------- pause flags
+
+fn update_crux_pause_flags_ix(test_crux: &TestCrux, crux_manager: &Pubkey, new_crux_pause_flags: CruxPauseFlags) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::UpdateCruxPauseFlags {
+            crux: test_crux.crux.pubkey(),
+            crux_manager: *crux_manager,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::UpdateCruxPauseFlags { new_crux_pause_flags }.data(),
+    }
+}
+
+#[tokio::test]
+async fn test_paused_profile_creation_is_rejected() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+
+    let crux_manager = ctx.payer.pubkey();
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags { profile_creation_paused: true, ..CruxPauseFlags::default() });
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    assert_error_code(process(&mut ctx, &[ix], &[&user]).await, ErrorCode::ProfileCreationPaused);
+
+    // Lifting the pause allows profiles to be created again
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags::default());
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_paused_challenge_creation_is_rejected() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+
+    let crux_manager = ctx.payer.pubkey();
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags { challenge_creation_paused: true, ..CruxPauseFlags::default() });
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let result = try_create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, ChallengeConfig::default()).await;
+    assert_error_code(result, ErrorCode::ChallengeCreationPaused);
+
+    // Lifting the pause allows challenges to be created again
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags::default());
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    create_challenge_with_config(&mut ctx, &test_crux, &test_moderator, ChallengeConfig::default()).await;
+}
+
+#[tokio::test]
+async fn test_paused_submissions_are_rejected() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+
+    let user = fund_wallet(&mut ctx).await;
+    let ix = create_user_profile_ix(&test_crux, &user.pubkey(), None);
+    process(&mut ctx, &[ix], &[&user]).await.unwrap();
+
+    let crux_manager = ctx.payer.pubkey();
+    let pause_submissions_ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags { submissions_paused: true, ..CruxPauseFlags::default() });
+    let unpause_ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags::default());
+    process(&mut ctx, std::slice::from_ref(&pause_submissions_ix), &[]).await.unwrap();
+
+    let result = submit_entry_as(&mut ctx, &test_crux, &test_challenge, user.insecure_clone(), None).await;
+    assert_error_code(result, ErrorCode::SubmissionsPaused);
+
+    process(&mut ctx, std::slice::from_ref(&unpause_ix), &[]).await.unwrap();
+    let test_submission = submit_as(&mut ctx, &test_crux, &test_challenge, user).await;
+
+    // Editing a submission is blocked by the same pause
+    process(&mut ctx, &[pause_submissions_ix], &[]).await.unwrap();
+
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), "https://example.com/edited");
+    assert_error_code(process(&mut ctx, &[ix], &[&test_submission.user]).await, ErrorCode::SubmissionsPaused);
+
+    process(&mut ctx, &[unpause_ix], &[]).await.unwrap();
+    let ix = edit_submission_ix(&test_crux, &test_challenge, &test_submission, Pubkey::new_unique(), "https://example.com/edited");
+    process(&mut ctx, &[ix], &[&test_submission.user]).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_paused_evaluation_is_rejected() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+
+    let crux_manager = ctx.payer.pubkey();
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags { evaluation_paused: true, ..CruxPauseFlags::default() });
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let evaluate_ix = evaluate_submission_ix(&test_crux, &test_moderator, &test_challenge, &test_submission, 0, SubmissionState::Completed, MAX_SCORE_BPS);
+    assert_error_code(process(&mut ctx, std::slice::from_ref(&evaluate_ix), &[]).await, ErrorCode::EvaluationPaused);
+
+    // Lifting the pause allows the submission to be evaluated
+    let ix = update_crux_pause_flags_ix(&test_crux, &crux_manager, CruxPauseFlags::default());
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    process(&mut ctx, &[evaluate_ix], &[]).await.unwrap();
+
+    let submission: Submission = fetch_account(&mut ctx, &test_submission.submission).await;
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+}
+
+// --------------------------------------- account migration
+
+fn set_program_account(ctx: &mut ProgramTestContext, address: &Pubkey, data: Vec<u8>) {
+    let mut account = AccountSharedData::new(1_000_000_000, data.len(), &challenger::id());
+    account.set_data(data);
+    ctx.set_account(address, &account);
+}
+
+fn migrate_challenge_ix(crux: &Pubkey, challenge: &Pubkey, crux_manager: &Pubkey) -> Instruction {
+    let (bounty_vault, _) = find_pda(&[b"bounty_vault".as_ref(), challenge.as_ref()]);
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::MigrateChallenge {
+            crux: *crux,
+            challenge: *challenge,
+            bounty_vault,
+            crux_manager: *crux_manager,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::MigrateChallenge {}.data(),
+    }
+}
+
+fn migrate_submission_ix(submission: &Pubkey, challenge: &Pubkey, payer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::MigrateSubmission {
+            submission: *submission,
+            challenge: *challenge,
+            payer: *payer,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::MigrateSubmission {}.data(),
+    }
+}
+
+#[tokio::test]
+async fn test_migrate_legacy_crux_and_challenge() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+
+    // Rewrite the crux with its version 0 layout, which ended after the crux counts
+    let account = ctx.banks_client.get_account(crux).await.unwrap().unwrap();
+    let mut legacy_crux_data = account.data[..8 + 171].to_vec();
+    legacy_crux_data[8..10].copy_from_slice(&0u16.to_le_bytes());
+    legacy_crux_data.resize(8 + 176, 0);
+    set_program_account(&mut ctx, &crux, legacy_crux_data);
+
+    let migrate_crux_ix = |signer: &Pubkey| Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::MigrateCrux {
+            crux,
+            crux_manager: *signer,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::MigrateCrux {}.data(),
+    };
+
+    // Only the crux manager can migrate the crux
+    let impostor = fund_wallet(&mut ctx).await;
+    assert_error_code(process(&mut ctx, &[migrate_crux_ix(&impostor.pubkey())], &[&impostor]).await, ErrorCode::NotCruxManager);
+
+    let crux_manager = ctx.payer.pubkey();
+    process(&mut ctx, &[migrate_crux_ix(&crux_manager)], &[]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(crux).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + std::mem::size_of::<Crux>());
+
+    let crux_account = fetch_crux(&mut ctx, &crux).await;
+    assert_eq!(crux_account.version, LATEST_CRUX_VERSION);
+    assert_eq!(crux_account.crux_manager, crux_manager);
+    assert_eq!(crux_account.crux_constants.max_tags_length, 3);
+    assert_eq!(crux_account.crux_constants.max_url_length, 256);
+
+    // Write a version 0 challenge, which ended after the reputation value and had no bounty vault
+    let challenge = Pubkey::new_unique();
+    let mut legacy_challenge_data = Challenge::DISCRIMINATOR.to_vec();
+    (crux, Pubkey::new_unique(), 1u64, 2u64, vec![Tags::Ideas], String::from("title"), String::from("url"), Pubkey::new_unique(), 10u64)
+        .serialize(&mut legacy_challenge_data)
+        .unwrap();
+    set_program_account(&mut ctx, &challenge, legacy_challenge_data);
+
+    let (bounty_vault, _) = find_pda(&[b"bounty_vault".as_ref(), challenge.as_ref()]);
+
+    // Only the crux manager can migrate the challenge, becoming the funder of its new bounty vault
+    let ix = migrate_challenge_ix(&crux, &challenge, &impostor.pubkey());
+    assert_error_code(process(&mut ctx, &[ix], &[&impostor]).await, anchor_lang::error::ErrorCode::ConstraintHasOne);
+
+    process(&mut ctx, &[migrate_challenge_ix(&crux, &challenge, &crux_manager)], &[]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(challenge).await.unwrap().unwrap();
+    let challenge_account = Challenge::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(challenge_account.version, LATEST_CHALLENGE_VERSION);
+    assert_eq!(challenge_account.title, "title");
+    assert_eq!(challenge_account.reputation, 10);
+    assert_eq!(challenge_account.bounty_vault, bounty_vault);
+    assert_eq!(challenge_account.bounty_funder, crux_manager);
+    assert!(ctx.banks_client.get_account(bounty_vault).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_migrate_edited_and_shortened_legacy_challenge() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let crux = test_crux.crux.pubkey();
+    let payer = ctx.payer.pubkey();
+
+    // A version 0 challenge whose url was shortened by an edit, which only ever grew the account,
+    // leaving the end of the previous content data hash and reputation after the challenge's own layout
+    let legacy_challenge = |content_data_url: String, content_data_hash: Pubkey| {
+        let mut data = Challenge::DISCRIMINATOR.to_vec();
+        (crux, Pubkey::new_unique(), 1u64, 2u64, vec![Tags::Ideas], String::from("title"), content_data_url, content_data_hash, 10u64)
+            .serialize(&mut data)
+            .unwrap();
+        data
+    };
+
+    // The stale bytes begin with what reads as a plausible version
+    let mut stale_content_data_hash = Pubkey::new_unique().to_bytes();
+    stale_content_data_hash[..2].copy_from_slice(&3u16.to_le_bytes());
+
+    let original_data = legacy_challenge(format!("https://example.com/{}", "a".repeat(40)), Pubkey::new_from_array(stale_content_data_hash));
+    let mut edited_data = legacy_challenge(String::from("https://example.com/"), Pubkey::new_unique());
+    let edited_length = edited_data.len();
+    edited_data.extend_from_slice(&original_data[edited_length..]);
+    assert_eq!(edited_data.len(), original_data.len());
+
+    let challenge = Pubkey::new_unique();
+    set_program_account(&mut ctx, &challenge, edited_data);
+
+    process(&mut ctx, &[migrate_challenge_ix(&crux, &challenge, &payer)], &[]).await.unwrap();
+
+    // The stale bytes are dropped, so the challenge is upgraded from version 0 with its later fields defaulted
+    let account = ctx.banks_client.get_account(challenge).await.unwrap().unwrap();
+    let challenge_account = Challenge::try_deserialize(&mut account.data.as_slice()).unwrap();
+    let (bounty_vault, _) = find_pda(&[b"bounty_vault".as_ref(), challenge.as_ref()]);
+    assert_eq!(challenge_account.version, LATEST_CHALLENGE_VERSION);
+    assert_eq!(challenge_account.content_data_url, "https://example.com/");
+    assert_eq!(challenge_account.reputation, 10);
+    assert_eq!(challenge_account.bounty_vault, bounty_vault);
+    assert_eq!(challenge_account.bounty_lamports, 0);
+    assert_eq!(challenge_account.required_approvals, 1);
+    assert_eq!(challenge_account.challenge_author, Pubkey::default());
+    assert_eq!(challenge_account.challenge_status, ChallengeStatus::Open);
+    assert_eq!(account.data.len(), edited_length + CHALLENGE_LAYOUT_TAIL_LENGTHS[LATEST_CHALLENGE_VERSION as usize]);
+
+    // Migrating again leaves the challenge as it is
+    process(&mut ctx, &[migrate_challenge_ix(&crux, &challenge, &payer)], &[]).await.unwrap();
+    let migrated_again = ctx.banks_client.get_account(challenge).await.unwrap().unwrap();
+    assert_eq!(migrated_again.data, account.data);
+}
+
+#[tokio::test]
+async fn test_migrate_legacy_submissions() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let test_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+    let test_submission = create_submission(&mut ctx, &test_crux, &test_challenge).await;
+    let payer = ctx.payer.pubkey();
+
+    let account = ctx.banks_client.get_account(test_submission.submission).await.unwrap().unwrap();
+    let mut current = Submission::try_deserialize(&mut account.data.as_slice()).unwrap();
+    let content_data_url = current.content_data_url.clone();
+
+    let mut serialized: Vec<u8> = Vec::new();
+    current.try_serialize(&mut serialized).unwrap();
+    assert_eq!(serialized.len(), Submission::layout_length(LATEST_SUBMISSION_VERSION, content_data_url.len()));
+
+    // A completed version 0 submission, which ended after its state and was padded with zeroes
+    current.submission_state = SubmissionState::Completed;
+    let mut completed: Vec<u8> = Vec::new();
+    current.try_serialize(&mut completed).unwrap();
+
+    let mut legacy_submission_data = completed[..LEGACY_SUBMISSION_LENGTH].to_vec();
+    legacy_submission_data.resize(8 + 120, 0);
+    let legacy_submission = Pubkey::new_unique();
+    set_program_account(&mut ctx, &legacy_submission, legacy_submission_data);
+
+    let ix = migrate_submission_ix(&legacy_submission, &test_challenge.challenge, &payer);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    // Completed submissions are backfilled with the rewards they already received
+    let account = ctx.banks_client.get_account(legacy_submission).await.unwrap().unwrap();
+    let submission = Submission::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(submission.version, LATEST_SUBMISSION_VERSION);
+    assert_eq!(submission.submission_state, SubmissionState::Completed);
+    assert_eq!(submission.awarded_reputation, 100);
+    assert!(submission.bounty_paid);
+    assert_eq!(submission.score_bps, MAX_SCORE_BPS);
+    assert_eq!(submission.content_data_url, "");
+    assert_eq!(account.data.len(), 8 + std::mem::size_of::<Submission>());
+
+    // A version 7 submission whose url was shortened by an edit, leaving stale bytes which don't decode as later fields
+    let mut edited_submission_data = serialized[..Submission::layout_length(7, content_data_url.len())].to_vec();
+    edited_submission_data[LEGACY_SUBMISSION_LENGTH..LEGACY_SUBMISSION_LENGTH + 2].copy_from_slice(&7u16.to_le_bytes());
+    edited_submission_data.extend_from_slice(&[0xFF; 64]);
+    let edited_submission = Pubkey::new_unique();
+    set_program_account(&mut ctx, &edited_submission, edited_submission_data);
+
+    // Submissions of another challenge are refused
+    let other_challenge = create_challenge(&mut ctx, &test_crux, &test_moderator, 100, 0, 0, 1).await;
+    let ix = migrate_submission_ix(&edited_submission, &other_challenge.challenge, &payer);
+    assert_error_code(process(&mut ctx, &[ix], &[]).await, ErrorCode::InvalidParameter);
+
+    let ix = migrate_submission_ix(&edited_submission, &test_challenge.challenge, &payer);
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(edited_submission).await.unwrap().unwrap();
+    let submission = Submission::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(submission.version, LATEST_SUBMISSION_VERSION);
+    assert_eq!(submission.content_data_url, content_data_url);
+    assert_eq!(submission.submission_index, 0);
+    assert_eq!(submission.co_author_count, 0);
+    assert!(!submission.awaiting_reveal);
+    assert_eq!(submission.answer_attempts, 0);
+    assert_eq!(account.data.len(), 8 + std::mem::size_of::<Submission>() + content_data_url.len());
+    assert!(account.data[serialized.len()..].iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn test_migrate_legacy_user_profile() {
+    let mut ctx = setup().await;
+    let test_crux = init_crux(&mut ctx, CruxFees { profile_fee: 0, submission_fee: 0 }).await;
+    let test_moderator = create_moderator(&mut ctx, &test_crux).await;
+    let payer = ctx.payer.pubkey();
+
+    // Rewrite the profile with its version 0 layout, which ended after the moderator flag
+    let account = ctx.banks_client.get_account(test_moderator.moderator_profile).await.unwrap().unwrap();
+    let mut legacy_user_profile_data = account.data[..8 + 137].to_vec();
+    legacy_user_profile_data.resize(8 + std::mem::size_of::<UserProfile>(), 0);
+    set_program_account(&mut ctx, &test_moderator.moderator_profile, legacy_user_profile_data);
+
+    let legacy_user_profile: UserProfile = fetch_account(&mut ctx, &test_moderator.moderator_profile).await;
+    assert_eq!(legacy_user_profile.version, 0);
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::MigrateUserProfile {
+            user_profile: test_moderator.moderator_profile,
+            payer,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::MigrateUserProfile {}.data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(test_moderator.moderator_profile).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + std::mem::size_of::<UserProfile>());
+
+    let user_profile = UserProfile::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(user_profile.version, LATEST_USER_PROFILE_VERSION);
+    assert_eq!(user_profile.crux, test_crux.crux.pubkey());
+    assert_eq!(user_profile.profile_owner, test_moderator.moderator);
+    assert!(user_profile.is_moderator);
+
+    // Rewrite the profile with its version 1 layout, which ended after the version and had no streak
+    let mut legacy_user_profile_data = account.data[..8 + 139].to_vec();
+    legacy_user_profile_data[8 + 137..].copy_from_slice(&1u16.to_le_bytes());
+    legacy_user_profile_data.resize(8 + 144, 0);
+    set_program_account(&mut ctx, &test_moderator.moderator_profile, legacy_user_profile_data);
+
+    let ix = Instruction {
+        program_id: challenger::id(),
+        accounts: challenger::accounts::MigrateUserProfile {
+            user_profile: test_moderator.moderator_profile,
+            payer,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: challenger::instruction::MigrateUserProfile {}.data(),
+    };
+    process(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(test_moderator.moderator_profile).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + std::mem::size_of::<UserProfile>());
+
+    let user_profile = UserProfile::try_deserialize(&mut account.data.as_slice()).unwrap();
+    assert_eq!(user_profile.version, LATEST_USER_PROFILE_VERSION);
+    assert!(user_profile.is_moderator);
+    assert_eq!(user_profile.streak_challenge, Pubkey::default());
+    assert_eq!(user_profile.streak, 0);
+}
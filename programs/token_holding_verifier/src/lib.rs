@@ -0,0 +1,36 @@
+// Anchor's error type is large, and every instruction returns it
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+declare_id!("3hC39RPxU3VvXbU1VKc5PY1yYHeSgw1RHb3TPzdVLyAp");
+
+// Sample verifier for challenges requiring the submitter to hold an SPL token. The challenger program calls `verify` with the
+// challenge's verifier params, which hold the required mint, and completes the submission if it returns true.
+#[program]
+pub mod token_holding_verifier {
+    use super::*;
+
+    pub fn verify(ctx: Context<Verify>, verifier_params: [u8; 32]) -> Result<bool> {
+        let required_mint = Pubkey::new_from_array(verifier_params);
+        let token_account = &ctx.accounts.token_account;
+
+        let holds_token = (token_account.owner == ctx.accounts.submitter.key())
+            && (token_account.mint == required_mint)
+            && (token_account.amount > 0);
+
+        msg!("Submitter {} holds mint {}: {}", ctx.accounts.submitter.key(), required_mint, holds_token);
+        Ok(holds_token)
+    }
+}
+
+#[derive(Accounts)]
+pub struct Verify<'info> {
+
+    /// CHECK: Wallet of the submitter being verified, passed first by the challenger program
+    pub submitter: AccountInfo<'info>,
+
+    // Token account which must belong to the submitter and hold the required mint
+    pub token_account: Account<'info, TokenAccount>,
+}
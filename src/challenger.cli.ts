@@ -7,7 +7,7 @@ import { IDL as ChallengerIDL } from './challenger.types';
 import { CHALLENGER_PROG_ID } from './index';
 import { stringifyPKsAndBNs } from './render-types';
 import { findCruxAuthorityPDA } from './challenger.pda';
-import { CruxFees, SubmissionState } from './challenger.client';
+import { ChallengeEdit, ChallengeSettings, ChallengeStatus, CruxFees, Evaluation, SubmissionState } from './challenger.client';
 import { hash } from 'blake3';
 
 import { networkConfig } from "./cli_configs/devnet/networkConfig";
@@ -18,9 +18,12 @@ import { submissionConfig } from "./cli_configs/devnet/submissionConfig";
 // ----------------------------------------------- Legend ---------------------------------------------------------
 
 // -a submission accepted (accepted)
+// -b submission score in basis points (bps)
 // -c challenge account address (comment)
 // -k pubkey of account being fetched (key)
+// -l amount of lamports (lamports)
 // -m crux manager account address (manager)
+// -n new challenge status (new)
 // -o user profile owner address (owner)
 // -p user profile account address (profile)
 // -r receiver account address (receiver)
@@ -69,6 +72,10 @@ const parser = yargs(process.argv.slice(2)).options({
                          crux,
                          wallet.payer,
                          cruxFees,
+                         cruxConfig.cruxConstants,
+                         cruxConfig.cruxPolicies,
+                         cruxConfig.appealFee,
+                         cruxConfig.autoAcceptAfterEvaluationDeadline,
                      );
                      console.log(stringifyPKsAndBNs(cruxInstance));
                  } else {
@@ -106,6 +113,10 @@ const parser = yargs(process.argv.slice(2)).options({
                          cruxKey,
                          wallet.payer,
                          cruxFees,
+                         cruxConfig.cruxConstants,
+                         cruxConfig.cruxPolicies,
+                         cruxConfig.appealFee,
+                         cruxConfig.autoAcceptAfterEvaluationDeadline,
                      );
                      console.log(stringifyPKsAndBNs(updateCruxParamsInstance));
                  } else {
@@ -116,6 +127,7 @@ const parser = yargs(process.argv.slice(2)).options({
 
 
 // Payout from treasury
+// Pays out the whole treasury balance above rent exemption unless an amount of lamports is passed in
     .command('payout-from-treasury', 'Payout from treasury', {
         cruxPubkey: {
             alias: 'x',
@@ -128,6 +140,12 @@ const parser = yargs(process.argv.slice(2)).options({
             type: 'string',
             demandOption: false,
             description: 'receiver account pubkey for reclaimed rent lamports'
+        },
+        lamports: {
+            alias: 'l',
+            type: 'number',
+            demandOption: false,
+            description: 'amount of lamports to pay out'
         }
     },
              async (argv) => {
@@ -149,11 +167,15 @@ const parser = yargs(process.argv.slice(2)).options({
                  console.log('Minimum balance for rent exemption for a data size of', rentBytes,
                              'bytes is: ', stringifyPKsAndBNs(minimumBalanceForRentExemption));
 
+                 const treasuryBalance: anchor.BN = new anchor.BN(await cruxClient.fetchTreasuryBalance(cruxKey));
+                 const amount: anchor.BN = argv.lamports ? new anchor.BN(argv.lamports) : treasuryBalance.sub(minimumBalanceForRentExemption);
+
                  if (!argv.dryRun) {
                      const payoutInstance = await cruxClient.payoutFromTreasury(
                          cruxKey,
                          wallet.payer,
                          receiverKey,
+                         amount,
                      );
                      console.log(stringifyPKsAndBNs(payoutInstance));
                  } else {
@@ -426,16 +448,20 @@ const parser = yargs(process.argv.slice(2)).options({
 
                  console.log('The content data hash pubkey is: ', stringifyPKsAndBNs(contentDataHash));
 
+                 const challengeSettings: ChallengeSettings = defaultChallengeSettings({
+                     tags,
+                     title,
+                     contentDataUrl,
+                     challengeExpiresTs,
+                     reputation,
+                 });
+
                  if (!argv.dryRun) {
                      const challengeInstance = await challengerClient.createChallenge(
                          cruxKey,
                          wallet.payer,
                          contentDataHash,
-                         title,
-                         contentDataUrl,
-                         tags,
-                         challengeExpiresTs,
-                         reputation,
+                         challengeSettings,
                      );
                      console.log(stringifyPKsAndBNs(challengeInstance));
                  } else {
@@ -477,16 +503,20 @@ const parser = yargs(process.argv.slice(2)).options({
                  const hashResult = hash(newContentString);
                  const newContentDataHash: PublicKey = new PublicKey(hashResult);
 
+                 const challengeEdit: ChallengeEdit = {
+                     tags: newTags,
+                     title: newTitle,
+                     contentDataUrl: newContentDataUrl,
+                     challengeExpiresTs: newChallengeExpiresTs,
+                     reputation: newReputation,
+                 };
+
                  if (!argv.dryRun) {
                      const editChallengeInstance = await challengerClient.editChallenge(
                          challengeKey,
                          wallet.payer,
                          newContentDataHash,
-                         newTitle,
-                         newContentDataUrl,
-                         newTags,
-                         newChallengeExpiresTs,
-                         newReputation,
+                         challengeEdit,
                      );
                      console.log(stringifyPKsAndBNs(editChallengeInstance));
                  } else {
@@ -496,6 +526,50 @@ const parser = yargs(process.argv.slice(2)).options({
 
 
 
+// Update challenge status
+// Archiving refunds any unclaimed bounty to its funder
+    .command('update-challenge-status', 'Update challenge status', {
+        challengePubkey: {
+            alias: 'c',
+            type: 'string',
+            demandOption: true,
+            description: 'challenge account pubkey'
+        },
+        newStatus: {
+            alias: 'n',
+            type: 'string',
+            choices: ['open', 'draft', 'closed', 'archived'],
+            demandOption: true,
+            description: 'new challenge status'
+        }
+    },
+             async (argv) => {
+                 const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
+                 const wallet: anchor.Wallet = new anchor.Wallet(await loadWallet(networkConfig.signerKeypair));
+                 const challengerClient: ChallengerClient = new ChallengerClient(
+                     rpcConn,
+                     wallet,
+                     ChallengerIDL,
+                     CHALLENGER_PROG_ID,
+                 );
+
+                 const challengeKey = new PublicKey(argv.challengePubkey);
+                 const newChallengeStatus = {[argv.newStatus]: {}} as ChallengeStatus;
+
+                 if (!argv.dryRun) {
+                     const updateChallengeStatusInstance = await challengerClient.updateChallengeStatus(
+                         challengeKey,
+                         wallet.payer,
+                         newChallengeStatus,
+                     );
+                     console.log(stringifyPKsAndBNs(updateChallengeStatusInstance));
+                 } else {
+                     console.log('Moving challenge with account address', challengeKey.toBase58(), 'to status', argv.newStatus);
+                 }
+             })
+
+
+
 // Delete challenge
     .command('delete-challenge', 'Delete challenge', {
         challengePubkey: {
@@ -571,15 +645,19 @@ const parser = yargs(process.argv.slice(2)).options({
 
                          console.log('The content data hash pubkey is: ', stringifyPKsAndBNs(contentDataHash));
 
-                         const challengeInstance = await challengerClient.createChallenge(
-                             cruxKey,
-                             wallet.payer,
-                             contentDataHash,
+                         const challengeSettings: ChallengeSettings = defaultChallengeSettings({
+                             tags,
                              title,
                              contentDataUrl,
-                             tags,
                              challengeExpiresTs,
                              reputation,
+                         });
+
+                         const challengeInstance = await challengerClient.createChallenge(
+                             cruxKey,
+                             wallet.payer,
+                             contentDataHash,
+                             challengeSettings,
                          );
                          console.log(stringifyPKsAndBNs(challengeInstance));
                      }
@@ -606,6 +684,7 @@ const parser = yargs(process.argv.slice(2)).options({
 
                  const challengeKey: PublicKey = submissionConfig.challenge;
                  const contentString: string = submissionConfig.content;
+                 const contentDataUrl: string = submissionConfig.contentDataUrl;
 
                  const hashResult = hash(contentString);
                  const contentDataHash: PublicKey = new PublicKey(hashResult);
@@ -617,6 +696,7 @@ const parser = yargs(process.argv.slice(2)).options({
                          challengeKey,
                          wallet.payer,
                          contentDataHash,
+                         contentDataUrl,
                      );
                      console.log(stringifyPKsAndBNs(submissionInstance));
                  } else {
@@ -649,6 +729,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  const submissionKey: PublicKey = new PublicKey(argv.submissionPubkey);
 
                  const newContentString: string = submissionConfig.content;
+                 const newContentDataUrl: string = submissionConfig.contentDataUrl;
                  const hashResult = hash(newContentString);
                  const newContentDataHash: PublicKey = new PublicKey(hashResult);
 
@@ -657,6 +738,7 @@ const parser = yargs(process.argv.slice(2)).options({
                          submissionKey,
                          wallet.payer,
                          newContentDataHash,
+                         newContentDataUrl,
                      );
                      console.log(stringifyPKsAndBNs(editSubmissionInstance));
                  } else {
@@ -763,6 +845,13 @@ const parser = yargs(process.argv.slice(2)).options({
             type: 'boolean',
             demandOption: true,
             description: 'submission state flag: true = submissionState::Completed, false = submissionState::Rejected'
+        },
+        scoreBps: {
+            alias: 'b',
+            type: 'number',
+            demandOption: false,
+            default: 10_000,
+            description: 'score of a completed submission in basis points'
         }
     },
              async (argv) => {
@@ -778,11 +867,21 @@ const parser = yargs(process.argv.slice(2)).options({
                  const submissionKey = new PublicKey(argv.submissionPubkey);
                  const submissionState: SubmissionState = argv.submissionCompleted ? {completed: {} as never} : {rejected: {} as never};
 
+                 // Only completed submissions are scored, and evaluations are posted without feedback
+                 const evaluation: Evaluation = {
+                     decision: {
+                         submissionState,
+                         scoreBps: new anchor.BN(argv.submissionCompleted ? argv.scoreBps : 0),
+                     },
+                     feedbackContentDataUrl: '',
+                 };
+
                  if (!argv.dryRun) {
                      const acceptSubmissionInstance = await challengerClient.evaluateSubmission(
                          submissionKey,
                          wallet.payer,
-                         submissionState,
+                         PublicKey.default,
+                         evaluation,
                      );
                      console.log(stringifyPKsAndBNs(acceptSubmissionInstance));
                  } else {
@@ -1358,6 +1457,34 @@ async function loadWallet(fileName: string): Promise<Keypair> {
 
 
 
+// Open challenge evaluated by a single moderator, without a bounty, answer, verifier, schedule, prerequisites or submission limits
+function defaultChallengeSettings(challengeEdit: ChallengeEdit): ChallengeSettings {
+    return {
+        ...challengeEdit,
+        bountyLamports: new anchor.BN(0),
+        bountyPerCompletion: new anchor.BN(0),
+        requiredApprovals: new anchor.BN(1),
+        maxRevisions: new anchor.BN(0),
+        maxSubmissionsPerUser: new anchor.BN(0),
+        revealDuration: new anchor.BN(0),
+        answerHash: PublicKey.default,
+        maxAnswerAttempts: new anchor.BN(0),
+        answerCooldown: new anchor.BN(0),
+        verifierProgram: PublicKey.default,
+        verifierParams: new Array(32).fill(0),
+        challengeOpensTs: new anchor.BN(0),
+        evaluationDeadlineTs: new anchor.BN(0),
+        maxCompletions: new anchor.BN(0),
+        reputationSchedule: [new anchor.BN(0), new anchor.BN(0), new anchor.BN(0)],
+        prerequisites: [],
+        minReputationScore: new anchor.BN(0),
+        periodLength: new anchor.BN(0),
+        isDraft: false,
+    };
+}
+
+
+
 // Let's go!
 (async() => {
     await parser.argv;
@@ -1,13 +1,22 @@
 import * as anchor from '@coral-xyz/anchor';
 import { AnchorProvider, BN, Idl, IdlTypes, Program } from '@coral-xyz/anchor';
-import { Connection, Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { AccountMeta, Connection, Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { createHash } from 'crypto';
 import { Challenger } from './challenger.types';
 import {
     findCruxAuthorityPDA,
     findCruxTreasuryPDA,
+    findCruxTokenTreasuryPDA,
+    findTokenLedgerPDA,
     findUserProfilePDA,
     findChallengePDA,
-    findSubmissionPDA
+    findBountyVaultPDA,
+    findBountyTokenVaultPDA,
+    findSubmissionPDA,
+    findSubmissionEntriesPDA,
+    findAnswerAttemptsPDA,
+    findReviewPDA
 } from './challenger.pda';
 
 // Enum: Tags
@@ -31,12 +40,24 @@ export const submissionState = {
     Completed: {completed: {}},
     Rejected: {rejected: {}},
     Pending: {pending: {}},
+    Appealed: {appealed: {}},
+    NeedsRevision: {needsRevision: {}},
+}
+
+// Enum: ChallengeStatus
+export const challengeStatus = {
+    Open: {open: {}},
+    Draft: {draft: {}},
+    Closed: {closed: {}},
+    Archived: {archived: {}},
 }
 
 export type Tags = IdlTypes<Challenger>['Tags'];
 
 export type SubmissionState = IdlTypes<Challenger>['SubmissionState'];
 
+export type ChallengeStatus = IdlTypes<Challenger>['ChallengeStatus'];
+
 export interface CruxCounts {
     profileCount: BN;
     challengeCount: BN;
@@ -48,10 +69,118 @@ export interface CruxFees {
     submissionFee: BN;
 }
 
+export interface CruxConstants {
+    maxTagsLength: BN;
+    maxTitleLength: BN;
+    maxUrlLength: BN;
+}
+
+export interface CruxPauseFlags {
+    profileCreationPaused: boolean;
+    challengeCreationPaused: boolean;
+    submissionsPaused: boolean;
+    evaluationPaused: boolean;
+}
+
+export interface CruxPolicies {
+    forbidAuthorEvaluation: boolean;
+}
+
+export interface TreasuryPayout {
+    receiver: PublicKey;
+    amount: BN;
+}
+
+export interface ChallengeSettings {
+    tags: Tags[];
+    title: string;
+    contentDataUrl: string;
+    challengeExpiresTs: BN;
+    reputation: BN;
+    bountyLamports: BN;
+    bountyPerCompletion: BN;
+    requiredApprovals: BN;
+    maxRevisions: BN;
+    maxSubmissionsPerUser: BN;
+    revealDuration: BN;
+    answerHash: PublicKey;
+    maxAnswerAttempts: BN;
+    answerCooldown: BN;
+    verifierProgram: PublicKey;
+    verifierParams: number[];
+    challengeOpensTs: BN;
+    evaluationDeadlineTs: BN;
+    maxCompletions: BN;
+    reputationSchedule: BN[];
+    prerequisites: PublicKey[];
+    minReputationScore: BN;
+    periodLength: BN;
+    isDraft: boolean;
+}
+
+export interface ChallengeEdit {
+    tags: Tags[];
+    title: string;
+    contentDataUrl: string;
+    challengeExpiresTs: BN;
+    reputation: BN;
+}
+
+export interface SubmissionTeam {
+    coAuthorProfiles: PublicKey[];
+    weights: BN[];
+}
+
+export interface SubmissionDecision {
+    submissionState: SubmissionState;
+    scoreBps: BN;
+}
+
+export interface Evaluation {
+    decision: SubmissionDecision;
+    feedbackContentDataUrl: string;
+}
+
 function isKp(toCheck: PublicKey | Keypair) {
     return typeof (<Keypair>toCheck).publicKey !== 'undefined';
 }
 
+async function findAssociatedTokenAddress(mint: PublicKey, owner: PublicKey) {
+    return Token.getAssociatedTokenAddress(ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, mint, owner);
+}
+
+// Mirrors Challenge::submission_index_seed, challenges accepting a single submission per user seed it without an index
+function submissionIndexSeed(challengeAcct: any, submissionIndex: BN) {
+    if (challengeAcct.maxSubmissionsPerUser.isZero() && challengeAcct.periodLength.isZero()) {
+        return Buffer.alloc(0);
+    }
+    return submissionIndex.toArrayLike(Buffer, 'le', 8);
+}
+
+// Co-author profiles are passed in order as remaining accounts wherever a team submission's reputation is credited or reversed
+function coAuthorProfileMetas(submissionAcct: any): AccountMeta[] {
+    return submissionAcct.coAuthors.slice(0, submissionAcct.coAuthorCount).map((coAuthor: any) => {
+        return { pubkey: coAuthor.userProfile, isSigner: false, isWritable: true };
+    });
+}
+
+// The answer is salted with the challenge's key, so that equal answers to different challenges have different hashes
+export function computeAnswerHash(challenge: PublicKey, answer: string) {
+    return new PublicKey(createHash('sha256').update(challenge.toBuffer()).update(Buffer.from(answer)).digest());
+}
+
+// Commitment posted in place of the content data hash on commit-reveal challenges
+export function computeContentCommitment(userProfile: PublicKey, contentDataHash: PublicKey, contentDataUrl: string, salt: number[]) {
+    return new PublicKey(
+        createHash('sha256')
+            .update(userProfile.toBuffer())
+            .update(contentDataHash.toBuffer())
+            .update(Buffer.from(contentDataUrl))
+            .update(Buffer.from(salt))
+            .digest()
+    );
+}
+
 export class ChallengerClient {
     connection: Connection;
     wallet: anchor.Wallet;
@@ -112,11 +241,27 @@ export class ChallengerClient {
         return this.challengerProgram.account.submission.fetch(submission);
     }
 
+    async fetchReviewAccount(review: PublicKey) {
+        return this.challengerProgram.account.review.fetch(review);
+    }
+
+    async fetchAnswerAttemptsAccount(answerAttempts: PublicKey) {
+        return this.challengerProgram.account.answerAttempts.fetch(answerAttempts);
+    }
+
+    async fetchTokenLedgerAccount(tokenLedger: PublicKey) {
+        return this.challengerProgram.account.tokenLedger.fetch(tokenLedger);
+    }
+
     async fetchTreasuryBalance(crux: PublicKey) {
         const [treasury] = await findCruxTreasuryPDA(crux);
         return this.connection.getBalance(treasury);
     }
 
+    async fetchTokenTreasuryBalance(crux: PublicKey, mint: PublicKey) {
+        const [tokenTreasury] = await findCruxTokenTreasuryPDA(crux, mint);
+        return this.connection.getTokenAccountBalance(tokenTreasury);
+    }
     // -------------------------------------------------------- get all PDAs by type
 
     async fetchAllCruxPDAs(cruxManager?: PublicKey) {
@@ -235,12 +380,142 @@ export class ChallengerClient {
         return pdas;
     }
 
+
+    async fetchAllReviewPDAs(submission?: PublicKey) {
+        const filter = submission
+            ? [
+                {
+                    memcmp: {
+                        offset: 8, // need to prepend 8 bytes for anchor's disc
+                        bytes: submission.toBase58(),
+                    },
+                },
+            ]
+            : [];
+        const pdas = await this.challengerProgram.account.review.all(filter);
+        if (submission) {
+            console.log('Found a total of', pdas.length, 'review PDAs for submission account with address', submission.toBase58());
+        }
+        else {
+            console.log('Found a total of', pdas.length, 'review PDAs');
+        }
+        return pdas;
+    }
+
+    // -------------------------------------------------------- derive optional accounts
+
+    // Token accounts paying a fee when the crux charges its fees in an SPL token, null otherwise
+    async feeTokenAccounts(crux: PublicKey, payer: PublicKey) {
+        const cruxAcct = await this.fetchCruxAccount(crux);
+
+        if (cruxAcct.feeMint.equals(PublicKey.default)) {
+            return {
+                profileOwnerTokenAccount: null,
+                cruxTreasuryTokenAccount: null,
+                cruxTokenLedger: null,
+                tokenProgram: null,
+            };
+        }
+
+        const [cruxTokenLedger] = await findTokenLedgerPDA(crux, cruxAcct.feeMint);
+
+        return {
+            profileOwnerTokenAccount: await findAssociatedTokenAddress(cruxAcct.feeMint, payer),
+            cruxTreasuryTokenAccount: cruxAcct.cruxTreasuryTokenAccount,
+            cruxTokenLedger,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+    }
+
+    // Token accounts moving a challenge's token bounty to the receiver's associated token account, null if it holds no token bounty
+    async bountyTokenAccounts(challengeAcct: any, receiver: PublicKey) {
+        if (challengeAcct.bountyTokenMint.equals(PublicKey.default)) {
+            return {
+                cruxAuthority: null,
+                bountyTokenVault: null,
+                receiverTokenAccount: null,
+                tokenProgram: null,
+            };
+        }
+
+        const [cruxAuthority] = await findCruxAuthorityPDA(challengeAcct.crux);
+
+        return {
+            cruxAuthority,
+            bountyTokenVault: challengeAcct.bountyTokenVault,
+            receiverTokenAccount: await findAssociatedTokenAddress(challengeAcct.bountyTokenMint, receiver),
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+    }
+
+    // Later-posted completions which completing the submission moves down the challenge's reputation schedule, passed after the
+    // co-author profiles as each submission followed by its user profile and co-author profiles (mirrors Challenge::completion_rank)
+    async movedCompletionMetas(challengeAcct: any, submissionAcct: any) {
+        const scheduledRanks = challengeAcct.reputationSchedule.findIndex((scheduledReputation: BN) => scheduledReputation.isZero());
+        const rankedCompletions = challengeAcct.rankedCompletions.slice(0, scheduledRanks < 0 ? undefined : scheduledRanks);
+        const isHeld = (ranked: any) => !ranked.submission.equals(PublicKey.default);
+
+        let rank = 0;
+        rankedCompletions.forEach((ranked: any, index: number) => {
+            if (isHeld(ranked) && ranked.submissionPostedTs.lte(submissionAcct.submissionPostedTs)) {
+                rank = index + 1;
+            }
+        });
+
+        const movedCompletionMetas: AccountMeta[] = [];
+        for (const ranked of rankedCompletions.slice(rank)) {
+            if (!isHeld(ranked)) {
+                break;
+            }
+
+            const movedSubmissionAcct = await this.fetchSubmissionAccount(ranked.submission);
+            movedCompletionMetas.push(
+                { pubkey: ranked.submission, isSigner: false, isWritable: true },
+                { pubkey: movedSubmissionAcct.userProfile, isSigner: false, isWritable: true },
+                ...coAuthorProfileMetas(movedSubmissionAcct),
+            );
+        }
+
+        return movedCompletionMetas;
+    }
+
+    // The user's submission entries account, only passed for challenges accepting multiple submissions per user
+    async submissionEntriesAccount(challenge: PublicKey, challengeAcct: any, userProfile: PublicKey) {
+        if (challengeAcct.maxSubmissionsPerUser.isZero()) {
+            return null;
+        }
+
+        const [submissionEntries] = await findSubmissionEntriesPDA(challenge, userProfile);
+        return submissionEntries;
+    }
+
+    // Index of the user's next submission, which is the current period of a recurring challenge
+    async nextSubmissionIndex(challenge: PublicKey, challengeAcct: any, userProfile: PublicKey) {
+        if (!challengeAcct.maxSubmissionsPerUser.isZero()) {
+            const [submissionEntries] = await findSubmissionEntriesPDA(challenge, userProfile);
+            const submissionEntriesAcct = await this.challengerProgram.account.submissionEntries.fetchNullable(submissionEntries);
+            return submissionEntriesAcct ? submissionEntriesAcct.nextSubmissionIndex : new BN(0);
+        }
+
+        if (!challengeAcct.periodLength.isZero()) {
+            const nowTs = new BN(await this.connection.getBlockTime(await this.connection.getSlot()));
+            const periodsStartTs = BN.max(challengeAcct.challengeOpensTs, challengeAcct.challengePostedTs);
+            return nowTs.sub(periodsStartTs).div(challengeAcct.periodLength);
+        }
+
+        return new BN(0);
+    }
+
     // -------------------------------------------------------- execute ixs
 
     async initCrux(
         crux: Keypair,
         cruxManager: PublicKey | Keypair,
         cruxFees: CruxFees,
+        cruxConstants: CruxConstants,
+        cruxPolicies: CruxPolicies,
+        appealFee: BN,
+        autoAcceptAfterEvaluationDeadline: boolean,
     ) {
         // Derive PDAs
         const [cruxAuthority, cruxAuthBump] = await findCruxAuthorityPDA(crux.publicKey);
@@ -257,6 +532,10 @@ export class ChallengerClient {
             .initCrux(
                 cruxAuthBump,
                 cruxFees,
+                cruxConstants,
+                cruxPolicies,
+                appealFee,
+                autoAcceptAfterEvaluationDeadline,
             )
             .accounts({
                 crux: crux.publicKey,
@@ -290,6 +569,10 @@ export class ChallengerClient {
         crux: PublicKey,
         cruxManager: PublicKey | Keypair,
         cruxFees: CruxFees,
+        cruxConstants: CruxConstants,
+        cruxPolicies: CruxPolicies,
+        appealFee: BN,
+        autoAcceptAfterEvaluationDeadline: boolean,
     ) {
         // Create Signers Array
         const signers = [];
@@ -301,6 +584,10 @@ export class ChallengerClient {
         const txSig = await this.challengerProgram.methods
             .updateCruxParams(
                 cruxFees,
+                cruxConstants,
+                cruxPolicies,
+                appealFee,
+                autoAcceptAfterEvaluationDeadline,
             )
             .accounts({
                 crux: crux,
@@ -323,10 +610,176 @@ export class ChallengerClient {
         }
     }
 
+    async updateCruxPauseFlags(
+        crux: PublicKey,
+        cruxManager: PublicKey | Keypair,
+        cruxPauseFlags: CruxPauseFlags,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
+
+        console.log('updating pause flags for crux account with pubkey: ', crux.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .updateCruxPauseFlags(
+                cruxPauseFlags,
+            )
+            .accounts({
+                crux: crux,
+                cruxManager: isKp(cruxManager)? (<Keypair>cruxManager).publicKey : <PublicKey>cruxManager,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Pause flags for crux account with address ${crux} updated`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async proposeCruxManager(
+        crux: PublicKey,
+        cruxManager: PublicKey | Keypair,
+        newCruxManager: PublicKey,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
+
+        console.log('proposing new crux manager for crux account with pubkey: ', crux.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .proposeCruxManager()
+            .accounts({
+                crux: crux,
+                cruxManager: isKp(cruxManager)? (<Keypair>cruxManager).publicKey : <PublicKey>cruxManager,
+                newCruxManager: newCruxManager,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Crux manager ${newCruxManager} proposed for crux account with address ${crux}`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async acceptCruxManager(
+        crux: PublicKey,
+        newCruxManager: PublicKey | Keypair,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(newCruxManager)) signers.push(<Keypair>newCruxManager);
+
+        console.log('accepting crux manager role for crux account with pubkey: ', crux.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .acceptCruxManager()
+            .accounts({
+                crux: crux,
+                newCruxManager: isKp(newCruxManager)? (<Keypair>newCruxManager).publicKey : <PublicKey>newCruxManager,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Crux manager role for crux account with address ${crux} accepted`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async updateCruxFeeMint(
+        crux: PublicKey,
+        cruxManager: PublicKey | Keypair,
+        feeMint: PublicKey | null,
+    ) {
+        // Derive PDAs, where the fee mint's treasury token account and ledger are only passed when switching to token fees
+        const [cruxAuthority, cruxAuthBump] = await findCruxAuthorityPDA(crux);
+        const [cruxTreasuryTokenAccount, cruxTreasuryTokenAccountBump] = feeMint ? await findCruxTokenTreasuryPDA(crux, feeMint) : [null, null];
+        const [cruxTokenLedger, cruxTokenLedgerBump] = feeMint ? await findTokenLedgerPDA(crux, feeMint) : [null, null];
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
+
+        console.log('updating fee mint for crux account with pubkey: ', crux.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .updateCruxFeeMint(
+                cruxAuthBump,
+                feeMint,
+            )
+            .accounts({
+                crux: crux,
+                cruxManager: isKp(cruxManager)? (<Keypair>cruxManager).publicKey : <PublicKey>cruxManager,
+                cruxAuthority: cruxAuthority,
+                feeMint: feeMint,
+                cruxTreasuryTokenAccount: cruxTreasuryTokenAccount,
+                cruxTokenLedger: cruxTokenLedger,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = feeMint
+                ? `Fees for crux account with address ${crux} now charged in mint ${feeMint}`
+                : `Fees for crux account with address ${crux} now charged in lamports`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            cruxAuthority,
+            cruxAuthBump,
+            cruxTreasuryTokenAccount,
+            cruxTreasuryTokenAccountBump,
+            cruxTokenLedger,
+            cruxTokenLedgerBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
     async payoutFromTreasury(
         crux: PublicKey,
         cruxManager: PublicKey | Keypair,
         receiver: PublicKey,
+        amount: BN,
+        additionalPayouts: TreasuryPayout[] = [],
     ) {
         // Derive PDAs
         const [cruxTreasury, cruxTreasuryBump] = await findCruxTreasuryPDA(crux);
@@ -335,12 +788,19 @@ export class ChallengerClient {
         const signers = [];
         if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
 
+        // Additional receivers are passed in as remaining accounts
+        const remainingAccounts: AccountMeta[] = additionalPayouts.map((payout) => {
+            return { pubkey: payout.receiver, isSigner: false, isWritable: true };
+        });
+
         console.log('paying out from treasury for crux account with pubkey: ', crux.toBase58());
 
         // Transaction
         const txSig = await this.challengerProgram.methods
             .payoutFromTreasury(
                 cruxTreasuryBump,
+                amount,
+                additionalPayouts,
             )
             .accounts({
                 crux: crux,
@@ -349,6 +809,7 @@ export class ChallengerClient {
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts)
             .signers(signers)
             .rpc();
 
@@ -367,6 +828,69 @@ export class ChallengerClient {
         }
     }
 
+    async payoutFromTokenTreasury(
+        crux: PublicKey,
+        cruxManager: PublicKey | Keypair,
+        mint: PublicKey,
+        receiverTokenAccount: PublicKey,
+        amount: BN,
+        additionalPayouts: TreasuryPayout[] = [],
+    ) {
+        // Derive PDAs
+        const [cruxAuthority, cruxAuthBump] = await findCruxAuthorityPDA(crux);
+        const [cruxTreasuryTokenAccount, cruxTreasuryTokenAccountBump] = await findCruxTokenTreasuryPDA(crux, mint);
+        const [cruxTokenLedger] = await findTokenLedgerPDA(crux, mint);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
+
+        // Additional receiver token accounts are passed in as remaining accounts
+        const remainingAccounts: AccountMeta[] = additionalPayouts.map((payout) => {
+            return { pubkey: payout.receiver, isSigner: false, isWritable: true };
+        });
+
+        console.log('paying out from token treasury for crux account with pubkey: ', crux.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .payoutFromTokenTreasury(
+                cruxAuthBump,
+                cruxTreasuryTokenAccountBump,
+                amount,
+                additionalPayouts,
+            )
+            .accounts({
+                crux: crux,
+                cruxManager: isKp(cruxManager)? (<Keypair>cruxManager).publicKey : <PublicKey>cruxManager,
+                cruxAuthority: cruxAuthority,
+                mint: mint,
+                cruxTreasuryTokenAccount: cruxTreasuryTokenAccount,
+                cruxTokenLedger: cruxTokenLedger,
+                receiverTokenAccount: receiverTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts(remainingAccounts)
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Tokens of mint ${mint} from crux account with address ${crux} collected`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            cruxTreasuryTokenAccount,
+            cruxTreasuryTokenAccountBump,
+            cruxTokenLedger,
+            txSigMessage,
+            txSig
+        }
+    }
+
     async closeCrux(
         crux: PublicKey,
         cruxManager: PublicKey | Keypair,
@@ -422,6 +946,7 @@ export class ChallengerClient {
         // Derive PDAs
         const [cruxTreasury, cruxTreasuryBump] = await findCruxTreasuryPDA(crux);
         const [userProfile, userProfileBump] = await findUserProfilePDA(crux, profileOwnerKey);
+        const feeTokenAccounts = await this.feeTokenAccounts(crux, profileOwnerKey);
 
         // Create Signers Array
         const signers = [];
@@ -439,6 +964,10 @@ export class ChallengerClient {
                 cruxTreasury: cruxTreasury,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
+                profileOwnerTokenAccount: feeTokenAccounts.profileOwnerTokenAccount,
+                cruxTreasuryTokenAccount: feeTokenAccounts.cruxTreasuryTokenAccount,
+                cruxTokenLedger: feeTokenAccounts.cruxTokenLedger,
+                tokenProgram: feeTokenAccounts.tokenProgram,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -460,7 +989,6 @@ export class ChallengerClient {
             txSig
         }
     }
-
     async editUserProfile(
         crux: PublicKey,
         profileOwner: PublicKey | Keypair,
@@ -655,11 +1183,8 @@ export class ChallengerClient {
         crux: PublicKey,
         moderator: PublicKey | Keypair,
         contentDataHash: PublicKey,
-        title: string,
-        contentDataUrl: string,
-        tags: Tags[],
-        challengeExpiresTs: BN,
-        reputation: BN,
+        challengeSettings: ChallengeSettings,
+        answer?: string,
     ) {
         const challengeSeedKeypair = Keypair.generate();
         const challengeSeed: PublicKey = challengeSeedKeypair.publicKey;
@@ -669,6 +1194,17 @@ export class ChallengerClient {
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(crux, moderatorKey);
         const [challenge, challengeBump] = await findChallengePDA(crux, challengeSeed);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+
+        // Auto-graded challenges hash their answer with the challenge's key, which is only known once the seed is generated
+        if (answer) {
+            challengeSettings = { ...challengeSettings, answerHash: computeAnswerHash(challenge, answer) };
+        }
+
+        // Prerequisite challenges are passed in order as remaining accounts
+        const remainingAccounts: AccountMeta[] = challengeSettings.prerequisites.map((prerequisite) => {
+            return { pubkey: prerequisite, isSigner: false, isWritable: false };
+        });
 
         // Create Signers Array
         const signers = [];
@@ -680,11 +1216,7 @@ export class ChallengerClient {
         const txSig = await this.challengerProgram.methods
             .createChallenge(
                 moderatorProfileBump,
-                tags,
-                title,
-                contentDataUrl,
-                challengeExpiresTs,
-                reputation,
+                challengeSettings,
             )
             .accounts({
                 crux: crux,
@@ -692,9 +1224,11 @@ export class ChallengerClient {
                 moderatorProfile: moderatorProfile,
                 challenge: challenge,
                 challengeSeed: challengeSeed,
+                bountyVault: bountyVault,
                 contentDataHash: contentDataHash,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts)
             .signers(signers)
             .rpc();
 
@@ -711,6 +1245,8 @@ export class ChallengerClient {
             challenge,
             challengeBump,
             challengeSeed,
+            bountyVault,
+            bountyVaultBump,
             txSigMessage,
             txSig
         }
@@ -720,11 +1256,7 @@ export class ChallengerClient {
         challengeKey: PublicKey,
         moderator: PublicKey | Keypair,
         newContentDataHash: PublicKey,
-        newTitle: string,
-        newContentDataUrl: string,
-        newTags: Tags[],
-        newChallengeExpiresTs: BN,
-        newReputation: BN,
+        challengeEdit: ChallengeEdit,
     ) {
         const challengeAcct = await this.fetchChallengeAccount(challengeKey);
         const cruxKey = challengeAcct.crux;
@@ -747,11 +1279,7 @@ export class ChallengerClient {
             .editChallenge(
                 moderatorProfileBump,
                 challengeBump,
-                newTags,
-                newTitle,
-                newContentDataUrl,
-                newChallengeExpiresTs,
-                newReputation,
+                challengeEdit,
             )
             .accounts({
                 crux: cruxKey,
@@ -782,10 +1310,10 @@ export class ChallengerClient {
         }
     }
 
-    async deleteChallenge(
+    async updateChallengeStatus(
         challengeKey: PublicKey,
         moderator: PublicKey | Keypair,
-        receiver: PublicKey,
+        newChallengeStatus: ChallengeStatus,
     ) {
         const challengeAcct = await this.fetchChallengeAccount(challengeKey);
         const cruxKey = challengeAcct.crux;
@@ -797,6 +1325,144 @@ export class ChallengerClient {
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
         const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
 
+        // Archiving refunds any unclaimed token bounty to the funder's associated token account
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, challengeAcct.bountyTokenFunder);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(moderator)) signers.push(<Keypair>moderator);
+
+        console.log('updating status of challenge with pubkey: ', challenge.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .updateChallengeStatus(
+                moderatorProfileBump,
+                challengeBump,
+                newChallengeStatus,
+            )
+            .accounts({
+                crux: cruxKey,
+                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderatorProfile: moderatorProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                bountyVault: challengeAcct.bountyVault,
+                bountyFunder: challengeAcct.bountyFunder,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                bountyTokenFunderTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                tokenProgram: bountyTokenAccounts.tokenProgram,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Challenge account with address ${challenge} moved to status ${Object.keys(newChallengeStatus)[0]}`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            moderatorProfile,
+            moderatorProfileBump,
+            challenge,
+            challengeBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async fundChallengeTokenBounty(
+        challengeKey: PublicKey,
+        moderator: PublicKey | Keypair,
+        bountyTokenMint: PublicKey,
+        bountyTokenAmount: BN,
+        bountyTokenPerCompletion: BN,
+        moderatorTokenAccount?: PublicKey,
+    ) {
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+
+        // Derive PDAs
+        const [cruxAuthority, cruxAuthBump] = await findCruxAuthorityPDA(cruxKey);
+        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [bountyTokenVault, bountyTokenVaultBump] = await findBountyTokenVaultPDA(challenge, bountyTokenMint);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(moderator)) signers.push(<Keypair>moderator);
+
+        console.log('funding token bounty of challenge with pubkey: ', challenge.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .fundChallengeTokenBounty(
+                cruxAuthBump,
+                moderatorProfileBump,
+                challengeBump,
+                bountyTokenAmount,
+                bountyTokenPerCompletion,
+            )
+            .accounts({
+                crux: cruxKey,
+                cruxAuthority: cruxAuthority,
+                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderatorProfile: moderatorProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                bountyTokenMint: bountyTokenMint,
+                bountyTokenVault: bountyTokenVault,
+                moderatorTokenAccount: moderatorTokenAccount ?? await findAssociatedTokenAddress(bountyTokenMint, moderatorKey),
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Token bounty of challenge account with address ${challenge} funded`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            challenge,
+            challengeBump,
+            bountyTokenVault,
+            bountyTokenVaultBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async deleteChallenge(
+        challengeKey: PublicKey,
+        moderator: PublicKey | Keypair,
+        receiver: PublicKey,
+    ) {
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+
+        // Derive PDAs
+        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+
+        // Any unclaimed token bounty is refunded to the funder's associated token account
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, challengeAcct.bountyTokenFunder);
+
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
@@ -807,7 +1473,8 @@ export class ChallengerClient {
         const txSig = await this.challengerProgram.methods
             .deleteChallenge(
                 moderatorProfileBump,
-                challengeBump
+                challengeBump,
+                bountyVaultBump,
             )
             .accounts({
                 crux: cruxKey,
@@ -815,7 +1482,13 @@ export class ChallengerClient {
                 moderatorProfile: moderatorProfile,
                 challenge: challenge,
                 challengeSeed: challengeSeedKey,
+                bountyVault: bountyVault,
+                bountyFunder: challengeAcct.bountyFunder,
                 receiver: receiver,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                bountyTokenFunderTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                tokenProgram: bountyTokenAccounts.tokenProgram,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -840,10 +1513,14 @@ export class ChallengerClient {
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+    // On commit-reveal challenges, pass the commitment from computeContentCommitment as the content data hash and an empty url
     async createSubmission(
         challengeKey: PublicKey,
         profileOwner: PublicKey | Keypair,
         contentDataHash: PublicKey,
+        contentDataUrl: string,
+        submissionTeam: SubmissionTeam = { coAuthorProfiles: [], weights: [] },
+        prerequisiteSubmissions: PublicKey[] = [],
     ) {
         const challengeAcct = await this.fetchChallengeAccount(challengeKey);
         const cruxKey = challengeAcct.crux;
@@ -855,7 +1532,16 @@ export class ChallengerClient {
         const [cruxTreasury, cruxTreasuryBump] = await findCruxTreasuryPDA(cruxKey);
         const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
         const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
-        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const submissionIndex = await this.nextSubmissionIndex(challenge, challengeAcct, userProfile);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionIndex));
+        const submissionEntries = await this.submissionEntriesAccount(challenge, challengeAcct, userProfile);
+        const feeTokenAccounts = await this.feeTokenAccounts(cruxKey, profileOwnerKey);
+
+        // The user's completed submissions to each prerequisite challenge are passed in order as remaining accounts,
+        // followed by the profiles of any co-authors
+        const remainingAccounts: AccountMeta[] = [...prerequisiteSubmissions, ...submissionTeam.coAuthorProfiles].map((remainingAccount) => {
+            return { pubkey: remainingAccount, isSigner: false, isWritable: false };
+        });
 
         // Create Signers Array
         const signers = [];
@@ -868,7 +1554,10 @@ export class ChallengerClient {
             .createSubmission(
                 cruxTreasuryBump,
                 userProfileBump,
-                challengeBump
+                challengeBump,
+                contentDataUrl,
+                submissionIndex,
+                submissionTeam,
             )
             .accounts({
                 crux: cruxKey,
@@ -878,9 +1567,15 @@ export class ChallengerClient {
                 challenge: challenge,
                 challengeSeed: challengeSeedKey,
                 submission: submission,
+                submissionEntries: submissionEntries,
                 contentDataHash: contentDataHash,
+                profileOwnerTokenAccount: feeTokenAccounts.profileOwnerTokenAccount,
+                cruxTreasuryTokenAccount: feeTokenAccounts.cruxTreasuryTokenAccount,
+                cruxTokenLedger: feeTokenAccounts.cruxTokenLedger,
+                tokenProgram: feeTokenAccounts.tokenProgram,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts)
             .signers(signers)
             .rpc();
 
@@ -900,6 +1595,57 @@ export class ChallengerClient {
             challengeBump,
             submission,
             submissionBump,
+            submissionIndex,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async acceptCoAuthorship(
+        submissionKey: PublicKey,
+        coAuthor: PublicKey | Keypair,
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeAcct = await this.fetchChallengeAccount(submissionAcct.challenge);
+        const cruxKey = challengeAcct.crux;
+
+        const coAuthorKey = isKp(coAuthor) ? (<Keypair>coAuthor).publicKey : <PublicKey>coAuthor;
+
+        // Derive PDAs
+        const [coAuthorProfile, coAuthorProfileBump] = await findUserProfilePDA(cruxKey, coAuthorKey);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(coAuthor)) signers.push(<Keypair>coAuthor);
+
+        console.log('accepting co-authorship of submission with pubkey: ', submissionKey.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .acceptCoAuthorship(
+                coAuthorProfileBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                coAuthor: coAuthorKey,
+                coAuthorProfile: coAuthorProfile,
+                challenge: submissionAcct.challenge,
+                submission: submissionKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Co-authorship of submission account with address ${submissionKey} accepted by ${coAuthorProfile}`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            coAuthorProfile,
+            coAuthorProfileBump,
             txSigMessage,
             txSig
         }
@@ -909,6 +1655,7 @@ export class ChallengerClient {
         submissionKey: PublicKey,
         profileOwner: PublicKey | Keypair,
         newContentDataHash: PublicKey,
+        newContentDataUrl: string,
     ) {
         const submisionAcct = await this.fetchSubmissionAccount(submissionKey);
         const challengeKey = submisionAcct.challenge;
@@ -922,7 +1669,7 @@ export class ChallengerClient {
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
         const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
-        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submisionAcct.submissionIndex));
 
         // Create Signers Array
         const signers = [];
@@ -935,7 +1682,8 @@ export class ChallengerClient {
             .editSubmission(
                 userProfileBump,
                 challengeBump,
-                submissionBump
+                submissionBump,
+                newContentDataUrl,
             )
             .accounts({
                 crux: cruxKey,
@@ -969,10 +1717,12 @@ export class ChallengerClient {
         }
     }
 
-    async deleteSubmission(
+    async revealSubmission(
         submissionKey: PublicKey,
         profileOwner: PublicKey | Keypair,
-        receiver: PublicKey,
+        contentDataHash: PublicKey,
+        contentDataUrl: string,
+        salt: number[],
     ) {
         const submisionAcct = await this.fetchSubmissionAccount(submissionKey);
         const challengeKey = submisionAcct.challenge;
@@ -986,20 +1736,22 @@ export class ChallengerClient {
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
         const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
-        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submisionAcct.submissionIndex));
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('deleting submission with pubkey: ', submission.toBase58());
+        console.log('revealing submission with pubkey: ', submission.toBase58());
 
         // Transaction
         const txSig = await this.challengerProgram.methods
-            .deleteSubmission(
+            .revealSubmission(
                 userProfileBump,
                 challengeBump,
                 submissionBump,
+                contentDataUrl,
+                salt,
             )
             .accounts({
                 crux: cruxKey,
@@ -1008,14 +1760,14 @@ export class ChallengerClient {
                 challenge: challenge,
                 challengeSeed: challengeSeedKey,
                 submission: submission,
-                receiver: receiver,
+                contentDataHash: contentDataHash,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         if (txSig) {
-            var txSigMessage = `Submission account with address ${submission} deleted`;
+            var txSigMessage = `Submission account with address ${submission} revealed`;
         }
         else {
             var txSigMessage = 'Transaction failed';
@@ -1033,10 +1785,9 @@ export class ChallengerClient {
         }
     }
 
-    async deleteSubmissionModerator(
+    // Anyone may close a submission left unrevealed after its challenge's reveal period, refunding the rent to its owner
+    async closeUnrevealedSubmission(
         submissionKey: PublicKey,
-        moderator: PublicKey | Keypair,
-        receiver: PublicKey,
     ) {
         const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
         const challengeKey = submissionAcct.challenge;
@@ -1047,47 +1798,113 @@ export class ChallengerClient {
         const challengeSeedKey = challengeAcct.challengeSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfileKey);
-        const profileOwner = userProfileAcct.profileOwner;
+        const profileOwnerKey = userProfileAcct.profileOwner;
 
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+        // Derive PDAs
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const submissionEntries = await this.submissionEntriesAccount(challenge, challengeAcct, userProfile);
+
+        console.log('closing unrevealed submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .closeUnrevealedSubmission(
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                profileOwner: profileOwnerKey,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                submission: submission,
+                submissionEntries: submissionEntries,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Unrevealed submission account with address ${submission} closed`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async submitAnswer(
+        submissionKey: PublicKey,
+        profileOwner: PublicKey | Keypair,
+        answer: string,
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submissionAcct.challenge;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
         // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
-        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwner);
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
         const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
-        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const [answerAttempts, answerAttemptsBump] = await findAnswerAttemptsPDA(challenge, userProfile);
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, profileOwnerKey);
+        const movedCompletionMetas = await this.movedCompletionMetas(challengeAcct, submissionAcct);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
+        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('moderator deleting submission with pubkey: ', submission.toBase58());
+        console.log('submitting answer for submission with pubkey: ', submission.toBase58());
 
         // Transaction
         const txSig = await this.challengerProgram.methods
-            .deleteSubmissionModerator(
-                moderatorProfileBump,
+            .submitAnswer(
                 userProfileBump,
                 challengeBump,
                 submissionBump,
+                bountyVaultBump,
+                answer,
             )
             .accounts({
                 crux: cruxKey,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
+                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 challenge: challenge,
                 challengeSeed: challengeSeedKey,
+                bountyVault: bountyVault,
                 submission: submission,
-                receiver: receiver,
+                answerAttempts: answerAttempts,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                profileOwnerTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                tokenProgram: bountyTokenAccounts.tokenProgram,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts([...coAuthorProfileMetas(submissionAcct), ...movedCompletionMetas])
             .signers(signers)
             .rpc();
 
         if (txSig) {
-            var txSigMessage = `Submission account with address ${submission} deleted by moderator`;
+            var txSigMessage = `Answer submitted for submission account with address ${submission}`;
         }
         else {
             var txSigMessage = 'Transaction failed';
@@ -1100,15 +1917,16 @@ export class ChallengerClient {
             challengeBump,
             submission,
             submissionBump,
+            answerAttempts,
+            answerAttemptsBump,
             txSigMessage,
             txSig
         }
     }
 
-    async evaluateSubmission(
+    // Anyone may resolve a submission left pending past its challenge's evaluation deadline with the crux's default
+    async resolveExpiredSubmission(
         submissionKey: PublicKey,
-        moderator: PublicKey | Keypair,
-        submissionState: SubmissionState,
     ) {
         const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
         const challengeKey = submissionAcct.challenge;
@@ -1121,53 +1939,49 @@ export class ChallengerClient {
         const userProfileAcct = await this.fetchUserProfileAccount(userProfileKey);
         const profileOwnerKey = userProfileAcct.profileOwner;
 
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
-
         // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
         const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
         const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
-        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, profileOwnerKey);
+        const movedCompletionMetas = await this.movedCompletionMetas(challengeAcct, submissionAcct);
 
-        console.log('accepting submission with pubkey: ', submission.toBase58());
+        console.log('resolving expired submission with pubkey: ', submission.toBase58());
 
         // Transaction
         const txSig = await this.challengerProgram.methods
-            .evaluateSubmission(
-                moderatorProfileBump,
+            .resolveExpiredSubmission(
                 userProfileBump,
                 challengeBump,
                 submissionBump,
-                submissionState,
+                bountyVaultBump,
             )
             .accounts({
                 crux: cruxKey,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
                 profileOwner: profileOwnerKey,
                 userProfile: userProfile,
                 challenge: challenge,
                 challengeSeed: challengeSeedKey,
+                bountyVault: bountyVault,
                 submission: submission,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                profileOwnerTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                tokenProgram: bountyTokenAccounts.tokenProgram,
                 systemProgram: SystemProgram.programId,
             })
-            .signers(signers)
+            .remainingAccounts([...coAuthorProfileMetas(submissionAcct), ...movedCompletionMetas])
             .rpc();
 
         if (txSig) {
-            var txSigMessage = `Submission account with address ${submission} evaluated with submission state ${submissionState}`;
+            var txSigMessage = `Expired submission account with address ${submission} resolved`;
         }
         else {
             var txSigMessage = 'Transaction failed';
         }
 
         return {
-            moderatorProfile,
-            moderatorProfileBump,
             userProfile,
             userProfileBump,
             challenge,
@@ -1179,6 +1993,692 @@ export class ChallengerClient {
         }
     }
 
+    // Verifier accounts are passed through to the challenge's verifier program after the submitter's wallet
+    async verifySubmission(
+        submissionKey: PublicKey,
+        profileOwner: PublicKey | Keypair,
+        verifierAccounts: AccountMeta[],
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submissionAcct.challenge;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+
+        // Derive PDAs
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, profileOwnerKey);
+        const movedCompletionMetas = await this.movedCompletionMetas(challengeAcct, submissionAcct);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+
+        console.log('verifying submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .verifySubmission(
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+                bountyVaultBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                bountyVault: bountyVault,
+                submission: submission,
+                verifierProgram: challengeAcct.verifierProgram,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                profileOwnerTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                tokenProgram: bountyTokenAccounts.tokenProgram,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([...coAuthorProfileMetas(submissionAcct), ...movedCompletionMetas, ...verifierAccounts])
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Submission account with address ${submission} verified`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async deleteSubmission(
+        submissionKey: PublicKey,
+        profileOwner: PublicKey | Keypair,
+        receiver: PublicKey,
+    ) {
+        const submisionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submisionAcct.challenge;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+
+        // Derive PDAs
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submisionAcct.submissionIndex));
+        const submissionEntries = await this.submissionEntriesAccount(challenge, challengeAcct, userProfile);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+
+        console.log('deleting submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .deleteSubmission(
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                submission: submission,
+                submissionEntries: submissionEntries,
+                receiver: receiver,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Submission account with address ${submission} deleted`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async deleteSubmissionModerator(
+        submissionKey: PublicKey,
+        moderator: PublicKey | Keypair,
+        receiver: PublicKey,
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submissionAcct.challenge;
+        const userProfileKey = submissionAcct.userProfile;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfileKey);
+        const profileOwner = userProfileAcct.profileOwner;
+
+        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+
+        // Derive PDAs
+        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwner);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const submissionEntries = await this.submissionEntriesAccount(challenge, challengeAcct, userProfile);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(moderator)) signers.push(<Keypair>moderator);
+
+        console.log('moderator deleting submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .deleteSubmissionModerator(
+                moderatorProfileBump,
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderatorProfile: moderatorProfile,
+                profileOwner: profileOwner,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                submission: submission,
+                submissionEntries: submissionEntries,
+                receiver: receiver,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts(coAuthorProfileMetas(submissionAcct))
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Submission account with address ${submission} deleted by moderator`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async evaluateSubmission(
+        submissionKey: PublicKey,
+        moderator: PublicKey | Keypair,
+        feedbackContentDataHash: PublicKey,
+        evaluation: Evaluation,
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submissionAcct.challenge;
+        const userProfileKey = submissionAcct.userProfile;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfileKey);
+        const profileOwnerKey = userProfileAcct.profileOwner;
+
+        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+
+        // Derive PDAs
+        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const [review, reviewBump] = await findReviewPDA(submission, moderatorProfile, submissionAcct.submissionPostedTs, submissionAcct.reviewRound);
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, profileOwnerKey);
+        const movedCompletionMetas = await this.movedCompletionMetas(challengeAcct, submissionAcct);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(moderator)) signers.push(<Keypair>moderator);
+
+        console.log('evaluating submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .evaluateSubmission(
+                moderatorProfileBump,
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+                bountyVaultBump,
+                evaluation,
+            )
+            .accounts({
+                crux: cruxKey,
+                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderatorProfile: moderatorProfile,
+                profileOwner: profileOwnerKey,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                bountyVault: bountyVault,
+                submission: submission,
+                review: review,
+                feedbackContentDataHash: feedbackContentDataHash,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                profileOwnerTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                tokenProgram: bountyTokenAccounts.tokenProgram,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([...coAuthorProfileMetas(submissionAcct), ...movedCompletionMetas])
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Submission account with address ${submission} evaluated with submission state ${Object.keys(evaluation.decision.submissionState)[0]}`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            moderatorProfile,
+            moderatorProfileBump,
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            review,
+            reviewBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    // Reviews can only be closed once their submission account has been closed, or deleted and created again
+    async closeReview(
+        reviewKey: PublicKey,
+        moderator: PublicKey | Keypair,
+    ) {
+        const reviewAcct = await this.fetchReviewAccount(reviewKey);
+        const moderatorProfileAcct = await this.fetchUserProfileAccount(reviewAcct.moderatorProfile);
+        const cruxKey = moderatorProfileAcct.crux;
+
+        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+
+        // Derive PDAs
+        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(cruxKey, moderatorKey);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(moderator)) signers.push(<Keypair>moderator);
+
+        console.log('closing review with pubkey: ', reviewKey.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .closeReview(
+                moderatorProfileBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderatorProfile: moderatorProfile,
+                submission: reviewAcct.submission,
+                review: reviewKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Review account with address ${reviewKey} closed`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            moderatorProfile,
+            moderatorProfileBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async appealSubmission(
+        submissionKey: PublicKey,
+        profileOwner: PublicKey | Keypair,
+        appealContentDataHash: PublicKey,
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submissionAcct.challenge;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+
+        // Derive PDAs
+        const [cruxTreasury, cruxTreasuryBump] = await findCruxTreasuryPDA(cruxKey);
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const feeTokenAccounts = await this.feeTokenAccounts(cruxKey, profileOwnerKey);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+
+        console.log('appealing submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .appealSubmission(
+                cruxTreasuryBump,
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+            )
+            .accounts({
+                crux: cruxKey,
+                cruxTreasury: cruxTreasury,
+                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                submission: submission,
+                appealContentDataHash: appealContentDataHash,
+                profileOwnerTokenAccount: feeTokenAccounts.profileOwnerTokenAccount,
+                cruxTreasuryTokenAccount: feeTokenAccounts.cruxTreasuryTokenAccount,
+                cruxTokenLedger: feeTokenAccounts.cruxTokenLedger,
+                tokenProgram: feeTokenAccounts.tokenProgram,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Submission account with address ${submission} appealed`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    // Appeals are resolved by the crux manager, or by a moderator who did not vote in the review round which rejected the submission
+    async resolveAppeal(
+        submissionKey: PublicKey,
+        resolver: PublicKey | Keypair,
+        decision: SubmissionDecision,
+    ) {
+        const submissionAcct = await this.fetchSubmissionAccount(submissionKey);
+        const challengeKey = submissionAcct.challenge;
+        const userProfileKey = submissionAcct.userProfile;
+
+        const challengeAcct = await this.fetchChallengeAccount(challengeKey);
+        const cruxKey = challengeAcct.crux;
+        const challengeSeedKey = challengeAcct.challengeSeed;
+
+        const cruxAcct = await this.fetchCruxAccount(cruxKey);
+
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfileKey);
+        const profileOwnerKey = userProfileAcct.profileOwner;
+
+        const resolverKey = isKp(resolver) ? (<Keypair>resolver).publicKey : <PublicKey>resolver;
+
+        // Derive PDAs
+        const [cruxTreasury, cruxTreasuryBump] = await findCruxTreasuryPDA(cruxKey);
+        const [userProfile, userProfileBump] = await findUserProfilePDA(cruxKey, profileOwnerKey);
+        const [challenge, challengeBump] = await findChallengePDA(cruxKey, challengeSeedKey);
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+        const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile, submissionIndexSeed(challengeAcct, submissionAcct.submissionIndex));
+        const bountyTokenAccounts = await this.bountyTokenAccounts(challengeAcct, profileOwnerKey);
+        const movedCompletionMetas = await this.movedCompletionMetas(challengeAcct, submissionAcct);
+
+        // A resolving moderator passes their profile along with their review PDA of the rejecting round, which must not exist
+        let resolverProfile: PublicKey | null = null;
+        let previousReview: PublicKey | null = null;
+        if (!resolverKey.equals(cruxAcct.cruxManager)) {
+            [resolverProfile] = await findUserProfilePDA(cruxKey, resolverKey);
+            if (!submissionAcct.reviewRound.isZero()) {
+                [previousReview] = await findReviewPDA(submission, resolverProfile, submissionAcct.submissionPostedTs, submissionAcct.reviewRound.subn(1));
+            }
+        }
+
+        // Appeal fees paid in an SPL token are refunded from the token treasury of the mint they were paid in
+        const appealFeeMint = submissionAcct.appealFeeMint;
+        const hasTokenAppealFee = !appealFeeMint.equals(PublicKey.default);
+        const [cruxTreasuryTokenAccount] = await findCruxTokenTreasuryPDA(cruxKey, appealFeeMint);
+        const [cruxTokenLedger] = await findTokenLedgerPDA(cruxKey, appealFeeMint);
+        const profileOwnerFeeTokenAccount = hasTokenAppealFee ? await findAssociatedTokenAddress(appealFeeMint, profileOwnerKey) : null;
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(resolver)) signers.push(<Keypair>resolver);
+
+        console.log('resolving appeal of submission with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .resolveAppeal(
+                cruxTreasuryBump,
+                userProfileBump,
+                challengeBump,
+                submissionBump,
+                bountyVaultBump,
+                decision,
+            )
+            .accounts({
+                crux: cruxKey,
+                cruxTreasury: cruxTreasury,
+                resolver: resolverKey,
+                resolverProfile: resolverProfile,
+                previousReview: previousReview,
+                profileOwner: profileOwnerKey,
+                userProfile: userProfile,
+                challenge: challenge,
+                challengeSeed: challengeSeedKey,
+                bountyVault: bountyVault,
+                submission: submission,
+                cruxAuthority: bountyTokenAccounts.cruxAuthority,
+                bountyTokenVault: bountyTokenAccounts.bountyTokenVault,
+                profileOwnerTokenAccount: bountyTokenAccounts.receiverTokenAccount,
+                cruxTreasuryTokenAccount: hasTokenAppealFee ? cruxTreasuryTokenAccount : null,
+                cruxTokenLedger: hasTokenAppealFee ? cruxTokenLedger : null,
+                profileOwnerFeeTokenAccount: profileOwnerFeeTokenAccount,
+                tokenProgram: (hasTokenAppealFee || bountyTokenAccounts.tokenProgram) ? TOKEN_PROGRAM_ID : null,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([...coAuthorProfileMetas(submissionAcct), ...movedCompletionMetas])
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Appeal of submission account with address ${submission} resolved with submission state ${Object.keys(decision.submissionState)[0]}`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            userProfile,
+            userProfileBump,
+            challenge,
+            challengeBump,
+            submission,
+            submissionBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    // Accounts created before the latest layout are migrated in place, with the payer covering any additional rent
+
+    async migrateCrux(
+        crux: PublicKey,
+        cruxManager: PublicKey | Keypair,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
+
+        console.log('migrating crux account with pubkey: ', crux.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .migrateCrux()
+            .accounts({
+                crux: crux,
+                cruxManager: isKp(cruxManager)? (<Keypair>cruxManager).publicKey : <PublicKey>cruxManager,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Crux account with address ${crux} migrated`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async migrateUserProfile(
+        userProfile: PublicKey,
+        payer: PublicKey | Keypair,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(payer)) signers.push(<Keypair>payer);
+
+        console.log('migrating user profile account with pubkey: ', userProfile.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .migrateUserProfile()
+            .accounts({
+                userProfile: userProfile,
+                payer: isKp(payer)? (<Keypair>payer).publicKey : <PublicKey>payer,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `User profile account with address ${userProfile} migrated`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async migrateChallenge(
+        crux: PublicKey,
+        challenge: PublicKey,
+        cruxManager: PublicKey | Keypair,
+    ) {
+        // Derive PDAs
+        const [bountyVault, bountyVaultBump] = await findBountyVaultPDA(challenge);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(cruxManager)) signers.push(<Keypair>cruxManager);
+
+        console.log('migrating challenge account with pubkey: ', challenge.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .migrateChallenge()
+            .accounts({
+                crux: crux,
+                challenge: challenge,
+                bountyVault: bountyVault,
+                cruxManager: isKp(cruxManager)? (<Keypair>cruxManager).publicKey : <PublicKey>cruxManager,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Challenge account with address ${challenge} migrated`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            bountyVault,
+            bountyVaultBump,
+            txSigMessage,
+            txSig
+        }
+    }
+
+    async migrateSubmission(
+        submission: PublicKey,
+        challenge: PublicKey,
+        payer: PublicKey | Keypair,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(payer)) signers.push(<Keypair>payer);
+
+        console.log('migrating submission account with pubkey: ', submission.toBase58());
+
+        // Transaction
+        const txSig = await this.challengerProgram.methods
+            .migrateSubmission()
+            .accounts({
+                submission: submission,
+                challenge: challenge,
+                payer: isKp(payer)? (<Keypair>payer).publicKey : <PublicKey>payer,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        if (txSig) {
+            var txSigMessage = `Submission account with address ${submission} migrated`;
+        }
+        else {
+            var txSigMessage = 'Transaction failed';
+        }
+
+        return {
+            txSigMessage,
+            txSig
+        }
+    }
+
     async closeAccount(
         signer: PublicKey | Keypair,
         accountToClose: PublicKey,
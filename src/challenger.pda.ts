@@ -1,3 +1,4 @@
+import { BN } from '@coral-xyz/anchor';
 import { PublicKey } from '@solana/web3.js';
 import { CHALLENGER_PROG_ID } from './index';
 
@@ -15,6 +16,20 @@ export const findCruxTreasuryPDA = async (crux: PublicKey) => {
     );
 };
 
+export const findCruxTokenTreasuryPDA = async (crux: PublicKey, mint: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('token_treasury'), crux.toBytes(), mint.toBytes()],
+        CHALLENGER_PROG_ID
+    );
+};
+
+export const findTokenLedgerPDA = async (crux: PublicKey, mint: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('token_ledger'), crux.toBytes(), mint.toBytes()],
+        CHALLENGER_PROG_ID
+    );
+};
+
 export const findUserProfilePDA = async (crux: PublicKey, profileOwner: PublicKey) => {
     return PublicKey.findProgramAddressSync(
         [Buffer.from('user_profile'), crux.toBytes(), profileOwner.toBytes()],
@@ -29,9 +44,45 @@ export const findChallengePDA = async (crux: PublicKey, challengeSeed: PublicKey
     );
 };
 
-export const findSubmissionPDA = async (challenge: PublicKey, userProfile: PublicKey, ) => {
+export const findBountyVaultPDA = async (challenge: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('bounty_vault'), challenge.toBytes()],
+        CHALLENGER_PROG_ID
+    );
+};
+
+export const findBountyTokenVaultPDA = async (challenge: PublicKey, bountyTokenMint: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('bounty_token_vault'), challenge.toBytes(), bountyTokenMint.toBytes()],
+        CHALLENGER_PROG_ID
+    );
+};
+
+// Challenges accepting a single submission per user seed it without an index
+export const findSubmissionPDA = async (challenge: PublicKey, userProfile: PublicKey, submissionIndexSeed: Buffer = Buffer.alloc(0)) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('submission'), challenge.toBytes(), userProfile.toBytes(), submissionIndexSeed],
+        CHALLENGER_PROG_ID
+    );
+};
+
+export const findSubmissionEntriesPDA = async (challenge: PublicKey, userProfile: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('submission_entries'), challenge.toBytes(), userProfile.toBytes()],
+        CHALLENGER_PROG_ID
+    );
+};
+
+export const findAnswerAttemptsPDA = async (challenge: PublicKey, userProfile: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('answer_attempts'), challenge.toBytes(), userProfile.toBytes()],
+        CHALLENGER_PROG_ID
+    );
+};
+
+export const findReviewPDA = async (submission: PublicKey, moderatorProfile: PublicKey, submissionPostedTs: BN, reviewRound: BN) => {
     return PublicKey.findProgramAddressSync(
-        [Buffer.from('submission'), challenge.toBytes(), userProfile.toBytes()],
+        [Buffer.from('review'), submission.toBytes(), moderatorProfile.toBytes(), submissionPostedTs.toArrayLike(Buffer, 'le', 8), reviewRound.toArrayLike(Buffer, 'le', 8)],
         CHALLENGER_PROG_ID
     );
 };
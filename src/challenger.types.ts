@@ -46,6 +46,26 @@ export type Challenger = {
           "type": {
             "defined": "CruxFees"
           }
+        },
+        {
+          "name": "cruxConstants",
+          "type": {
+            "defined": "CruxConstants"
+          }
+        },
+        {
+          "name": "cruxPolicies",
+          "type": {
+            "defined": "CruxPolicies"
+          }
+        },
+        {
+          "name": "appealFee",
+          "type": "u64"
+        },
+        {
+          "name": "autoAcceptAfterEvaluationDeadline",
+          "type": "bool"
         }
       ]
     },
@@ -74,16 +94,176 @@ export type Challenger = {
           "type": {
             "defined": "CruxFees"
           }
+        },
+        {
+          "name": "newCruxConstants",
+          "type": {
+            "defined": "CruxConstants"
+          }
+        },
+        {
+          "name": "newCruxPolicies",
+          "type": {
+            "defined": "CruxPolicies"
+          }
+        },
+        {
+          "name": "newAppealFee",
+          "type": "u64"
+        },
+        {
+          "name": "newAutoAcceptAfterEvaluationDeadline",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "payoutFromTreasury",
+      "name": "updateCruxPauseFlags",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newCruxPauseFlags",
+          "type": {
+            "defined": "CruxPauseFlags"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeCruxManager",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newCruxManager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptCruxManager",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newCruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateCruxFeeMint",
       "accounts": [
         {
           "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCruxAuth",
+          "type": "u8"
+        },
+        {
+          "name": "newFeeMint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTreasury",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "cruxManager",
@@ -110,6 +290,90 @@ export type Challenger = {
         {
           "name": "bumpTreasury",
           "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "additionalPayouts",
+          "type": {
+            "vec": {
+              "defined": "TreasuryPayout"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTokenTreasury",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCruxAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTokenTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "additionalPayouts",
+          "type": {
+            "vec": {
+              "defined": "TreasuryPayout"
+            }
+          }
         }
       ]
     },
@@ -172,6 +436,30 @@ export type Challenger = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -357,6 +645,11 @@ export type Challenger = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "contentDataHash",
           "isMut": false,
@@ -374,28 +667,10 @@ export type Challenger = {
           "type": "u8"
         },
         {
-          "name": "tags",
+          "name": "challengeSettings",
           "type": {
-            "vec": {
-              "defined": "Tags"
-            }
+            "defined": "ChallengeSettings"
           }
-        },
-        {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "contentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "challengeExpiresTs",
-          "type": "u64"
-        },
-        {
-          "name": "reputation",
-          "type": "u64"
         }
       ]
     },
@@ -448,37 +723,19 @@ export type Challenger = {
           "type": "u8"
         },
         {
-          "name": "newTags",
+          "name": "challengeEdit",
           "type": {
-            "vec": {
-              "defined": "Tags"
-            }
+            "defined": "ChallengeEdit"
           }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "newChallengeExpiresTs",
-          "type": "u64"
-        },
-        {
-          "name": "newReputation",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "deleteChallenge",
+      "name": "updateChallengeStatus",
       "accounts": [
         {
           "name": "crux",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -502,10 +759,39 @@ export type Challenger = {
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bountyFunder",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenFunderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -520,35 +806,41 @@ export type Challenger = {
         {
           "name": "bumpChallenge",
           "type": "u8"
+        },
+        {
+          "name": "newChallengeStatus",
+          "type": {
+            "defined": "ChallengeStatus"
+          }
         }
       ]
     },
     {
-      "name": "createSubmission",
+      "name": "fundChallengeTokenBounty",
       "accounts": [
         {
           "name": "crux",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cruxTreasury",
-          "isMut": true,
+          "name": "cruxAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "challenge",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -557,12 +849,27 @@ export type Challenger = {
           "isSigner": false
         },
         {
-          "name": "submission",
+          "name": "bountyTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyTokenVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "moderatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -574,40 +881,48 @@ export type Challenger = {
       ],
       "args": [
         {
-          "name": "bumpTreasury",
+          "name": "bumpCruxAuth",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
           "name": "bumpChallenge",
           "type": "u8"
+        },
+        {
+          "name": "bountyTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "bountyTokenPerCompletion",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "editSubmission",
+      "name": "deleteChallenge",
       "accounts": [
         {
           "name": "crux",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "challenge",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -616,15 +931,44 @@ export type Challenger = {
           "isSigner": false
         },
         {
-          "name": "submission",
+          "name": "bountyVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "bountyFunder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenFunderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -633,7 +977,7 @@ export type Challenger = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
@@ -641,22 +985,27 @@ export type Challenger = {
           "type": "u8"
         },
         {
-          "name": "bumpSubmission",
+          "name": "bumpBountyVault",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteSubmission",
+      "name": "createSubmission",
       "accounts": [
         {
           "name": "crux",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -680,10 +1029,40 @@ export type Challenger = {
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "submissionEntries",
           "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -691,6 +1070,10 @@ export type Challenger = {
         }
       ],
       "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
@@ -700,34 +1083,75 @@ export type Challenger = {
           "type": "u8"
         },
         {
-          "name": "bumpSubmission",
-          "type": "u8"
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "submissionIndex",
+          "type": "u64"
+        },
+        {
+          "name": "submissionTeam",
+          "type": {
+            "defined": "SubmissionTeam"
+          }
         }
       ]
     },
     {
-      "name": "deleteSubmissionModerator",
+      "name": "acceptCoAuthorship",
       "accounts": [
         {
           "name": "crux",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "coAuthor",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "coAuthorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCoAuthorProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "editSubmission",
+      "accounts": [
+        {
+          "name": "crux",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "userProfile",
           "isMut": true,
@@ -749,8 +1173,8 @@ export type Challenger = {
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": true,
+          "name": "newContentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -760,10 +1184,6 @@ export type Challenger = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
@@ -775,31 +1195,25 @@ export type Challenger = {
         {
           "name": "bumpSubmission",
           "type": "u8"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "evaluateSubmission",
+      "name": "revealSubmission",
       "accounts": [
         {
           "name": "crux",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "userProfile",
@@ -821,6 +1235,11 @@ export type Challenger = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -828,10 +1247,6 @@ export type Challenger = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
@@ -845,344 +1260,3403 @@ export type Challenger = {
           "type": "u8"
         },
         {
-          "name": "submissionState",
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "salt",
           "type": {
-            "defined": "SubmissionState"
+            "array": [
+              "u8",
+              32
+            ]
           }
         }
       ]
     },
     {
-      "name": "closeAccount",
+      "name": "closeUnrevealedSubmission",
       "accounts": [
         {
-          "name": "signer",
+          "name": "crux",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "accountToClose",
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "submitAnswer",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerAttempts",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        },
+        {
+          "name": "answer",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "resolveExpiredSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "verifySubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifierProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteSubmissionModerator",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "evaluateSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "review",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feedbackContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        },
+        {
+          "name": "evaluation",
+          "type": {
+            "defined": "Evaluation"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeReview",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "review",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "appealSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "appealContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "resolveAppeal",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "resolver",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "resolverProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "previousReview",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerFeeTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        },
+        {
+          "name": "decision",
+          "type": {
+            "defined": "SubmissionDecision"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateCrux",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUserProfile",
+      "accounts": [
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateChallenge",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSubmission",
+      "accounts": [
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeAccount",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "accountToClose",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "answerAttempts",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "lastAnswerAttemptTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challenge",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "crux",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengeSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengePostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "challengeExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "bountyVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyFunder",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bountyPerCompletion",
+            "type": "u64"
+          },
+          {
+            "name": "bountyTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyTokenVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyTokenFunder",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bountyTokenPerCompletion",
+            "type": "u64"
+          },
+          {
+            "name": "requiredApprovals",
+            "type": "u64"
+          },
+          {
+            "name": "challengeAuthor",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxRevisions",
+            "type": "u64"
+          },
+          {
+            "name": "maxSubmissionsPerUser",
+            "type": "u64"
+          },
+          {
+            "name": "revealDuration",
+            "type": "u64"
+          },
+          {
+            "name": "answerHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxAnswerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "answerCooldown",
+            "type": "u64"
+          },
+          {
+            "name": "verifierProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "verifierParams",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "challengeOpensTs",
+            "type": "u64"
+          },
+          {
+            "name": "evaluationDeadlineTs",
+            "type": "u64"
+          },
+          {
+            "name": "maxCompletions",
+            "type": "u64"
+          },
+          {
+            "name": "completionCount",
+            "type": "u64"
+          },
+          {
+            "name": "reputationSchedule",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "rankedCompletions",
+            "type": {
+              "array": [
+                {
+                  "defined": "RankedCompletion"
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "prerequisites",
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "minReputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "periodLength",
+            "type": "u64"
+          },
+          {
+            "name": "challengeStatus",
+            "type": {
+              "defined": "ChallengeStatus"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "crux",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "cruxManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxAuthoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "cruxTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxFees",
+            "type": {
+              "defined": "CruxFees"
+            }
+          },
+          {
+            "name": "cruxCounts",
+            "type": {
+              "defined": "CruxCounts"
+            }
+          },
+          {
+            "name": "cruxConstants",
+            "type": {
+              "defined": "CruxConstants"
+            }
+          },
+          {
+            "name": "feeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxTreasuryTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxLedger",
+            "type": {
+              "defined": "CruxLedger"
+            }
+          },
+          {
+            "name": "pendingCruxManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxPauseFlags",
+            "type": {
+              "defined": "CruxPauseFlags"
+            }
+          },
+          {
+            "name": "cruxPolicies",
+            "type": {
+              "defined": "CruxPolicies"
+            }
+          },
+          {
+            "name": "appealFee",
+            "type": "u64"
+          },
+          {
+            "name": "autoAcceptAfterEvaluationDeadline",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "review",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "moderatorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "reviewRound",
+            "type": "u64"
+          },
+          {
+            "name": "reviewTs",
+            "type": "u64"
+          },
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "scoreBps",
+            "type": "u64"
+          },
+          {
+            "name": "feedbackContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "feedbackContentDataUrl",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "submissionEntries",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "entryCount",
+            "type": "u64"
+          },
+          {
+            "name": "nextSubmissionIndex",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "submission",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "awardedReputation",
+            "type": "u64"
+          },
+          {
+            "name": "bountyPaid",
+            "type": "bool"
+          },
+          {
+            "name": "scoreBps",
+            "type": "u64"
+          },
+          {
+            "name": "reviewRound",
+            "type": "u64"
+          },
+          {
+            "name": "approvalCount",
+            "type": "u64"
+          },
+          {
+            "name": "rejectionCount",
+            "type": "u64"
+          },
+          {
+            "name": "approvalScoreBpsTotal",
+            "type": "u64"
+          },
+          {
+            "name": "appealContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "appealFeePaid",
+            "type": "u64"
+          },
+          {
+            "name": "appealFeeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          },
+          {
+            "name": "previousContentDataHashes",
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "revisionRequestCount",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "submissionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "submitterWeight",
+            "type": "u64"
+          },
+          {
+            "name": "coAuthorCount",
+            "type": "u8"
+          },
+          {
+            "name": "coAuthors",
+            "type": {
+              "array": [
+                {
+                  "defined": "CoAuthor"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "contentCommitment",
+            "type": "publicKey"
+          },
+          {
+            "name": "awaitingReveal",
+            "type": "bool"
+          },
+          {
+            "name": "answerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "lastAnswerAttemptTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "crux",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalTokenFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalTokenPaidOut",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "crux",
+            "type": "publicKey"
+          },
+          {
+            "name": "profileCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "challengesSubmitted",
+            "type": "u64"
+          },
+          {
+            "name": "challengesCompleted",
+            "type": "u64"
+          },
+          {
+            "name": "reputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "nftPfpTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "isModerator",
+            "type": "bool"
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "streakChallenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "streakPeriodsStartTs",
+            "type": "u64"
+          },
+          {
+            "name": "streakPeriodLength",
+            "type": "u64"
+          },
+          {
+            "name": "streakPeriodIndex",
+            "type": "u64"
+          },
+          {
+            "name": "streakPeriods",
+            "type": "u64"
+          },
+          {
+            "name": "streakCarry",
+            "type": "u64"
+          },
+          {
+            "name": "streak",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "ChallengeEdit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "challengeExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "challengeExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "bountyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bountyPerCompletion",
+            "type": "u64"
+          },
+          {
+            "name": "requiredApprovals",
+            "type": "u64"
+          },
+          {
+            "name": "maxRevisions",
+            "type": "u64"
+          },
+          {
+            "name": "maxSubmissionsPerUser",
+            "type": "u64"
+          },
+          {
+            "name": "revealDuration",
+            "type": "u64"
+          },
+          {
+            "name": "answerHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxAnswerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "answerCooldown",
+            "type": "u64"
+          },
+          {
+            "name": "verifierProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "verifierParams",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "challengeOpensTs",
+            "type": "u64"
+          },
+          {
+            "name": "evaluationDeadlineTs",
+            "type": "u64"
+          },
+          {
+            "name": "maxCompletions",
+            "type": "u64"
+          },
+          {
+            "name": "reputationSchedule",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "prerequisites",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "minReputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "periodLength",
+            "type": "u64"
+          },
+          {
+            "name": "isDraft",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoAuthor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "awardedReputation",
+            "type": "u64"
+          },
+          {
+            "name": "accepted",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxConstants",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTagsLength",
+            "type": "u64"
+          },
+          {
+            "name": "maxTitleLength",
+            "type": "u64"
+          },
+          {
+            "name": "maxUrlLength",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxCounts",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileCount",
+            "type": "u64"
+          },
+          {
+            "name": "challengeCount",
+            "type": "u64"
+          },
+          {
+            "name": "submissionCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileFee",
+            "type": "u64"
+          },
+          {
+            "name": "submissionFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaidOut",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxPauseFlags",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileCreationPaused",
+            "type": "bool"
+          },
+          {
+            "name": "challengeCreationPaused",
+            "type": "bool"
+          },
+          {
+            "name": "submissionsPaused",
+            "type": "bool"
+          },
+          {
+            "name": "evaluationPaused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxPolicies",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forbidAuthorEvaluation",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Evaluation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "decision",
+            "type": {
+              "defined": "SubmissionDecision"
+            }
+          },
+          {
+            "name": "feedbackContentDataUrl",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RankedCompletion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SubmissionDecision",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "scoreBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SubmissionTeam",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "coAuthorProfiles",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "weights",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TreasuryPayout",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "receiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Draft"
+          },
+          {
+            "name": "Closed"
+          },
+          {
+            "name": "Archived"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SubmissionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Completed"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Appealed"
+          },
+          {
+            "name": "NeedsRevision"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Tags",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ArtificialIntelligence"
+          },
+          {
+            "name": "CryptoInfrastructure"
+          },
+          {
+            "name": "DaosAndNetworkStates"
+          },
+          {
+            "name": "DataAndAnalytics"
+          },
+          {
+            "name": "Development"
+          },
+          {
+            "name": "FinanceAndPayments"
+          },
+          {
+            "name": "GamingAndEntertainment"
+          },
+          {
+            "name": "Ideas"
+          },
+          {
+            "name": "MobileConsumerApps"
+          },
+          {
+            "name": "Nfts"
+          },
+          {
+            "name": "PhysicalInfrastructureNetworks"
+          },
+          {
+            "name": "Social"
+          }
+        ]
+      }
     }
-  ],
-  "accounts": [
+  ]
+};
+
+export const IDL: Challenger = {
+  "version": "0.1.0",
+  "name": "challenger",
+  "instructions": [
+    {
+      "name": "initCrux",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cruxManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCruxAuth",
+          "type": "u8"
+        },
+        {
+          "name": "cruxFees",
+          "type": {
+            "defined": "CruxFees"
+          }
+        },
+        {
+          "name": "cruxConstants",
+          "type": {
+            "defined": "CruxConstants"
+          }
+        },
+        {
+          "name": "cruxPolicies",
+          "type": {
+            "defined": "CruxPolicies"
+          }
+        },
+        {
+          "name": "appealFee",
+          "type": "u64"
+        },
+        {
+          "name": "autoAcceptAfterEvaluationDeadline",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateCruxParams",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newCruxFees",
+          "type": {
+            "defined": "CruxFees"
+          }
+        },
+        {
+          "name": "newCruxConstants",
+          "type": {
+            "defined": "CruxConstants"
+          }
+        },
+        {
+          "name": "newCruxPolicies",
+          "type": {
+            "defined": "CruxPolicies"
+          }
+        },
+        {
+          "name": "newAppealFee",
+          "type": "u64"
+        },
+        {
+          "name": "newAutoAcceptAfterEvaluationDeadline",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateCruxPauseFlags",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newCruxPauseFlags",
+          "type": {
+            "defined": "CruxPauseFlags"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeCruxManager",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newCruxManager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptCruxManager",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newCruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateCruxFeeMint",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCruxAuth",
+          "type": "u8"
+        },
+        {
+          "name": "newFeeMint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTreasury",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "additionalPayouts",
+          "type": {
+            "vec": {
+              "defined": "TreasuryPayout"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTokenTreasury",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCruxAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTokenTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "additionalPayouts",
+          "type": {
+            "vec": {
+              "defined": "TreasuryPayout"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeCrux",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createUserProfile",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        }
+      ]
+    },
     {
-      "name": "challenge",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "crux",
-            "type": "publicKey"
-          },
-          {
-            "name": "challengeSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "challengePostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "challengeExpiresTs",
-            "type": "u64"
-          },
-          {
-            "name": "tags",
-            "type": {
-              "vec": {
-                "defined": "Tags"
-              }
-            }
-          },
-          {
-            "name": "title",
-            "type": "string"
-          },
-          {
-            "name": "contentDataUrl",
-            "type": "string"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "reputation",
-            "type": "u64"
+      "name": "editUserProfile",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftPfpTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteUserProfile",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addModerator",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeModerator",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createChallenge",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "challengeSettings",
+          "type": {
+            "defined": "ChallengeSettings"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "crux",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "cruxManager",
-            "type": "publicKey"
-          },
-          {
-            "name": "cruxAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "cruxAuthoritySeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "cruxAuthorityBumpSeed",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "cruxTreasury",
-            "type": "publicKey"
-          },
-          {
-            "name": "cruxFees",
-            "type": {
-              "defined": "CruxFees"
-            }
-          },
-          {
-            "name": "cruxCounts",
-            "type": {
-              "defined": "CruxCounts"
-            }
+      "name": "editChallenge",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "challengeEdit",
+          "type": {
+            "defined": "ChallengeEdit"
           }
-        ]
-      }
+        }
+      ]
+    },
+    {
+      "name": "updateChallengeStatus",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bountyFunder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenFunderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "newChallengeStatus",
+          "type": {
+            "defined": "ChallengeStatus"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundChallengeTokenBounty",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCruxAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bountyTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "bountyTokenPerCompletion",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "submission",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "challenge",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "submissionPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "submissionState",
-            "type": {
-              "defined": "SubmissionState"
-            }
-          }
-        ]
-      }
+      "name": "deleteChallenge",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bountyFunder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenFunderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "userProfile",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "profileOwner",
-            "type": "publicKey"
-          },
-          {
-            "name": "crux",
-            "type": "publicKey"
-          },
-          {
-            "name": "profileCreatedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "challengesSubmitted",
-            "type": "u64"
-          },
-          {
-            "name": "challengesCompleted",
-            "type": "u64"
-          },
-          {
-            "name": "reputationScore",
-            "type": "u64"
-          },
-          {
-            "name": "nftPfpTokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "isModerator",
-            "type": "bool"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "CruxCounts",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "profileCount",
-            "type": "u64"
-          },
-          {
-            "name": "challengeCount",
-            "type": "u64"
-          },
-          {
-            "name": "submissionCount",
-            "type": "u64"
+      "name": "createSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "submissionIndex",
+          "type": "u64"
+        },
+        {
+          "name": "submissionTeam",
+          "type": {
+            "defined": "SubmissionTeam"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "CruxFees",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "profileFee",
-            "type": "u64"
-          },
-          {
-            "name": "submissionFee",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "acceptCoAuthorship",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coAuthor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "coAuthorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCoAuthorProfile",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "SubmissionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Completed"
-          },
-          {
-            "name": "Rejected"
-          },
-          {
-            "name": "Pending"
-          }
-        ]
-      }
+      "name": "editSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "Tags",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "ArtificialIntelligence"
-          },
-          {
-            "name": "CryptoInfrastructure"
-          },
-          {
-            "name": "DaosAndNetworkStates"
-          },
-          {
-            "name": "DataAndAnalytics"
-          },
-          {
-            "name": "Development"
-          },
-          {
-            "name": "FinanceAndPayments"
-          },
-          {
-            "name": "GamingAndEntertainment"
-          },
-          {
-            "name": "Ideas"
-          },
-          {
-            "name": "MobileConsumerApps"
-          },
-          {
-            "name": "Nfts"
-          },
-          {
-            "name": "PhysicalInfrastructureNetworks"
-          },
-          {
-            "name": "Social"
+      "name": "revealSubmission",
+      "accounts": [
+        {
+          "name": "crux",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           }
-        ]
-      }
-    }
-  ]
-};
-
-export const IDL: Challenger = {
-  "version": "0.1.0",
-  "name": "challenger",
-  "instructions": [
+        }
+      ]
+    },
     {
-      "name": "initCrux",
+      "name": "closeUnrevealedSubmission",
       "accounts": [
         {
           "name": "crux",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "cruxManager",
+          "name": "profileOwner",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "cruxAuthority",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cruxTreasury",
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "systemProgram",
@@ -1192,68 +4666,86 @@ export const IDL: Challenger = {
       ],
       "args": [
         {
-          "name": "bumpCruxAuth",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "cruxFees",
-          "type": {
-            "defined": "CruxFees"
-          }
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "updateCruxParams",
+      "name": "submitAnswer",
       "accounts": [
         {
           "name": "crux",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cruxManager",
-          "isMut": false,
+          "name": "profileOwner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "userProfile",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "newCruxFees",
-          "type": {
-            "defined": "CruxFees"
-          }
-        }
-      ]
-    },
-    {
-      "name": "payoutFromTreasury",
-      "accounts": [
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "crux",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cruxManager",
-          "isMut": false,
-          "isSigner": true
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "cruxTreasury",
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "answerAttempts",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1262,69 +4754,88 @@ export const IDL: Challenger = {
       ],
       "args": [
         {
-          "name": "bumpTreasury",
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
           "type": "u8"
+        },
+        {
+          "name": "answer",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "closeCrux",
+      "name": "resolveExpiredSubmission",
       "accounts": [
         {
           "name": "crux",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cruxManager",
-          "isMut": false,
-          "isSigner": true
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "cruxTreasury",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "createUserProfile",
-      "accounts": [
+        },
         {
-          "name": "crux",
+          "name": "bountyVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "cruxTreasury",
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "userProfile",
+          "name": "profileOwnerTokenAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "systemProgram",
@@ -1334,13 +4845,25 @@ export const IDL: Challenger = {
       ],
       "args": [
         {
-          "name": "bumpTreasury",
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editUserProfile",
+      "name": "verifySubmission",
       "accounts": [
         {
           "name": "crux",
@@ -1349,7 +4872,7 @@ export const IDL: Challenger = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1358,10 +4881,54 @@ export const IDL: Challenger = {
           "isSigner": false
         },
         {
-          "name": "nftPfpTokenMint",
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verifierProgram",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1372,11 +4939,23 @@ export const IDL: Challenger = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteUserProfile",
+      "name": "deleteSubmission",
       "accounts": [
         {
           "name": "crux",
@@ -1393,6 +4972,27 @@ export const IDL: Challenger = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "receiver",
           "isMut": true,
@@ -1408,22 +5008,35 @@ export const IDL: Challenger = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "addModerator",
+      "name": "deleteSubmissionModerator",
       "accounts": [
         {
           "name": "crux",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "cruxManager",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
           "isMut": false,
@@ -1435,38 +5048,28 @@ export const IDL: Challenger = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "removeModerator",
-      "accounts": [
-        {
-          "name": "crux",
-          "isMut": false,
+          "name": "challenge",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "cruxManager",
+          "name": "challengeSeed",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "submission",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "submissionEntries",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
@@ -1477,18 +5080,30 @@ export const IDL: Challenger = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "createChallenge",
+      "name": "evaluateSubmission",
       "accounts": [
         {
           "name": "crux",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1501,6 +5116,16 @@ export const IDL: Challenger = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "challenge",
           "isMut": true,
@@ -1512,10 +5137,49 @@ export const IDL: Challenger = {
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "review",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feedbackContentDataHash",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "cruxAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1528,33 +5192,31 @@ export const IDL: Challenger = {
           "type": "u8"
         },
         {
-          "name": "tags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
+          "name": "bumpUserProfile",
+          "type": "u8"
         },
         {
-          "name": "title",
-          "type": "string"
+          "name": "bumpChallenge",
+          "type": "u8"
         },
         {
-          "name": "contentDataUrl",
-          "type": "string"
+          "name": "bumpSubmission",
+          "type": "u8"
         },
         {
-          "name": "challengeExpiresTs",
-          "type": "u64"
+          "name": "bumpBountyVault",
+          "type": "u8"
         },
         {
-          "name": "reputation",
-          "type": "u64"
+          "name": "evaluation",
+          "type": {
+            "defined": "Evaluation"
+          }
         }
       ]
     },
     {
-      "name": "editChallenge",
+      "name": "closeReview",
       "accounts": [
         {
           "name": "crux",
@@ -1568,22 +5230,17 @@ export const IDL: Challenger = {
         },
         {
           "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "submission",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "review",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1596,39 +5253,11 @@ export const IDL: Challenger = {
         {
           "name": "bumpModeratorProfile",
           "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "newChallengeExpiresTs",
-          "type": "u64"
-        },
-        {
-          "name": "newReputation",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "deleteChallenge",
+      "name": "appealSubmission",
       "accounts": [
         {
           "name": "crux",
@@ -1636,18 +5265,23 @@ export const IDL: Challenger = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "cruxTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "challenge",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1656,10 +5290,39 @@ export const IDL: Challenger = {
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "appealContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1668,17 +5331,25 @@ export const IDL: Challenger = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
           "name": "bumpChallenge",
           "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "createSubmission",
+      "name": "resolveAppeal",
       "accounts": [
         {
           "name": "crux",
@@ -1690,10 +5361,27 @@ export const IDL: Challenger = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "resolver",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "resolverProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "previousReview",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "profileOwner",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "userProfile",
@@ -1702,7 +5390,7 @@ export const IDL: Challenger = {
         },
         {
           "name": "challenge",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1710,15 +5398,57 @@ export const IDL: Challenger = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "cruxAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bountyTokenVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cruxTokenLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "profileOwnerFeeTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "systemProgram",
@@ -1738,318 +5468,801 @@ export const IDL: Challenger = {
         {
           "name": "bumpChallenge",
           "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyVault",
+          "type": "u8"
+        },
+        {
+          "name": "decision",
+          "type": {
+            "defined": "SubmissionDecision"
+          }
         }
       ]
     },
     {
-      "name": "editSubmission",
+      "name": "migrateCrux",
       "accounts": [
         {
           "name": "crux",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "cruxManager",
+          "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUserProfile",
+      "accounts": [
         {
           "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateChallenge",
+      "accounts": [
+        {
+          "name": "crux",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bountyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cruxManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSubmission",
+      "accounts": [
         {
           "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "closeAccount",
+      "accounts": [
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "bumpChallenge",
-          "type": "u8"
+          "name": "accountToClose",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "bumpSubmission",
-          "type": "u8"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "answerAttempts",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "lastAnswerAttemptTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challenge",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "crux",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengeSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengePostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "challengeExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "bountyVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyFunder",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bountyPerCompletion",
+            "type": "u64"
+          },
+          {
+            "name": "bountyTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyTokenVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyTokenFunder",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bountyTokenPerCompletion",
+            "type": "u64"
+          },
+          {
+            "name": "requiredApprovals",
+            "type": "u64"
+          },
+          {
+            "name": "challengeAuthor",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxRevisions",
+            "type": "u64"
+          },
+          {
+            "name": "maxSubmissionsPerUser",
+            "type": "u64"
+          },
+          {
+            "name": "revealDuration",
+            "type": "u64"
+          },
+          {
+            "name": "answerHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxAnswerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "answerCooldown",
+            "type": "u64"
+          },
+          {
+            "name": "verifierProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "verifierParams",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "challengeOpensTs",
+            "type": "u64"
+          },
+          {
+            "name": "evaluationDeadlineTs",
+            "type": "u64"
+          },
+          {
+            "name": "maxCompletions",
+            "type": "u64"
+          },
+          {
+            "name": "completionCount",
+            "type": "u64"
+          },
+          {
+            "name": "reputationSchedule",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "rankedCompletions",
+            "type": {
+              "array": [
+                {
+                  "defined": "RankedCompletion"
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "prerequisites",
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "minReputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "periodLength",
+            "type": "u64"
+          },
+          {
+            "name": "challengeStatus",
+            "type": {
+              "defined": "ChallengeStatus"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "crux",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "cruxManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxAuthoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "cruxTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxFees",
+            "type": {
+              "defined": "CruxFees"
+            }
+          },
+          {
+            "name": "cruxCounts",
+            "type": {
+              "defined": "CruxCounts"
+            }
+          },
+          {
+            "name": "cruxConstants",
+            "type": {
+              "defined": "CruxConstants"
+            }
+          },
+          {
+            "name": "feeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxTreasuryTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxLedger",
+            "type": {
+              "defined": "CruxLedger"
+            }
+          },
+          {
+            "name": "pendingCruxManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "cruxPauseFlags",
+            "type": {
+              "defined": "CruxPauseFlags"
+            }
+          },
+          {
+            "name": "cruxPolicies",
+            "type": {
+              "defined": "CruxPolicies"
+            }
+          },
+          {
+            "name": "appealFee",
+            "type": "u64"
+          },
+          {
+            "name": "autoAcceptAfterEvaluationDeadline",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "deleteSubmission",
-      "accounts": [
-        {
-          "name": "crux",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        }
-      ]
+      "name": "review",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "moderatorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "reviewRound",
+            "type": "u64"
+          },
+          {
+            "name": "reviewTs",
+            "type": "u64"
+          },
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "scoreBps",
+            "type": "u64"
+          },
+          {
+            "name": "feedbackContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "feedbackContentDataUrl",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "name": "deleteSubmissionModerator",
-      "accounts": [
-        {
-          "name": "crux",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        }
-      ]
+      "name": "submissionEntries",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "entryCount",
+            "type": "u64"
+          },
+          {
+            "name": "nextSubmissionIndex",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "submission",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "awardedReputation",
+            "type": "u64"
+          },
+          {
+            "name": "bountyPaid",
+            "type": "bool"
+          },
+          {
+            "name": "scoreBps",
+            "type": "u64"
+          },
+          {
+            "name": "reviewRound",
+            "type": "u64"
+          },
+          {
+            "name": "approvalCount",
+            "type": "u64"
+          },
+          {
+            "name": "rejectionCount",
+            "type": "u64"
+          },
+          {
+            "name": "approvalScoreBpsTotal",
+            "type": "u64"
+          },
+          {
+            "name": "appealContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "appealFeePaid",
+            "type": "u64"
+          },
+          {
+            "name": "appealFeeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          },
+          {
+            "name": "previousContentDataHashes",
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "revisionRequestCount",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "submissionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "submitterWeight",
+            "type": "u64"
+          },
+          {
+            "name": "coAuthorCount",
+            "type": "u8"
+          },
+          {
+            "name": "coAuthors",
+            "type": {
+              "array": [
+                {
+                  "defined": "CoAuthor"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "contentCommitment",
+            "type": "publicKey"
+          },
+          {
+            "name": "awaitingReveal",
+            "type": "bool"
+          },
+          {
+            "name": "answerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "lastAnswerAttemptTs",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "evaluateSubmission",
-      "accounts": [
-        {
-          "name": "crux",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        },
-        {
-          "name": "submissionState",
-          "type": {
-            "defined": "SubmissionState"
+      "name": "tokenLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "crux",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalTokenFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalTokenPaidOut",
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "closeAccount",
-      "accounts": [
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "accountToClose",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+      "name": "userProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "crux",
+            "type": "publicKey"
+          },
+          {
+            "name": "profileCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "challengesSubmitted",
+            "type": "u64"
+          },
+          {
+            "name": "challengesCompleted",
+            "type": "u64"
+          },
+          {
+            "name": "reputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "nftPfpTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "isModerator",
+            "type": "bool"
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "streakChallenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "streakPeriodsStartTs",
+            "type": "u64"
+          },
+          {
+            "name": "streakPeriodLength",
+            "type": "u64"
+          },
+          {
+            "name": "streakPeriodIndex",
+            "type": "u64"
+          },
+          {
+            "name": "streakPeriods",
+            "type": "u64"
+          },
+          {
+            "name": "streakCarry",
+            "type": "u64"
+          },
+          {
+            "name": "streak",
+            "type": "u64"
+          }
+        ]
+      }
     }
   ],
-  "accounts": [
+  "types": [
     {
-      "name": "challenge",
+      "name": "ChallengeEdit",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "crux",
-            "type": "publicKey"
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
           },
           {
-            "name": "challengeSeed",
-            "type": "publicKey"
+            "name": "title",
+            "type": "string"
           },
           {
-            "name": "challengePostedTs",
-            "type": "u64"
+            "name": "contentDataUrl",
+            "type": "string"
           },
           {
             "name": "challengeExpiresTs",
             "type": "u64"
           },
+          {
+            "name": "reputation",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "tags",
             "type": {
@@ -2067,181 +6280,344 @@ export const IDL: Challenger = {
             "type": "string"
           },
           {
-            "name": "contentDataHash",
-            "type": "publicKey"
+            "name": "challengeExpiresTs",
+            "type": "u64"
           },
           {
             "name": "reputation",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "crux",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "version",
-            "type": "u16"
+            "name": "bountyLamports",
+            "type": "u64"
           },
           {
-            "name": "cruxManager",
-            "type": "publicKey"
+            "name": "bountyPerCompletion",
+            "type": "u64"
           },
           {
-            "name": "cruxAuthority",
+            "name": "requiredApprovals",
+            "type": "u64"
+          },
+          {
+            "name": "maxRevisions",
+            "type": "u64"
+          },
+          {
+            "name": "maxSubmissionsPerUser",
+            "type": "u64"
+          },
+          {
+            "name": "revealDuration",
+            "type": "u64"
+          },
+          {
+            "name": "answerHash",
             "type": "publicKey"
           },
           {
-            "name": "cruxAuthoritySeed",
+            "name": "maxAnswerAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "answerCooldown",
+            "type": "u64"
+          },
+          {
+            "name": "verifierProgram",
             "type": "publicKey"
           },
           {
-            "name": "cruxAuthorityBumpSeed",
+            "name": "verifierParams",
             "type": {
               "array": [
                 "u8",
-                1
+                32
               ]
             }
           },
           {
-            "name": "cruxTreasury",
-            "type": "publicKey"
+            "name": "challengeOpensTs",
+            "type": "u64"
           },
           {
-            "name": "cruxFees",
+            "name": "evaluationDeadlineTs",
+            "type": "u64"
+          },
+          {
+            "name": "maxCompletions",
+            "type": "u64"
+          },
+          {
+            "name": "reputationSchedule",
             "type": {
-              "defined": "CruxFees"
+              "array": [
+                "u64",
+                3
+              ]
             }
           },
           {
-            "name": "cruxCounts",
+            "name": "prerequisites",
             "type": {
-              "defined": "CruxCounts"
+              "vec": "publicKey"
             }
+          },
+          {
+            "name": "minReputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "periodLength",
+            "type": "u64"
+          },
+          {
+            "name": "isDraft",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "submission",
+      "name": "CoAuthor",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "challenge",
+            "name": "userProfile",
             "type": "publicKey"
           },
           {
-            "name": "userProfile",
-            "type": "publicKey"
+            "name": "weight",
+            "type": "u64"
           },
           {
-            "name": "submissionPostedTs",
+            "name": "awardedReputation",
             "type": "u64"
           },
           {
-            "name": "mostRecentEngagementTs",
+            "name": "accepted",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxConstants",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTagsLength",
             "type": "u64"
           },
           {
-            "name": "contentDataHash",
-            "type": "publicKey"
+            "name": "maxTitleLength",
+            "type": "u64"
           },
           {
-            "name": "submissionState",
-            "type": {
-              "defined": "SubmissionState"
-            }
+            "name": "maxUrlLength",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "userProfile",
+      "name": "CruxCounts",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "profileOwner",
-            "type": "publicKey"
+            "name": "profileCount",
+            "type": "u64"
           },
           {
-            "name": "crux",
-            "type": "publicKey"
+            "name": "challengeCount",
+            "type": "u64"
           },
           {
-            "name": "profileCreatedTs",
+            "name": "submissionCount",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "mostRecentEngagementTs",
+            "name": "profileFee",
             "type": "u64"
           },
           {
-            "name": "challengesSubmitted",
+            "name": "submissionFee",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "challengesCompleted",
+            "name": "totalFeesCollected",
             "type": "u64"
           },
           {
-            "name": "reputationScore",
+            "name": "totalPaidOut",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CruxPauseFlags",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileCreationPaused",
+            "type": "bool"
           },
           {
-            "name": "nftPfpTokenMint",
-            "type": "publicKey"
+            "name": "challengeCreationPaused",
+            "type": "bool"
           },
           {
-            "name": "isModerator",
+            "name": "submissionsPaused",
+            "type": "bool"
+          },
+          {
+            "name": "evaluationPaused",
             "type": "bool"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "CruxCounts",
+      "name": "CruxPolicies",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "profileCount",
-            "type": "u64"
+            "name": "forbidAuthorEvaluation",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Evaluation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "decision",
+            "type": {
+              "defined": "SubmissionDecision"
+            }
           },
           {
-            "name": "challengeCount",
-            "type": "u64"
+            "name": "feedbackContentDataUrl",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RankedCompletion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
           },
           {
-            "name": "submissionCount",
+            "name": "submissionPostedTs",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "CruxFees",
+      "name": "SubmissionDecision",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "profileFee",
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "scoreBps",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SubmissionTeam",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "coAuthorProfiles",
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
-            "name": "submissionFee",
+            "name": "weights",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TreasuryPayout",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "receiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
             "type": "u64"
           }
         ]
       }
     },
+    {
+      "name": "ChallengeStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Draft"
+          },
+          {
+            "name": "Closed"
+          },
+          {
+            "name": "Archived"
+          }
+        ]
+      }
+    },
     {
       "name": "SubmissionState",
       "type": {
@@ -2255,6 +6631,12 @@ export const IDL: Challenger = {
           },
           {
             "name": "Pending"
+          },
+          {
+            "name": "Appealed"
+          },
+          {
+            "name": "NeedsRevision"
           }
         ]
       }
@@ -1,8 +1,12 @@
 import { BN } from '@coral-xyz/anchor';
-import { CruxFees } from '../../challenger.client';
+import { CruxConstants, CruxFees, CruxPolicies } from '../../challenger.client';
 
 type CruxConfig = {
-    cruxFees: CruxFees
+    cruxFees: CruxFees,
+    cruxConstants: CruxConstants,
+    cruxPolicies: CruxPolicies,
+    appealFee: BN,
+    autoAcceptAfterEvaluationDeadline: boolean
 }
 
 export const cruxConfig: CruxConfig =
@@ -10,6 +14,16 @@ export const cruxConfig: CruxConfig =
         cruxFees: {
             profileFee: new BN(10_000), // 0.000,010,0000 Sol
             submissionFee: new BN(5_000), // 5000 Lamports
-        }
+        },
+        cruxConstants: {
+            maxTagsLength: new BN(3),
+            maxTitleLength: new BN(256),
+            maxUrlLength: new BN(256),
+        },
+        cruxPolicies: {
+            forbidAuthorEvaluation: false,
+        },
+        appealFee: new BN(0), // Appeals are free
+        autoAcceptAfterEvaluationDeadline: false, // Expired submissions are rejected
     }
 
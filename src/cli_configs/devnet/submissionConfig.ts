@@ -3,6 +3,7 @@ import { PublicKey } from '@solana/web3.js';
 type SubmissionConfig = {
     challenge: PublicKey,
     content: string,
+    contentDataUrl: string,
 }
 
 // User 1
@@ -10,6 +11,7 @@ export const submissionConfig: SubmissionConfig =
     {
         challenge: new PublicKey("4QfsnQPnzfN6GscPF4am4JV43mnpsYysTRCX1zTZQECD"),
         content: "Built something cool for #SolanaU #SolanaKR. \n" + "https://twitter.com/CharmsSol/status/1661171281369219074",
+        contentDataUrl: "https://twitter.com/CharmsSol/status/1661171281369219074",
     }
 
 